@@ -1,7 +1,33 @@
-use std::path::Path;
+use std::{io::IsTerminal, path::Path};
 
-use clap::{Parser, Subcommand};
+use avro::generator_builder::AvroTypeDescriptionGeneratorBuilder;
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use csharp::{
+    description_generator::declare_part_generator::{CSharpSerializer, CSharpTypeStyle},
+    generator_builder::CSharpTypeDescriptionGeneratorBuilder,
+};
+use dart::{
+    description_generator::declare_part_generator::DartClassStyle,
+    generator_builder::DartTypeDescriptionGeneratorBuilder,
+};
 use go::generator_builder::GoTypeDescriptionGeneratorBuilder;
+use graphql::generator_builder::GraphqlTypeDescriptionGeneratorBuilder;
+use java::{
+    description_generator::declare_part_generator::JavaClassStyle,
+    generator_builder::JavaTypeDescriptionGeneratorBuilder,
+};
+use json_schema::generator_builder::JsonSchemaTypeDescriptionGeneratorBuilder;
+use kotlin::{
+    description_generator::declare_part_generator::KotlinSerializer,
+    generator_builder::KotlinTypeDescriptionGeneratorBuilder,
+};
+use protobuf::{
+    field_number_lock::FieldNumberLock, generator_builder::ProtoTypeDescriptionGeneratorBuilder,
+};
+use python::{
+    description_generator::declare_part_generator::PythonModelStyle,
+    generator_builder::PythonTypeDescriptionGeneratorBuilder,
+};
 use rust::{
     description_generator::RustTypeDescriptionGenerator,
     generator_builder::RustTypeDescriptionGeneratorBuilder,
@@ -11,16 +37,34 @@ use sf_df::{
     fileconvertor::{FileStructure, PathStructure},
     fileoperator::file_structures_to_files,
 };
+use sql::{
+    description_generator::mapper::{SqlArrayStyle, SqlDialect},
+    generator_builder::SqlTypeDescriptionGeneratorBuilder,
+};
+use swift::generator_builder::SwiftTypeDescriptionGeneratorBuilder;
+use typescript::generator_builder::TypeScriptTypeDescriptionGeneratorBuilder;
+use zod::generator_builder::ZodTypeDescriptionGeneratorBuilder;
 
-use crate::config::{InlineSource, SourceConvertor, TypeGenSource};
+use crate::config::{InlineSource, SourceConvertor, SourceFormat, SourceOption, TypeGenSource};
 
 #[derive(Parser)]
 pub struct Cli {
     #[clap(subcommand)]
     sub: Sub,
+    /// format of source, json (sample), jsonl, yaml, toml, csv, tsv, xml, jsonschema, openapi, graphql, proto, sql, har or postman.
+    /// inferred from extension of source file if not stated
+    #[clap(long, global = true)]
+    from: Option<SourceFormat>,
+    /// generate one file per type
+    #[clap(long, global = true)]
+    per_type: bool,
+    /// prefix of property made from xml attribute, default is @
+    #[clap(long, global = true)]
+    attribute_prefix: Option<String>,
 }
 impl Cli {
     pub async fn exec(self) {
+        let option = SourceOption::new(self.from, self.per_type, self.attribute_prefix);
         match self.sub {
             Sub::Go {
                 dist,
@@ -49,6 +93,7 @@ impl Cli {
                     json_tag,
                     row,
                     console,
+                    option,
                 )
                 .await;
             }
@@ -59,6 +104,7 @@ impl Cli {
                 remote_config_file,
                 pub_all,
                 derives,
+                from_row,
                 comment,
                 optional_all,
                 name,
@@ -72,85 +118,1266 @@ impl Cli {
                     remote_config_file,
                     pub_all,
                     derives,
+                    from_row,
+                    comment,
+                    optional_all,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Ts {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                pub_all,
+                comment,
+                optional_all,
+                type_alias,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_ts(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    pub_all,
+                    comment,
+                    optional_all,
+                    type_alias,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Py {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                style,
+                comment,
+                optional_all,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_py(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    style,
+                    comment,
+                    optional_all,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Java {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                style,
+                package,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_java(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    style,
+                    package,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Kotlin {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                serializer,
+                package,
+                comment,
+                optional_all,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_kotlin(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    serializer,
+                    package,
+                    comment,
+                    optional_all,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Swift {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                comment,
+                optional_all,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_swift(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
                     comment,
                     optional_all,
                     name,
                     row,
                     console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Csharp {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                style,
+                serializer,
+                namespace,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_csharp(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    style,
+                    serializer,
+                    namespace,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Dart {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                style,
+                part,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_dart(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    style,
+                    part,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::JsonSchema {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                id,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_json_schema(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    id,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Proto {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                package,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_proto(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    package,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Graphql {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                input,
+                any_scalar,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_graphql(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    input,
+                    any_scalar,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Sql {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                dialect,
+                array_style,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_sql(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    dialect,
+                    array_style,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+            Sub::Zod {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_zod(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
                 )
                 .await;
             }
+            Sub::Avro {
+                dist,
+                source,
+                extension,
+                remote_config_file,
+                namespace,
+                comment,
+                name,
+                row,
+                console,
+            } => {
+                Sub::exec_avro(
+                    dist,
+                    extension,
+                    source,
+                    remote_config_file,
+                    namespace,
+                    comment,
+                    name,
+                    row,
+                    console,
+                    option,
+                )
+                .await;
+            }
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Sub {
+    Go {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(short, long)]
+        pub_all: bool,
+        #[clap(short, long)]
+        all_pointer: bool,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(short, long)]
+        json_tag: bool,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Rust {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(short, long)]
+        pub_all: bool,
+        #[clap(long)]
+        derives: Option<Vec<String>>,
+        /// derive sqlx::FromRow, for row types made from sql.
+        /// integer keeps width of column, decimal is rust_decimal::Decimal, date and time are chrono types,
+        /// uuid is uuid::Uuid and binary is Vec<u8>
+        #[clap(long)]
+        from_row: bool,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Ts {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(short, long)]
+        pub_all: bool,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        type_alias: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Py {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// dataclass, typeddict or pydantic
+        #[clap(long)]
+        style: Option<PythonModelStyle>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Java {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// pojo, record or lombok
+        #[clap(long)]
+        style: Option<JavaClassStyle>,
+        #[clap(long)]
+        package: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Kotlin {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// kotlinx or moshi
+        #[clap(long)]
+        serializer: Option<KotlinSerializer>,
+        #[clap(long)]
+        package: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    /// any value is AnyCodable, so package of it (https://github.com/Flight-School/AnyCodable) is needed
+    Swift {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        optional_all: bool,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Csharp {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// class or record
+        #[clap(long)]
+        style: Option<CSharpTypeStyle>,
+        /// system_text_json or newtonsoft
+        #[clap(long)]
+        serializer: Option<CSharpSerializer>,
+        #[clap(long)]
+        namespace: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Dart {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// json_serializable or freezed
+        #[clap(long)]
+        style: Option<DartClassStyle>,
+        /// file name used in part directives
+        #[clap(long)]
+        part: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    JsonSchema {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// $id of root schema
+        #[clap(long)]
+        id: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Proto {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(long)]
+        package: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    /// integer is declared as custom scalar Long, because Int of graphql is 32 bit
+    Graphql {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// also declare input type for each type
+        #[clap(long)]
+        input: bool,
+        /// scalar name for any type
+        #[clap(long)]
+        any_scalar: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Sql {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        /// postgres, mysql or sqlite
+        #[clap(long)]
+        dialect: Option<SqlDialect>,
+        /// join (join table) or json (json column)
+        #[clap(long)]
+        array_style: Option<SqlArrayStyle>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Zod {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+    Avro {
+        #[clap(short, long)]
+        extension: Option<String>,
+        #[clap(short, long)]
+        dist: Option<String>,
+        #[clap(short, long)]
+        source: Option<String>,
+        #[clap(short, long)]
+        remote_config_file: Option<String>,
+        #[clap(long)]
+        namespace: Option<String>,
+        /// doc of records and fields
+        #[clap(short, long)]
+        comment: Option<String>,
+        #[clap(short, long)]
+        name: Option<String>,
+        #[clap(long)]
+        row: Option<String>,
+        #[clap(long)]
+        console: bool,
+    },
+}
+impl Sub {
+    async fn exec_go(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        pub_all: bool,
+        pointer_all: bool,
+        _comment: Option<String>,
+        optional_all: bool,
+        name: Option<String>,
+        json_tag: bool,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+
+        let mut builder = GoTypeDescriptionGeneratorBuilder::new();
+        if pub_all {
+            builder = builder.declare_part_pub_all();
+            builder = builder.property_part_pub_all();
+        }
+        if pointer_all {
+            builder = builder.property_part_all_pointer();
+        }
+        if json_tag {
+            builder = builder.property_part_json_marshal();
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "go").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    async fn exec_rust(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        pub_all: bool,
+        derives: Option<Vec<String>>,
+        from_row: bool,
+        comment: Option<String>,
+        optional_all: bool,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = RustTypeDescriptionGeneratorBuilder::new();
+        if pub_all {
+            builder = builder.declare_part_pub_all();
+            builder = builder.property_part_pub_all();
+        }
+        if derives.is_some() {
+            builder = builder.declare_part_set_all_derive_with_serde(derives.unwrap());
+            //declare_part_all_attrs_with_serde(derives.unwrap());
+        }
+        if from_row {
+            builder = builder.from_row();
+        }
+        if comment.is_some() {
+            let comment = comment.unwrap();
+            builder = builder.declare_part_all_comment(&comment.as_str());
+            builder = builder.property_part_all_comment(&comment.as_str());
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "rs").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+        if dist.len() > "../".len() {
+            create_rust_mod_files(&dist);
+        }
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_ts(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        pub_all: bool,
+        comment: Option<String>,
+        optional_all: bool,
+        type_alias: bool,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = TypeScriptTypeDescriptionGeneratorBuilder::new();
+        if pub_all {
+            builder = builder.declare_part_export_all();
+        }
+        if type_alias {
+            builder = builder.declare_part_use_type_alias();
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "ts").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_py(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        style: Option<PythonModelStyle>,
+        comment: Option<String>,
+        optional_all: bool,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = PythonTypeDescriptionGeneratorBuilder::new();
+        if let Some(style) = style {
+            builder = builder.style(style);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "py").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_java(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        style: Option<JavaClassStyle>,
+        package: Option<String>,
+        comment: Option<String>,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = JavaTypeDescriptionGeneratorBuilder::new();
+        if let Some(style) = style {
+            builder = builder.style(style);
+        }
+        if let Some(package) = package {
+            builder = builder.declare_part_package(package);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        // java requires one public type per file
+        file_structures_to_files(
+            convertor.convert_per_type(&dist, &generator, "java").await,
+            sf_df::fileoperator::NamingPrincipal::Pascal,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_kotlin(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        serializer: Option<KotlinSerializer>,
+        package: Option<String>,
+        comment: Option<String>,
+        optional_all: bool,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = KotlinTypeDescriptionGeneratorBuilder::new();
+        if let Some(serializer) = serializer {
+            builder = builder.serializer(serializer);
+        }
+        if let Some(package) = package {
+            builder = builder.declare_part_package(package);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "kt").await,
+            sf_df::fileoperator::NamingPrincipal::Pascal,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_swift(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        comment: Option<String>,
+        optional_all: bool,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = SwiftTypeDescriptionGeneratorBuilder::new();
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        if optional_all {
+            builder = builder.property_part_all_optional();
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
         }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "swift").await,
+            sf_df::fileoperator::NamingPrincipal::Pascal,
+        );
     }
-}
-
-#[derive(Subcommand)]
-enum Sub {
-    Go {
-        #[clap(short, long)]
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_csharp(
+        dist: Option<String>,
         extension: Option<String>,
-        #[clap(short, long)]
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        style: Option<CSharpTypeStyle>,
+        serializer: Option<CSharpSerializer>,
+        namespace: Option<String>,
+        comment: Option<String>,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = CSharpTypeDescriptionGeneratorBuilder::new();
+        if let Some(style) = style {
+            builder = builder.style(style);
+        }
+        if let Some(serializer) = serializer {
+            builder = builder.serializer(serializer);
+        }
+        if let Some(namespace) = namespace {
+            builder = builder.declare_part_namespace(namespace);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        // one type per file, same as java
+        file_structures_to_files(
+            convertor.convert_per_type(&dist, &generator, "cs").await,
+            sf_df::fileoperator::NamingPrincipal::Pascal,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_dart(
         dist: Option<String>,
-        #[clap(short, long)]
+        extension: Option<String>,
         source: Option<String>,
-        #[clap(short, long)]
         remote_config_file: Option<String>,
-        #[clap(short, long)]
-        pub_all: bool,
-        #[clap(short, long)]
-        all_pointer: bool,
-        #[clap(short, long)]
+        style: Option<DartClassStyle>,
+        part: Option<String>,
         comment: Option<String>,
-        #[clap(short, long)]
-        optional_all: bool,
-        #[clap(short, long)]
         name: Option<String>,
-        #[clap(short, long)]
-        json_tag: bool,
-        #[clap(long)]
         row: Option<String>,
-        #[clap(long)]
         console: bool,
-    },
-    Rust {
-        #[clap(short, long)]
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = DartTypeDescriptionGeneratorBuilder::new();
+        if let Some(style) = style {
+            builder = builder.style(style);
+        }
+        if let Some(part) = part {
+            builder = builder.declare_part_part(part);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "dart").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_json_schema(
+        dist: Option<String>,
         extension: Option<String>,
-        #[clap(short, long)]
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        id: Option<String>,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = JsonSchemaTypeDescriptionGeneratorBuilder::new();
+        if let Some(id) = id {
+            builder = builder.declare_part_id(id);
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "json").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_proto(
         dist: Option<String>,
-        #[clap(short, long)]
+        extension: Option<String>,
         source: Option<String>,
-        #[clap(short, long)]
         remote_config_file: Option<String>,
-        #[clap(short, long)]
-        pub_all: bool,
-        #[clap(long)]
-        derives: Option<Vec<String>>,
-        #[clap(short, long)]
+        package: Option<String>,
         comment: Option<String>,
-        #[clap(short, long)]
-        optional_all: bool,
-        #[clap(short, long)]
         name: Option<String>,
-        #[clap(long)]
         row: Option<String>,
-        #[clap(long)]
         console: bool,
-    },
-}
-impl Sub {
-    async fn exec_go(
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        // field numbers are locked next to dist, so regeneration does not renumber fields
+        let lock_path = format!(
+            "{}.proto.lock",
+            dist.trim_end_matches('/').trim_end_matches(".proto")
+        );
+        let lock = match std::fs::read_to_string(&lock_path) {
+            Ok(lock) => match FieldNumberLock::from_lock_str(&lock) {
+                Ok(lock) => lock,
+                Err(e) => Cli::command()
+                    .error(ErrorKind::InvalidValue, format!("{}: {}", lock_path, e))
+                    .exit(),
+            },
+            Err(_) => FieldNumberLock::new(),
+        };
+        let mut builder =
+            ProtoTypeDescriptionGeneratorBuilder::new().property_part_lock(lock.clone());
+        if let Some(package) = package {
+            builder = builder.declare_part_package(package);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "proto").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+        std::fs::write(&lock_path, lock.to_lock_string()).unwrap();
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_graphql(
         dist: Option<String>,
         extension: Option<String>,
         source: Option<String>,
         remote_config_file: Option<String>,
-        pub_all: bool,
-        pointer_all: bool,
-        _comment: Option<String>,
-        optional_all: bool,
+        input: bool,
+        any_scalar: Option<String>,
+        comment: Option<String>,
         name: Option<String>,
-        json_tag: bool,
         row: Option<String>,
         console: bool,
+        option: SourceOption,
     ) {
         let dist = if let Some(dist) = dist {
             dist
@@ -162,47 +1389,41 @@ impl Sub {
         } else {
             "json".into()
         };
-
-        let source = Self::make_source(name, source, remote_config_file, extension, row);
-
-        let mut builder = GoTypeDescriptionGeneratorBuilder::new();
-        if pub_all {
-            builder = builder.declare_part_pub_all();
-            builder = builder.property_part_pub_all();
-        }
-        if pointer_all {
-            builder = builder.property_part_all_pointer();
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = GraphqlTypeDescriptionGeneratorBuilder::new();
+        if input {
+            builder = builder.declare_part_input_types();
         }
-        if json_tag {
-            builder = builder.property_part_json_marshal();
+        if let Some(any_scalar) = any_scalar {
+            builder = builder.any_scalar(any_scalar);
         }
-        if optional_all {
-            builder = builder.property_part_all_optional();
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
         }
         let generator = builder.build();
         if console {
-            SourceConvertor::new(source).console(&generator);
+            convertor.console(&generator);
             return;
         }
         file_structures_to_files(
-            SourceConvertor::new(source)
-                .convert(&dist, &generator, "go")
-                .await,
+            convertor.convert(&dist, &generator, "graphql").await,
             sf_df::fileoperator::NamingPrincipal::Snake,
         );
     }
-    async fn exec_rust(
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_sql(
         dist: Option<String>,
         extension: Option<String>,
         source: Option<String>,
         remote_config_file: Option<String>,
-        pub_all: bool,
-        derives: Option<Vec<String>>,
+        dialect: Option<SqlDialect>,
+        array_style: Option<SqlArrayStyle>,
         comment: Option<String>,
-        optional_all: bool,
         name: Option<String>,
         row: Option<String>,
         console: bool,
+        option: SourceOption,
     ) {
         let dist = if let Some(dist) = dist {
             dist
@@ -214,38 +1435,107 @@ impl Sub {
         } else {
             "json".into()
         };
-        let source = Self::make_source(name, source, remote_config_file, extension, row);
-        let mut builder = RustTypeDescriptionGeneratorBuilder::new();
-        if pub_all {
-            builder = builder.declare_part_pub_all();
-            builder = builder.property_part_pub_all();
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = SqlTypeDescriptionGeneratorBuilder::new();
+        if let Some(dialect) = dialect {
+            builder = builder.dialect(dialect);
         }
-        if derives.is_some() {
-            builder = builder.declare_part_set_all_derive_with_serde(derives.unwrap());
-            //declare_part_all_attrs_with_serde(derives.unwrap());
+        if let Some(array_style) = array_style {
+            builder = builder.array_style(array_style);
         }
-        if comment.is_some() {
-            let comment = comment.unwrap();
-            builder = builder.declare_part_all_comment(&comment.as_str());
-            builder = builder.property_part_all_comment(&comment.as_str());
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
         }
-        if optional_all {
-            builder = builder.property_part_all_optional();
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
+        }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "sql").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_zod(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        comment: Option<String>,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = ZodTypeDescriptionGeneratorBuilder::new();
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
         }
         let generator = builder.build();
         if console {
-            SourceConvertor::new(source).console(&generator);
+            convertor.console(&generator);
             return;
         }
         file_structures_to_files(
-            SourceConvertor::new(source)
-                .convert(&dist, &generator, "rs")
-                .await,
+            convertor.convert(&dist, &generator, "ts").await,
             sf_df::fileoperator::NamingPrincipal::Snake,
         );
-        if dist.len() > "../".len() {
-            create_rust_mod_files(&dist);
+    }
+    #[allow(clippy::too_many_arguments)]
+    async fn exec_avro(
+        dist: Option<String>,
+        extension: Option<String>,
+        source: Option<String>,
+        remote_config_file: Option<String>,
+        namespace: Option<String>,
+        comment: Option<String>,
+        name: Option<String>,
+        row: Option<String>,
+        console: bool,
+        option: SourceOption,
+    ) {
+        let dist = if let Some(dist) = dist {
+            dist
+        } else {
+            "./dist".to_string()
+        };
+        let extension: Extension = if let Some(extension) = extension.as_ref() {
+            extension.as_str().into()
+        } else {
+            "json".into()
+        };
+        let convertor = Self::make_source(name, source, remote_config_file, extension, row, option);
+        let mut builder = AvroTypeDescriptionGeneratorBuilder::new();
+        if let Some(namespace) = namespace {
+            builder = builder.declare_part_namespace(namespace);
+        }
+        if let Some(comment) = comment {
+            builder = builder.declare_part_all_comment(comment.as_str());
+            builder = builder.property_part_all_comment(comment.as_str());
+        }
+        let generator = builder.build();
+        if console {
+            convertor.console(&generator);
+            return;
         }
+        file_structures_to_files(
+            convertor.convert(&dist, &generator, "avsc").await,
+            sf_df::fileoperator::NamingPrincipal::Snake,
+        );
     }
     fn make_source(
         name: Option<String>,
@@ -253,12 +1543,26 @@ impl Sub {
         remote_config_file: Option<String>,
         extension: Extension,
         row: Option<String>,
-    ) -> TypeGenSource {
-        match (name, source, remote_config_file, row) {
-            (Some(name), _, _, Some(row)) => TypeGenSource::Inline(InlineSource::new(row, name)),
-            (None, Some(source), _, _) => TypeGenSource::new(&source, extension),
-            (None, None, Some(config), _) => TypeGenSource::from_config_file(&config).unwrap(),
-            _ => TypeGenSource::new("./", extension),
+        option: SourceOption,
+    ) -> SourceConvertor {
+        let source = match (name, source, remote_config_file, row) {
+            (Some(name), _, _, Some(row)) => {
+                Ok(TypeGenSource::Inline(InlineSource::new(row, name)))
+            }
+            (name, Some(source), _, _) if source == TypeGenSource::STDIN => {
+                TypeGenSource::from_stdin(std::io::stdin().lock(), name)
+            }
+            (None, Some(source), _, _) => Ok(TypeGenSource::new(&source, extension)),
+            (None, None, Some(config), _) => TypeGenSource::from_config_file(&config),
+            // content is piped, for example `curl ... | tg rust -n User --console`
+            (name, None, None, None) if !std::io::stdin().is_terminal() => {
+                TypeGenSource::from_stdin(std::io::stdin().lock(), name)
+            }
+            _ => Ok(TypeGenSource::new("./", extension)),
+        };
+        match source {
+            Ok(source) => SourceConvertor::new(source).source_option(option),
+            Err(e) => Cli::command().error(ErrorKind::Io, e).exit(),
         }
     }
 }
@@ -1,20 +1,31 @@
-use std::{fs::read_to_string, path::Path};
+use std::{fs::read_to_string, io::Read, path::Path, str::FromStr};
 
+use csv_source::csv::Csv;
 use description_generator::{
     type_description_generator::{
         DeclarePartGenerator, PropertyPartGenerator, TypeDescriptionGenerator,
     },
     type_mapper::TypeMapper,
 };
+use graphql_source::graphql::Graphql;
+use har_source::{har::Har, postman::Postman};
 use json::json::Json;
+use json_schema_source::json_schema::JsonSchema;
 use npc::fns::to_pascal;
+use openapi_source::openapi::OpenApi;
+use proto_source::proto::Proto;
 use reqwest::RequestBuilder;
 use serde_json::Value;
 use sf_df::{
     extension::Extension,
     fileconvertor::{FileStructure, PathStructure},
-    fileoperator::{all_file_structure, is_dir},
+    fileoperator::{all_path_structure, is_dir},
 };
+use sql_source::sql::Sql;
+use structure::{parts::type_name::TypeName, type_structure::TypeStructure};
+use toml_source::toml::Toml;
+use xml_source::xml::Xml;
+use yaml_source::yaml::Yaml;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum TypeGenDist {
@@ -76,6 +87,8 @@ impl InlineSource {
 #[derive(Debug, PartialEq, Eq)]
 pub enum TypeGenSource {
     Inline(InlineSource),
+    /// content piped to stdin, it is treated like inline source
+    Stdin(InlineSource),
     File(FileSource),
     Dir(DirSource),
     Remote(RemoteSource),
@@ -90,6 +103,26 @@ impl TypeGenSource {
     pub fn new_inline(content: &str, name: &str) -> TypeGenSource {
         TypeGenSource::Inline(InlineSource::new(content, name))
     }
+    /// source which means stdin
+    pub const STDIN: &'static str = "-";
+    /// whole content is read at once, name is `Root` if not stated.
+    /// empty content is error, because there is nothing to convert
+    pub fn from_stdin(
+        mut reader: impl Read,
+        name: Option<String>,
+    ) -> Result<TypeGenSource, String> {
+        let mut content = String::new();
+        if let Err(e) = reader.read_to_string(&mut content) {
+            return Err(format!("failed to read stdin: {}", e));
+        }
+        if content.trim().is_empty() {
+            return Err("stdin is empty, pipe content or state source".to_string());
+        }
+        Ok(TypeGenSource::Stdin(InlineSource::new(
+            content,
+            name.unwrap_or_else(|| "Root".to_string()),
+        )))
+    }
     pub fn from_config_file(file: impl AsRef<Path>) -> Result<Self, String> {
         match read_to_string(&file) {
             Ok(s) => match RemoteSource::from_path(&file) {
@@ -126,6 +159,212 @@ impl TypeGenSource {
         }
     }
 }
+/// how content of source is read
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SourceFormat {
+    /// sample json, types are inferred from values
+    #[default]
+    Json,
+    JsonSchema,
+    /// OpenAPI 3 document in yaml or json, types are named by document
+    OpenApi,
+    /// sample yaml, each document of file is a root
+    Yaml,
+    /// sample toml, datetime is typed as datetime
+    Toml,
+    /// newline delimited sample json, all lines are put together into one type
+    JsonLines,
+    /// header row is keys, and one row type is inferred from all rows
+    Csv,
+    Tsv,
+    /// sample xml, attributes are properties with prefix
+    Xml {
+        attribute_prefix: String,
+    },
+    /// GraphQL SDL or introspection result, types are named by schema
+    Graphql,
+    /// proto3 file, imports are resolved from root of source directory
+    Proto {
+        import_root: Option<String>,
+    },
+    /// sql ddl, one row type per table
+    Sql,
+    /// HTTP Archive, bodies are put together per endpoint
+    Har,
+    /// Postman collection, saved bodies are put together per endpoint
+    Postman,
+}
+impl SourceFormat {
+    /// format which is inferred when --from is not stated
+    pub fn from_extension(extension: Extension) -> Self {
+        match extension {
+            Extension::Yaml => Self::Yaml,
+            Extension::Toml => Self::Toml,
+            Extension::Jsonl | Extension::Ndjson => Self::JsonLines,
+            Extension::Csv => Self::Csv,
+            Extension::Tsv => Self::Tsv,
+            Extension::Xml => Self::xml(),
+            Extension::Graphql => Self::Graphql,
+            Extension::Proto => Self::Proto { import_root: None },
+            Extension::Sql => Self::Sql,
+            Extension::Har => Self::Har,
+            _ => Self::Json,
+        }
+    }
+    fn xml() -> Self {
+        Self::Xml {
+            attribute_prefix: Xml::DEFAULT_ATTRIBUTE_PREFIX.to_string(),
+        }
+    }
+    /// only xml has attributes, other formats are not changed
+    pub fn attribute_prefix(self, prefix: impl Into<String>) -> Self {
+        match self {
+            Self::Xml { .. } => Self::Xml {
+                attribute_prefix: prefix.into(),
+            },
+            _ => self,
+        }
+    }
+    /// only proto has imports, other formats are not changed
+    pub fn import_root(self, root: impl Into<String>) -> Self {
+        match self {
+            Self::Proto { .. } => Self::Proto {
+                import_root: Some(root.into()),
+            },
+            _ => self,
+        }
+    }
+    /// lines skipped by json lines source are reported to stderr
+    fn report_skipped((json, skipped): (Json, Vec<String>)) -> Json {
+        skipped.iter().for_each(|reason| eprintln!("{}", reason));
+        json
+    }
+    /// file of streamed format is read line by line from path, not from content
+    fn is_streamed(&self) -> bool {
+        matches!(self, Self::JsonLines | Self::Csv | Self::Tsv)
+    }
+    pub fn into_type_structures(
+        self,
+        content: &str,
+        root_name: impl Into<TypeName>,
+    ) -> Vec<TypeStructure> {
+        match self {
+            Self::Json => Json::from(content).into_type_structures(root_name),
+            Self::JsonSchema => JsonSchema::from(content).into_type_structures(root_name),
+            Self::OpenApi => OpenApi::from(content).into_type_structures(),
+            Self::Yaml => Yaml::from(content).into_type_structures(root_name),
+            Self::Toml => Toml::from(content).into_type_structures(root_name),
+            Self::JsonLines => Self::report_skipped(Json::from_lines(content.as_bytes()))
+                .into_type_structures(root_name),
+            Self::Csv => {
+                Csv::from_reader(content.as_bytes(), Csv::COMMA).into_type_structures(root_name)
+            }
+            Self::Tsv => {
+                Csv::from_reader(content.as_bytes(), Csv::TAB).into_type_structures(root_name)
+            }
+            Self::Xml { attribute_prefix } => Xml::from(content)
+                .attribute_prefix(attribute_prefix)
+                .into_type_structures(root_name),
+            Self::Graphql => Graphql::from(content).into_type_structures(),
+            Self::Proto { .. } => Proto::from(content).into_type_structures(),
+            Self::Sql => Sql::from(content).into_type_structures(),
+            Self::Har => Har::from(content).into_type_structures(),
+            Self::Postman => Postman::from(content).into_type_structures(),
+        }
+    }
+    /// type structures grouped by output file.
+    /// only OpenAPI has groups (tags), others are one group named by root
+    pub fn into_type_structure_groups(
+        self,
+        content: &str,
+        root_name: impl Into<TypeName>,
+    ) -> Vec<(String, Vec<TypeStructure>)> {
+        match self {
+            Self::OpenApi => OpenApi::from(content).into_tagged_type_structures(),
+            _ => {
+                let root_name = root_name.into();
+                vec![(
+                    root_name.as_str().to_string(),
+                    self.into_type_structures(content, root_name),
+                )]
+            }
+        }
+    }
+    pub fn file_into_type_structure_groups(
+        self,
+        file: &FileStructure,
+        root_name: impl Into<TypeName>,
+    ) -> Vec<(String, Vec<TypeStructure>)> {
+        let path = file.path().path_str();
+        let root_name = root_name.into();
+        let type_structures = match self {
+            Self::JsonLines => Self::report_skipped(Json::from_lines_file(path))
+                .into_type_structures(root_name.clone()),
+            Self::Csv => Csv::from_file(path, Csv::COMMA).into_type_structures(root_name.clone()),
+            Self::Tsv => Csv::from_file(path, Csv::TAB).into_type_structures(root_name.clone()),
+            Self::Proto { import_root } => match import_root {
+                Some(import_root) => Proto::from_file(path, import_root),
+                None => Proto::from_file_in_parent(path),
+            }
+            .into_type_structures(),
+            _ => return self.into_type_structure_groups(file.content(), root_name),
+        };
+        vec![(root_name.as_str().to_string(), type_structures)]
+    }
+    pub fn file_into_type_structures(
+        self,
+        file: &FileStructure,
+        root_name: impl Into<TypeName>,
+    ) -> Vec<TypeStructure> {
+        self.file_into_type_structure_groups(file, root_name)
+            .into_iter()
+            .flat_map(|(_, type_structures)| type_structures)
+            .collect()
+    }
+}
+impl FromStr for SourceFormat {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "json" => Ok(Self::Json),
+            "jsonschema" | "json-schema" | "json_schema" => Ok(Self::JsonSchema),
+            "openapi" | "open-api" | "oas" => Ok(Self::OpenApi),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "toml" => Ok(Self::Toml),
+            "jsonl" | "ndjson" | "jsonlines" | "json-lines" => Ok(Self::JsonLines),
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            "xml" => Ok(Self::xml()),
+            "graphql" | "gql" => Ok(Self::Graphql),
+            "proto" | "protobuf" => Ok(Self::Proto { import_root: None }),
+            "sql" | "ddl" => Ok(Self::Sql),
+            "har" => Ok(Self::Har),
+            "postman" => Ok(Self::Postman),
+            _ => Err(format!("{} is not define source format", str)),
+        }
+    }
+}
+/// options of source which are common to all subcommands
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SourceOption {
+    format: Option<SourceFormat>,
+    per_type: bool,
+    attribute_prefix: Option<String>,
+}
+impl SourceOption {
+    /// format is inferred from extension of each source file, if it is None
+    pub fn new(
+        format: Option<SourceFormat>,
+        per_type: bool,
+        attribute_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            format,
+            per_type,
+            attribute_prefix,
+        }
+    }
+}
 #[derive(Debug, PartialEq, Eq)]
 pub struct DirSource {
     root: String,
@@ -139,18 +378,93 @@ impl DirSource {
         }
     }
     fn to_files(&self) -> Vec<FileSource> {
-        all_file_structure(&self.root, self.extension)
+        all_path_structure(&self.root, self.extension)
             .into_iter()
-            .map(|f| FileSource { src: f })
+            .map(FileSource::from_path_structure)
             .collect()
     }
 }
 pub struct SourceConvertor {
     src: TypeGenSource,
+    format: Option<SourceFormat>,
+    per_type: bool,
+    attribute_prefix: Option<String>,
 }
 impl SourceConvertor {
     pub fn new(src: TypeGenSource) -> Self {
-        Self { src }
+        Self {
+            src,
+            format: None,
+            per_type: false,
+            attribute_prefix: None,
+        }
+    }
+    pub fn source_format(mut self, format: SourceFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+    /// convert behaves as convert_per_type
+    pub fn per_type(mut self, per_type: bool) -> Self {
+        self.per_type = per_type;
+        self
+    }
+    /// prefix of property made from xml attribute
+    pub fn attribute_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.attribute_prefix = Some(prefix.into());
+        self
+    }
+    pub fn source_option(mut self, option: SourceOption) -> Self {
+        self.format = option.format;
+        self.attribute_prefix = option.attribute_prefix;
+        self.per_type(option.per_type)
+    }
+    /// stated format, or format inferred from extension of source file.
+    /// inline and remote sources are json unless format is stated
+    fn format_of(&self, path: Option<&PathStructure>) -> SourceFormat {
+        let format = match (&self.format, path) {
+            (Some(format), _) => format.clone(),
+            (None, Some(path)) => SourceFormat::from_extension(path.extension()),
+            (None, None) => SourceFormat::default(),
+        };
+        let format = match &self.attribute_prefix {
+            Some(prefix) => format.attribute_prefix(prefix),
+            None => format,
+        };
+        match &self.src {
+            TypeGenSource::Dir(s) => format.import_root(&s.root),
+            _ => format,
+        }
+    }
+    fn content_type_structures(&self, content: &str, name: &str) -> Vec<TypeStructure> {
+        self.format_of(None)
+            .into_type_structures(content, to_pascal(name))
+    }
+    fn file_type_structures(&self, source: &FileSource) -> Vec<TypeStructure> {
+        let file = self.read_file(source);
+        self.format_of(Some(file.path()))
+            .file_into_type_structures(&file, to_pascal(file.name_without_extension()))
+    }
+    fn content_type_structure_groups(
+        &self,
+        content: &str,
+        name: &str,
+    ) -> Vec<(String, Vec<TypeStructure>)> {
+        self.format_of(None)
+            .into_type_structure_groups(content, to_pascal(name))
+    }
+    fn file_type_structure_groups(&self, source: &FileSource) -> Vec<(String, Vec<TypeStructure>)> {
+        let file = self.read_file(source);
+        self.format_of(Some(file.path()))
+            .file_into_type_structure_groups(&file, to_pascal(file.name_without_extension()))
+    }
+    /// content of streamed format is not read here, because the file may be too large
+    fn read_file(&self, source: &FileSource) -> FileStructure {
+        let content = if self.format_of(Some(&source.src)).is_streamed() {
+            String::new()
+        } else {
+            read_to_string(source.src.path_str()).unwrap()
+        };
+        FileStructure::new(content, source.src.clone())
     }
     pub fn console<D, P, M>(&self, generator: &TypeDescriptionGenerator<D, P, M>)
     where
@@ -158,9 +472,8 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        if let TypeGenSource::Inline(s) = &self.src {
-            let json = Json::from(s.content.as_str());
-            let type_description = Self::json_to_type_description(json, &s.name, generator);
+        if let TypeGenSource::Inline(s) | TypeGenSource::Stdin(s) = &self.src {
+            let type_description = self.content_to_type_description(&s.content, &s.name, generator);
             println!("{}", type_description);
         }
     }
@@ -175,23 +488,113 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
+        if self.per_type {
+            return self.convert_per_type(dist_root, generator, extension).await;
+        }
         let dist = TypeGenDist::new(dist_root, extension);
         match (&self.src, dist) {
-            (TypeGenSource::File(s), TypeGenDist::File(d)) => Self::file_to_file(s, d, generator),
-            (TypeGenSource::File(s), TypeGenDist::Dir(d)) => Self::file_to_dir(s, d, generator),
-            (TypeGenSource::Dir(s), TypeGenDist::Dir(d)) => Self::dir_to_dir(s, d, generator),
-            (TypeGenSource::Dir(s), TypeGenDist::File(d)) => Self::dir_to_file(s, d, generator),
+            (TypeGenSource::File(s), TypeGenDist::File(d)) => self.file_to_file(s, d, generator),
+            (TypeGenSource::File(s), TypeGenDist::Dir(d)) => self.file_to_dir(s, d, generator),
+            (TypeGenSource::Dir(s), TypeGenDist::Dir(d)) => self.dir_to_dir(s, d, generator),
+            (TypeGenSource::Dir(s), TypeGenDist::File(d)) => self.dir_to_file(s, d, generator),
             (TypeGenSource::Remote(s), TypeGenDist::Dir(d)) => {
-                Self::remote_to_dir(s, d, generator).await
+                self.remote_to_dir(s, d, generator).await
             }
-            (TypeGenSource::Inline(s), TypeGenDist::File(d)) => {
-                Self::inline_to_file(s, d, generator)
+            (TypeGenSource::Inline(s) | TypeGenSource::Stdin(s), TypeGenDist::File(d)) => {
+                self.inline_to_file(s, d, generator)
             }
+            (TypeGenSource::Stdin(s), TypeGenDist::Dir(d)) => self.inline_to_dir(s, d, generator),
             (TypeGenSource::Inline(_s), TypeGenDist::Dir(_d)) => todo!(),
             (TypeGenSource::Remote(_s), TypeGenDist::File(_d)) => todo!(),
         }
     }
+    /// convert each type structure to one file named by type name.
+    /// dist_root is always treated as directory,
+    /// because some langs (for example Java) require one public type per file
+    pub async fn convert_per_type<D, P, M>(
+        &self,
+        dist_root: &str,
+        generator: &TypeDescriptionGenerator<D, P, M>,
+        extension: impl Into<Extension>,
+    ) -> Vec<FileStructure>
+    where
+        D: DeclarePartGenerator<Mapper = M>,
+        P: PropertyPartGenerator<M>,
+        M: TypeMapper,
+    {
+        let extension: Extension = extension.into();
+        match &self.src {
+            TypeGenSource::Inline(s) | TypeGenSource::Stdin(s) => self.type_structures_to_files(
+                self.content_type_structures(&s.content, &s.name),
+                dist_root,
+                extension,
+                generator,
+            ),
+            TypeGenSource::File(s) => self.type_structures_to_files(
+                self.file_type_structures(s),
+                dist_root,
+                extension,
+                generator,
+            ),
+            TypeGenSource::Dir(s) => s
+                .to_files()
+                .iter()
+                .flat_map(|f| {
+                    let dist_dir = f.src.to_dist(&s.root, dist_root, extension).parent_str();
+                    self.type_structures_to_files(
+                        self.file_type_structures(f),
+                        &dist_dir,
+                        extension,
+                        generator,
+                    )
+                })
+                .collect(),
+            TypeGenSource::Remote(s) => {
+                let client = RemoteClient::new();
+                let mut result = Vec::new();
+                for s in &s.sources {
+                    let res = client.fetch(s).await.unwrap();
+                    result.append(&mut self.type_structures_to_files(
+                        self.content_type_structures(&res, &s.name),
+                        dist_root,
+                        extension,
+                        generator,
+                    ));
+                }
+                result
+            }
+        }
+    }
+    fn type_structures_to_files<D, P, M>(
+        &self,
+        type_structures: Vec<TypeStructure>,
+        dist_dir: &str,
+        extension: Extension,
+        generator: &TypeDescriptionGenerator<D, P, M>,
+    ) -> Vec<FileStructure>
+    where
+        D: DeclarePartGenerator<Mapper = M>,
+        P: PropertyPartGenerator<M>,
+        M: TypeMapper,
+    {
+        let dist_dir = dist_dir.trim_end_matches('/');
+        type_structures
+            .into_iter()
+            .map(|s| {
+                let path = format!(
+                    "{}/{}.{}",
+                    dist_dir,
+                    s.type_name().valid_lang_str(),
+                    extension.to_str()
+                );
+                let content = generator.generate_concat_define(vec![s]);
+                FileStructure::new(content, PathStructure::new(path, extension))
+            })
+            .filter(|f| !f.content().is_empty())
+            .collect()
+    }
     async fn remote_to_dir<D, P, M>(
+        &self,
         s: &RemoteSource,
         d: DirDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -201,32 +604,24 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
+        let client = RemoteClient::new();
         let mut result = Vec::new();
         for s in &s.sources {
             let dist_path = format!("{}/{}.{}", &d.root, &s.name, d.extension.to_str());
             let dist_path = PathStructure::new(dist_path, d.extension)
                 .to_snake_path_consider_with_wellknown_words();
-            result.push(Self::remote_to_file_structure(s, dist_path, generator).await);
+            let res = client.fetch(s).await.unwrap();
+            result.append(&mut self.groups_to_files(
+                self.content_type_structure_groups(&res, &s.name),
+                dist_path,
+                generator,
+            ));
         }
         result
     }
-    async fn remote_to_file_structure<D, P, M>(
-        s: &RemoteSourceConfig,
-        dist_path: PathStructure,
-        generator: &TypeDescriptionGenerator<D, P, M>,
-    ) -> FileStructure
-    where
-        D: DeclarePartGenerator<Mapper = M>,
-        P: PropertyPartGenerator<M>,
-        M: TypeMapper,
-    {
-        let client = RemoteClient::new();
-        let res = client.fetch(s).await.unwrap();
-        let content = Self::json_to_type_description(res, &s.name, generator);
-        FileStructure::new(content, dist_path)
-    }
 
     fn inline_to_file<D, P, M>(
+        &self,
         s: &InlineSource,
         d: FileDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -236,14 +631,38 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        let json = Json::from(s.content.as_str());
-        let type_description = Self::json_to_type_description(json, &s.name, generator);
+        let type_description = self.content_to_type_description(&s.content, &s.name, generator);
         vec![FileStructure::new(
             type_description,
             PathStructure::from_path(&d.path),
         )]
     }
+    /// file is named by name of root type
+    fn inline_to_dir<D, P, M>(
+        &self,
+        s: &InlineSource,
+        d: DirDist,
+        generator: &TypeDescriptionGenerator<D, P, M>,
+    ) -> Vec<FileStructure>
+    where
+        D: DeclarePartGenerator<Mapper = M>,
+        P: PropertyPartGenerator<M>,
+        M: TypeMapper,
+    {
+        let path = format!(
+            "{}/{}.{}",
+            d.root.trim_end_matches('/'),
+            s.name,
+            d.extension.to_str()
+        );
+        self.groups_to_files(
+            self.content_type_structure_groups(&s.content, &s.name),
+            PathStructure::new(path, d.extension).to_snake_path_consider_with_wellknown_words(),
+            generator,
+        )
+    }
     fn dir_to_file<D, P, M>(
+        &self,
         s: &DirSource,
         d: FileDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -256,7 +675,7 @@ impl SourceConvertor {
         let contents = s
             .to_files()
             .iter()
-            .map(|f| Self::file_source_to_type_description(f, generator))
+            .map(|f| self.file_source_to_type_description(f, generator))
             .reduce(|acc, cur| format!("{}\n{}", acc, cur))
             .unwrap_or_default();
         vec![FileStructure::new(
@@ -265,6 +684,7 @@ impl SourceConvertor {
         )]
     }
     fn dir_to_dir<D, P, M>(
+        &self,
         s: &DirSource,
         d: DirDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -277,17 +697,17 @@ impl SourceConvertor {
         let s_root = &s.root;
         s.to_files()
             .iter()
-            .map(|s| {
-                s.src.to_dist(
-                    s_root,
-                    &d.root,
-                    d.extension,
-                    Self::file_source_to_type_description(s, generator),
+            .flat_map(|s| {
+                self.groups_to_files(
+                    self.file_type_structure_groups(s),
+                    s.src.to_dist(s_root, &d.root, d.extension),
+                    generator,
                 )
             })
             .collect()
     }
     fn file_to_dir<D, P, M>(
+        &self,
         s: &FileSource,
         d: DirDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -297,13 +717,54 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        vec![s.src.to(
-            &d.root,
-            d.extension,
-            Self::file_source_to_type_description(s, generator),
-        )]
+        self.groups_to_files(
+            self.file_type_structure_groups(s),
+            s.src.to(&d.root, d.extension),
+            generator,
+        )
+    }
+    /// one group is written to dist_path as it is,
+    /// and several groups (for example tags of OpenAPI) are written to
+    /// one file per group, named by the group, in the directory of dist_path
+    fn groups_to_files<D, P, M>(
+        &self,
+        mut groups: Vec<(String, Vec<TypeStructure>)>,
+        dist_path: PathStructure,
+        generator: &TypeDescriptionGenerator<D, P, M>,
+    ) -> Vec<FileStructure>
+    where
+        D: DeclarePartGenerator<Mapper = M>,
+        P: PropertyPartGenerator<M>,
+        M: TypeMapper,
+    {
+        if groups.len() == 1 {
+            let (_, type_structures) = groups.remove(0);
+            return vec![FileStructure::new(
+                generator.generate_concat_define(type_structures),
+                dist_path,
+            )];
+        }
+        let dist_dir = dist_path.parent_str();
+        let extension = dist_path.extension();
+        groups
+            .into_iter()
+            .map(|(group, type_structures)| {
+                let path = format!(
+                    "{}/{}.{}",
+                    dist_dir.trim_end_matches('/'),
+                    group,
+                    extension.to_str()
+                );
+                FileStructure::new(
+                    generator.generate_concat_define(type_structures),
+                    PathStructure::new(path, extension)
+                        .to_snake_path_consider_with_wellknown_words(),
+                )
+            })
+            .collect()
     }
     fn file_to_file<D, P, M>(
+        &self,
         s: &FileSource,
         d: FileDist,
         generator: &TypeDescriptionGenerator<D, P, M>,
@@ -313,13 +774,13 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        vec![s.src.to(
-            &d.path,
-            d.extension,
-            Self::file_source_to_type_description(s, generator),
+        vec![FileStructure::new(
+            self.file_source_to_type_description(s, generator),
+            s.src.to(&d.path, d.extension),
         )]
     }
     fn file_source_to_type_description<D, P, M>(
+        &self,
         f: &FileSource,
         generator: &TypeDescriptionGenerator<D, P, M>,
     ) -> String
@@ -328,10 +789,11 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        Self::json_to_type_description(f.src.content(), f.src.name_without_extension(), generator)
+        generator.generate_concat_define(self.file_type_structures(f))
     }
-    fn json_to_type_description<D, P, M>(
-        json: impl Into<Json>,
+    fn content_to_type_description<D, P, M>(
+        &self,
+        content: &str,
         name: &str,
         generator: &TypeDescriptionGenerator<D, P, M>,
     ) -> String
@@ -340,8 +802,7 @@ impl SourceConvertor {
         P: PropertyPartGenerator<M>,
         M: TypeMapper,
     {
-        let json = json.into();
-        let type_structure = json.into_type_structures(to_pascal(name));
+        let type_structure = self.content_type_structures(content, name);
         generator.generate_concat_define(type_structure)
     }
 }
@@ -382,15 +843,17 @@ impl RemoteClient {
     }
 }
 
+/// content is read on convert, because whether it is streamed depends on format
 #[derive(Debug, PartialEq, Eq)]
 pub struct FileSource {
-    src: FileStructure,
+    src: PathStructure,
 }
 impl FileSource {
     fn new(src: &str) -> Self {
-        FileSource {
-            src: FileStructure::from_path(src),
-        }
+        Self::from_path_structure(PathStructure::from_path(src))
+    }
+    fn from_path_structure(path: PathStructure) -> Self {
+        FileSource { src: path }
     }
 }
 #[derive(Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
@@ -530,6 +993,69 @@ mod tests {
         );
         ope.clean_up();
     }
+    #[tokio::test]
+    #[ignore = "because create file"]
+    async fn dir_sourceのopenapiはタグごとのファイルに変換される() {
+        let src = "test-openapi-root";
+        let mut ope = TestDirectoryOperator::new();
+        ope.clean_up_before_test(src);
+        ope.prepare_file(
+            "test-openapi-root/api/openapi.json",
+            r#"{
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "listUsers",
+        "tags": ["user"],
+        "responses": {
+          "200": {
+            "content": {
+              "application/json": {
+                "schema": {
+                  "type": "object",
+                  "required": ["name"],
+                  "properties": { "name": { "type": "string" } }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": {
+        "type": "object",
+        "required": ["id"],
+        "properties": { "id": { "type": "string" } }
+      }
+    }
+  }
+}"#,
+        );
+        let sut = SourceConvertor::new(TypeGenSource::new(src, "json"))
+            .source_format(SourceFormat::OpenApi);
+        assert_eq!(
+            sut.convert(
+                "dist",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs"
+            )
+            .await,
+            vec![
+                FileStructure::new(
+                    "struct User {\n    id: String,\n}",
+                    PathStructure::new("dist/api/components.rs", "rs")
+                ),
+                FileStructure::new(
+                    "struct ListUsersResponse {\n    name: String,\n}",
+                    PathStructure::new("dist/api/user.rs", "rs")
+                ),
+            ]
+        );
+        ope.clean_up();
+    }
     #[test]
     #[ignore = "because create file"]
     fn 入力されたsrcからsrcの種類を判定するjson_fine版() {
@@ -553,6 +1079,24 @@ mod tests {
             })
         );
     }
+    #[tokio::test]
+    async fn stdinの内容はルートの型の名前のファイルに変換される() {
+        assert!(TypeGenSource::from_stdin("\n".as_bytes(), None).is_err());
+        let src = TypeGenSource::from_stdin(r#"{"userId":1}"#.as_bytes(), None).unwrap();
+        let sut = SourceConvertor::new(src);
+        assert_eq!(
+            sut.convert(
+                "dist",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs"
+            )
+            .await,
+            vec![FileStructure::new(
+                "struct Root {\n    #[serde(rename = \"userId\")]\n    user_id: usize,\n}",
+                PathStructure::new("dist/root.rs", "rs")
+            ),]
+        );
+    }
     #[ignore = "because create file"]
     #[test]
     fn 入力されたsrcからsrcの種類を判定するdir版() {
@@ -579,6 +1123,182 @@ mod tests {
         }
         ope.clean_up();
     }
+    #[tokio::test]
+    async fn convert_per_typeは型ごとに型名のファイルを生成する() {
+        let src = TypeGenSource::new_inline(r#"{"id":0,"user":{"name":"kai"}}"#, "test");
+        let sut = SourceConvertor::new(src);
+        let generator = java::generator_builder::JavaTypeDescriptionGeneratorBuilder::new()
+            .lombok()
+            .build();
+
+        let result = sut.convert_per_type("dist/", &generator, "java").await;
+
+        assert_eq!(
+            result,
+            vec![
+                FileStructure::new(
+                    "import lombok.Data;\n\n@Data\npublic class Test {\n    private Long id;\n    private TestUser user;\n}\n",
+                    PathStructure::new("dist/Test.java", "java")
+                ),
+                FileStructure::new(
+                    "import lombok.Data;\n\n@Data\npublic class TestUser {\n    private String name;\n}\n",
+                    PathStructure::new("dist/TestUser.java", "java")
+                ),
+            ]
+        );
+    }
+    #[tokio::test]
+    async fn json_schemaのsourceはスキーマから型を生成する() {
+        let src = TypeGenSource::new_inline(
+            r#"{"type":"object","required":["id"],"properties":{"id":{"type":"integer"},"name":{"type":"string"}}}"#,
+            "test",
+        );
+        let sut =
+            SourceConvertor::new(src).source_format(SourceFormat::from_str("jsonschema").unwrap());
+
+        let result = sut
+            .convert_per_type(
+                "dist/",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs",
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            vec![FileStructure::new(
+                "struct Test {\n    id: isize,\n    name: Option<String>,\n}",
+                PathStructure::new("dist/Test.rs", "rs")
+            )]
+        );
+    }
+    #[tokio::test]
+    #[ignore = "because create file"]
+    async fn jsonlのfile_sourceは全ての行を一つの型にまとめる() {
+        let src = "events.jsonl";
+        let mut ope = TestDirectoryOperator::new();
+        ope.clean_up_before_test(src);
+        ope.prepare_file(src, "{\"id\":0}\n{\"id\":1,\"name\":\"kai\"}\n");
+        let sut = SourceConvertor::new(TypeGenSource::new(src, "jsonl"));
+        assert_eq!(
+            sut.convert(
+                "test.rs",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs"
+            )
+            .await,
+            vec![FileStructure::new(
+                "struct Events {\n    id: usize,\n    name: String,\n}",
+                PathStructure::new("test.rs", "rs")
+            )]
+        );
+        ope.clean_up();
+    }
+    #[tokio::test]
+    #[ignore = "because create file"]
+    async fn fromで指定した形式は拡張子より優先して読み込まれる() {
+        let src = "events.jsonl";
+        let mut ope = TestDirectoryOperator::new();
+        ope.clean_up_before_test(src);
+        ope.prepare_file(src, "{\n  \"id\": 0\n}\n");
+        let sut = SourceConvertor::new(TypeGenSource::new(src, "jsonl"))
+            .source_format(SourceFormat::from_str("json").unwrap());
+        assert_eq!(
+            sut.convert(
+                "test.rs",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs"
+            )
+            .await,
+            vec![FileStructure::new(
+                "struct Events {\n    id: usize,\n}",
+                PathStructure::new("test.rs", "rs")
+            )]
+        );
+        ope.clean_up();
+    }
+    #[tokio::test]
+    #[ignore = "because create file"]
+    async fn csvのfile_sourceはヘッダーをキーにした一つの型になる() {
+        let src = "users.csv";
+        let mut ope = TestDirectoryOperator::new();
+        ope.clean_up_before_test(src);
+        ope.prepare_file(src, "id,name,score\n1,kai,\n2,,0.5\n");
+        let sut = SourceConvertor::new(TypeGenSource::new(src, "csv"));
+        assert_eq!(
+            sut.convert(
+                "test.rs",
+                &RustTypeDescriptionGeneratorBuilder::new().build(),
+                "rs"
+            )
+            .await,
+            vec![FileStructure::new(
+                "struct Users {\n    id: usize,\n    name: Option<String>,\n    score: Option<f64>,\n}",
+                PathStructure::new("test.rs", "rs")
+            )]
+        );
+        ope.clean_up();
+    }
+    #[test]
+    fn 形式が指定されなければsourceの拡張子から推測される() {
+        let path = PathStructure::new("config/compose.yml", "yml");
+        let sut = SourceConvertor::new(TypeGenSource::new_inline("", "test"));
+
+        assert_eq!(sut.format_of(Some(&path)), SourceFormat::Yaml);
+        assert_eq!(sut.format_of(None), SourceFormat::Json);
+        assert_eq!(
+            sut.format_of(Some(&PathStructure::new("api/schema.graphql", "graphql"))),
+            SourceFormat::Graphql
+        );
+        assert_eq!(
+            sut.format_of(Some(&PathStructure::new("migrations/001_init.sql", "sql"))),
+            SourceFormat::Sql
+        );
+        assert_eq!(
+            sut.format_of(Some(&PathStructure::new("recorded/app.har", "har"))),
+            SourceFormat::Har
+        );
+
+        let sut = sut.source_format(SourceFormat::from_str("openapi").unwrap());
+
+        assert_eq!(sut.format_of(Some(&path)), SourceFormat::OpenApi);
+    }
+    #[test]
+    fn 定義されていない形式はエラーになる() {
+        assert_eq!(SourceFormat::from_str("yml"), Ok(SourceFormat::Yaml));
+        assert_eq!(
+            SourceFormat::from_str("jsn"),
+            Err("jsn is not define source format".to_string())
+        );
+    }
+    #[test]
+    fn 属性の接頭辞はxmlの形式にだけ適用される() {
+        let sut = SourceConvertor::new(TypeGenSource::new_inline("", "test")).attribute_prefix("_");
+
+        assert_eq!(
+            sut.format_of(Some(&PathStructure::new("soap/response.xml", "xml"))),
+            SourceFormat::Xml {
+                attribute_prefix: "_".to_string()
+            }
+        );
+        assert_eq!(sut.format_of(None), SourceFormat::Json);
+    }
+    #[test]
+    fn protoのimportはsourceのディレクトリから解決される() {
+        let sut = SourceConvertor::new(TypeGenSource::Dir(DirSource::new_with_extension(
+            "protos", "proto",
+        )));
+
+        assert_eq!(
+            sut.format_of(Some(&PathStructure::new(
+                "protos/shop/order.proto",
+                "proto"
+            ))),
+            SourceFormat::Proto {
+                import_root: Some("protos".to_string())
+            }
+        );
+    }
     #[test]
     fn 設定ファイルにはsrcかr_srcの指定が必須() {
         let config_src = r#"{"src":{"root":"./","extension":"json"}}"#;
@@ -8,7 +8,23 @@ pub enum Extension {
     Go,
     Ts,
     Py,
+    Kt,
+    Swift,
+    Cs,
+    Dart,
+    Proto,
+    Graphql,
+    Sql,
+    Avsc,
     Json,
+    Jsonl,
+    Ndjson,
+    Yaml,
+    Toml,
+    Csv,
+    Tsv,
+    Xml,
+    Har,
     Empty,
 }
 impl Extension {
@@ -54,17 +70,27 @@ impl Extension {
         ))
     }
     pub fn replace(path: &str, source: &Extension, dist: &Extension) -> String {
-        path.replace(
-            &format!(".{}", source.to_str()),
-            &format!(".{}", dist.to_str()),
-        )
+        let source = Path::new(path)
+            .extension()
+            .and_then(|ex| ex.to_str())
+            .filter(|ex| source.is_this_str(ex))
+            .unwrap_or(source.to_str());
+        path.replace(&format!(".{}", source), &format!(".{}", dist.to_str()))
     }
     pub fn is_this_extension(&self, path: impl AsRef<Path>) -> bool {
         path.as_ref()
             .extension()
-            .map(|ex| Some(self.to_str()) == ex.to_str())
+            .and_then(|ex| ex.to_str())
+            .map(|ex| self.is_this_str(ex))
             .unwrap_or(false)
     }
+    /// yaml is also written as yml
+    fn is_this_str(&self, extension: &str) -> bool {
+        match self {
+            Extension::Yaml => extension == "yaml" || extension == "yml",
+            _ => extension == self.to_str(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +126,15 @@ mod tests {
         assert_eq!(result, "json.rs");
     }
     #[test]
+    fn ymlはyamlの拡張子として扱われる() {
+        assert!(Extension::Yaml.is_this_extension("config.yml"));
+        assert!(Extension::Yaml.is_this_extension("config.yaml"));
+        assert_eq!(
+            Extension::replace("dir/config.yml", &Extension::Yaml, &Extension::Rs),
+            "dir/config.rs"
+        );
+    }
+    #[test]
     fn 拡張子を削除する() {
         let path = "json.json";
         let result = Extension::remove_extension(path);
@@ -116,7 +151,23 @@ impl From<&str> for Extension {
             "go" => Extension::Go,
             "ts" => Extension::Ts,
             "py" => Extension::Py,
+            "kt" => Extension::Kt,
+            "swift" => Extension::Swift,
+            "cs" => Extension::Cs,
+            "dart" => Extension::Dart,
+            "proto" => Extension::Proto,
+            "graphql" => Extension::Graphql,
+            "sql" => Extension::Sql,
+            "avsc" => Extension::Avsc,
             "json" => Extension::Json,
+            "jsonl" => Extension::Jsonl,
+            "ndjson" => Extension::Ndjson,
+            "yaml" | "yml" => Extension::Yaml,
+            "toml" => Extension::Toml,
+            "csv" => Extension::Csv,
+            "tsv" => Extension::Tsv,
+            "xml" => Extension::Xml,
+            "har" => Extension::Har,
             "" => Extension::Empty,
             _ => panic!("not impl extension {}", s),
         }
@@ -132,7 +183,23 @@ impl Into<&'static str> for &Extension {
             Extension::Go => "go",
             Extension::Ts => "ts",
             Extension::Py => "py",
+            Extension::Kt => "kt",
+            Extension::Swift => "swift",
+            Extension::Cs => "cs",
+            Extension::Dart => "dart",
+            Extension::Proto => "proto",
+            Extension::Graphql => "graphql",
+            Extension::Sql => "sql",
+            Extension::Avsc => "avsc",
             Extension::Json => "json",
+            Extension::Jsonl => "jsonl",
+            Extension::Ndjson => "ndjson",
+            Extension::Yaml => "yaml",
+            Extension::Toml => "toml",
+            Extension::Csv => "csv",
+            Extension::Tsv => "tsv",
+            Extension::Xml => "xml",
+            Extension::Har => "har",
             Extension::Empty => "",
         }
     }
@@ -146,7 +213,23 @@ impl Into<&'static str> for Extension {
             Self::Go => "go",
             Self::Ts => "ts",
             Self::Py => "py",
+            Self::Kt => "kt",
+            Self::Swift => "swift",
+            Self::Cs => "cs",
+            Self::Dart => "dart",
+            Self::Proto => "proto",
+            Self::Graphql => "graphql",
+            Self::Sql => "sql",
+            Self::Avsc => "avsc",
             Self::Json => "json",
+            Self::Jsonl => "jsonl",
+            Self::Ndjson => "ndjson",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Xml => "xml",
+            Self::Har => "har",
             Self::Empty => "",
         }
     }
@@ -4,7 +4,7 @@ use std::{
     path::{self, Path},
 };
 
-use npc::fns::{to_snake, to_snake_consider_with_wellknown_word};
+use npc::fns::{to_pascal, to_snake, to_snake_consider_with_wellknown_word};
 
 use crate::{extension::Extension, fileoperator::is_dir};
 
@@ -38,6 +38,9 @@ impl FileStructure {
             self.path.to_snake_path_consider_with_wellknown_words(),
         )
     }
+    pub fn to_pascal_path(self) -> Self {
+        Self::new(self.content, self.path.to_pascal_path())
+    }
     pub fn name_without_extension(&self) -> &str {
         self.path.name_without_extension()
     }
@@ -145,6 +148,9 @@ impl PathStructure {
     pub fn path_str(&self) -> &str {
         &self.path
     }
+    pub fn extension(&self) -> Extension {
+        self.extension
+    }
     pub fn all_child_dirs(&self, this_root: &str) -> Vec<String> {
         let all_child_dirs = self.extract_dir();
         let mut dir = String::new();
@@ -188,6 +194,11 @@ impl PathStructure {
         let new_path = self.path.replace(self.name_without_extension(), &new_name);
         Self::new(new_path, self.extension)
     }
+    pub fn to_pascal_path(self) -> Self {
+        let new_name = to_pascal(self.name_without_extension());
+        let new_path = self.path.replace(self.name_without_extension(), &new_name);
+        Self::new(new_path, self.extension)
+    }
     pub fn to_dist(
         &self,
         src_root: &str,
@@ -300,6 +311,14 @@ mod path_structure_tests {
         assert_eq!(result, PathStructure::new("./src/chain_case.rs", "rs"));
     }
     #[test]
+    fn パスの名前をpascal_caseに変更する() {
+        let sut = PathStructure::new("./src/chain-case.java", "java");
+
+        let result = sut.to_pascal_path();
+
+        assert_eq!(result, PathStructure::new("./src/ChainCase.java", "java"));
+    }
+    #[test]
     fn path構造体から生成できる() {
         let sut = PathStructure::from_path("./src/main.rs");
 
@@ -32,6 +32,9 @@ pub fn file_structures_to_files(v: Vec<FileStructure>, file_principal: NamingPri
             v.into_iter()
                 .for_each(|f| f.to_snake_path_consider_with_wellknown_words().new_file());
         }
+        NamingPrincipal::Pascal => {
+            v.into_iter().for_each(|f| f.to_pascal_path().new_file());
+        }
         _ => todo!("Other NamingPrincipal"),
     }
 }
@@ -141,19 +144,20 @@ fn extract_dir<P: AsRef<Path>>(path: P) -> Option<String> {
     path.as_ref().to_str().map(|s| s.replace(filename, ""))
 }
 pub fn all_file_structure(root: &str, extension: impl Into<Extension>) -> Vec<FileStructure> {
+    all_path_structure(root, extension)
+        .into_iter()
+        .map(|path| FileStructure::new(read_to_string(path.path_str()).unwrap(), path))
+        .collect()
+}
+/// same as all_file_structure, but content of files is not read
+pub fn all_path_structure(root: &str, extension: impl Into<Extension>) -> Vec<PathStructure> {
     let extension: Extension = extension.into();
     if extension.is_this_extension(root) {
-        return vec![FileStructure::from_path(root)];
+        return vec![PathStructure::from_path(root)];
     }
     all_file_path(root)
         .iter()
         .filter(move |p| extension.is_this_extension(p))
-        .map(|p| {
-            let path = p.to_str().unwrap_or_default();
-            FileStructure::new(
-                read_to_string(p).unwrap(),
-                PathStructure::new(path, extension),
-            )
-        })
+        .map(|p| PathStructure::new(p.to_str().unwrap_or_default(), extension))
         .collect()
 }
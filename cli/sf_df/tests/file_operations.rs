@@ -1,12 +1,12 @@
 mod helper;
 mod integration_tests {
-    use std::fs::read_to_string;
+    use std::fs::{self, read_to_string};
 
     use crate::helper::TestDirectoryOperator;
     use sf_df::{
         fileconvertor::{FileStructure, PathStructure},
         fileoperator::{
-            add_to_file, all_file_path, all_file_structure, create_new_file,
+            add_to_file, all_file_path, all_file_structure, all_path_structure, create_new_file,
             file_structures_to_files, mkdir_rec,
         },
     };
@@ -34,6 +34,26 @@ mod integration_tests {
         operator.remove_dir_all("./for-test");
     }
     #[test]
+    fn ディレクトリ内のymlファイルはyamlとして取得される() {
+        // temp dir is not watched
+        let root = std::env::temp_dir().join(format!("sf_df-yml-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("child")).unwrap();
+        fs::write(root.join("config.yaml"), "id: 0").unwrap();
+        fs::write(root.join("child/config.yml"), "id: 0").unwrap();
+        fs::write(root.join("child/config.json"), "{}").unwrap();
+        let root_str = root.to_str().unwrap();
+
+        let mut result = all_path_structure(root_str, "yaml")
+            .into_iter()
+            .map(|path| path.path_str().replacen(root_str, "", 1))
+            .collect::<Vec<_>>();
+        result.sort();
+
+        assert_eq!(result, vec!["/child/config.yml", "/config.yaml"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+    #[test]
     #[ignore = "watchでテストする際にwatchが生成のたびにループしてしまうので"]
     fn for_testディレクトリ内の全てのファイルのパスを取得する() {
         // this test context is exist test directory
@@ -7,8 +7,8 @@ use structure::{
         property_key::PropertyKey,
         property_type::{
             property_type_factories::{
-                make_any, make_array_type, make_bool_type, make_custom_type, make_float_type,
-                make_isize_type, make_string_type, make_usize_type,
+                make_any, make_array_type, make_bool_type, make_custom_type, make_datetime_type,
+                make_float_type, make_isize_type, make_string_type, make_usize_type,
             },
             PropertyType,
         },
@@ -27,6 +27,7 @@ impl Json {
             Json::Object(obj) => Self::case_obj(&root_name, obj).into(),
             Json::Array(arr) => Self::case_alias_arr(&root_name, arr).into(),
             Json::String(_) => Self::case_alias_string(root_name),
+            Json::DateTime(_) => vec![TypeStructure::make_alias(root_name, make_datetime_type())],
             Json::Null => Self::case_alias_null(root_name),
             Json::Number(num) => Self::case_alias_num(root_name, num),
             Json::Boolean(_) => Self::case_alias_boolean(root_name),
@@ -129,6 +130,7 @@ impl Json {
             JsonType::Usize64 => make_usize_type(),
             JsonType::Isize64 => make_isize_type(),
             JsonType::String => make_string_type(),
+            JsonType::DateTime => make_datetime_type(),
             JsonType::Object => make_custom_type(type_name),
             JsonType::Null => make_any(),
             JsonType::Array => panic!(),
@@ -141,6 +143,7 @@ impl Json {
     fn into_primitive_property_type(&self) -> PropertyType {
         match self {
             Json::String(_) => make_string_type(),
+            Json::DateTime(_) => make_datetime_type(),
             Json::Number(num) => Self::json_num_to_property_type(num),
             Json::Boolean(_) => make_bool_type(),
             Json::Null => make_any(),
@@ -1,7 +1,7 @@
 use std::{
     collections::BTreeMap,
     fs::File,
-    io::{BufReader, Read},
+    io::{BufRead, BufReader, Read},
     path::Path,
 };
 
@@ -16,6 +16,8 @@ pub enum Json {
     Number(Number),
     Null,
     String(String),
+    /// json has no datetime, but toml and yaml sources have
+    DateTime(String),
 }
 impl Json {
     pub fn from_file(path: impl AsRef<Path>) -> Self {
@@ -25,6 +27,41 @@ impl Json {
         reader.read_to_string(&mut result).unwrap();
         Json::from(result.as_str())
     }
+    pub fn from_lines_file(path: impl AsRef<Path>) -> (Self, Vec<String>) {
+        let file = File::open(path).unwrap();
+        Json::from_lines(BufReader::new(file))
+    }
+    /// each line of json lines is put together one by one,
+    /// so only one line is held at once.
+    /// line which can not be read as json is skipped, and the reasons are returned with json
+    pub fn from_lines(reader: impl BufRead) -> (Self, Vec<String>) {
+        let mut skipped = Vec::new();
+        let json = reader
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let json = line
+                    .map_err(|e| e.to_string())
+                    .and_then(|line| Self::from_line(&line).map_err(|e| e.to_string()));
+                match json {
+                    Ok(json) => json,
+                    Err(e) => {
+                        skipped.push(format!("line {} is skipped: {}", i + 1, e));
+                        None
+                    }
+                }
+            })
+            .reduce(|acc, json| Self::put_together_array_json(vec![acc, json]))
+            .unwrap_or(Json::Null);
+        (json, skipped)
+    }
+    /// None if line is blank
+    fn from_line(line: &str) -> serde_json::Result<Option<Self>> {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        serde_json::from_str::<Value>(line).map(|json| Some(Json::from(json)))
+    }
     pub fn put_together(array: Vec<Json>) -> [Json; 1] {
         [Self::put_together_array_json(array)]
     }
@@ -46,7 +83,7 @@ impl Json {
                 for json in array {
                     let Json::Array(v) = json else {
                         println!("array index 0 is array. source array is but array content is not array {:#?}",json);
-                        return Json::Null
+                        return Json::Null;
                     };
                     flated_array.push(Self::put_together_array_json(v))
                 }
@@ -58,6 +95,7 @@ impl Json {
                 }
             }
             JsonType::String => Json::String(String::default()),
+            JsonType::DateTime => Json::DateTime(String::default()),
             JsonType::Boolean => Json::Boolean(bool::default()),
             JsonType::Float64 => Json::Number(Number::Float64(f64::default())),
             JsonType::Usize64 => Json::Number(Number::Usize64(u64::default())),
@@ -118,6 +156,7 @@ pub(crate) enum JsonType {
     Usize64,
     Null,
     String,
+    DateTime,
 }
 impl JsonType {
     fn get_represent_from_array(array: &[Json]) -> &Json {
@@ -149,6 +188,7 @@ impl JsonType {
             Json::Array(_) => Self::Array,
             Json::Null => Self::Null,
             Json::String(_) => Self::String,
+            Json::DateTime(_) => Self::DateTime,
             Json::Boolean(_) => Self::Boolean,
             Json::Number(num) => {
                 if num.is_f64() {
@@ -168,37 +208,41 @@ mod test_count_nest {
     use super::*;
     #[test]
     fn test_case_array_json_contain_mulit_type() {
-        let Json::Array(array_json) = Json::from(r#"[[{"key":"value"}],{"id":0}]"#)else{
+        let Json::Array(array_json) = Json::from(r#"[[{"key":"value"}],{"id":0}]"#) else {
             panic!()
         };
         assert_eq!(Json::put_together_array_json(array_json), Json::Null);
     }
     #[test]
     fn test_case_first_element_is_empty() {
-        let Json::Array(array_json) = Json::from(r#"[
+        let Json::Array(array_json) = Json::from(
+            r#"[
             [],
             [
                 [],
                 [{"key":"value"},{"key":"value2"}],
                 [{"key":"value3"}]
             ]
-        ]"#) else {
+        ]"#,
+        ) else {
             panic!()
-         };
+        };
         assert_eq!(Json::count_array_nest(&array_json), 3);
     }
     #[test]
     fn test_case_double() {
-        let Json::Array(array_json) = Json::from(r#"[[{"key":"value"},{"key":"value2"}],[{"key":"value3"}]]"#) else {
+        let Json::Array(array_json) =
+            Json::from(r#"[[{"key":"value"},{"key":"value2"}],[{"key":"value3"}]]"#)
+        else {
             panic!()
-         };
+        };
         assert_eq!(Json::count_array_nest(&array_json), 2);
     }
     #[test]
     fn test_case_one() {
         let Json::Array(array_json) = Json::from(r#"[{"key":"value"}]"#) else {
             panic!()
-         };
+        };
         assert_eq!(Json::count_array_nest(&array_json), 1);
     }
 }
@@ -628,9 +672,37 @@ impl From<String> for Json {
 
 #[cfg(test)]
 mod test_json {
-    use super::Json;
+    use super::{Json, Number};
     use std::collections::BTreeMap;
     #[test]
+    fn 各行のjsonは一つにまとめられる() {
+        let source = "{\"id\":0,\"name\":\"kai\"}\n\n{\"id\":1,\"tags\":[\"a\"]}\n";
+        let mut map = BTreeMap::new();
+        map.insert("id".to_string(), Json::Number(Number::Usize64(0)));
+        map.insert("name".to_string(), Json::String(String::default()));
+        map.insert(
+            "tags".to_string(),
+            Json::Array(vec![Json::String(String::default())]),
+        );
+
+        assert_eq!(
+            Json::from_lines(source.as_bytes()),
+            (Json::Object(map), vec![])
+        );
+    }
+    #[test]
+    fn jsonでない行は読み飛ばされる() {
+        let source = "{\"id\":0}\n{\"id\":\n{\"id\":1}\n";
+        let mut map = BTreeMap::new();
+        map.insert("id".to_string(), Json::Number(Number::Usize64(0)));
+
+        let (json, skipped) = Json::from_lines(source.as_bytes());
+
+        assert_eq!(json, Json::Object(map));
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].starts_with("line 2 is skipped"));
+    }
+    #[test]
     fn test_from_str_to_json() {
         let source = r#"{"key":"value"}"#;
         let mut map = BTreeMap::new();
@@ -0,0 +1,17 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::AvroDeclarePartGenerator, mapper::AvroMapper,
+    property_part_generator::AvroPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type AvroTypeDescriptionGenerator =
+    TypeDescriptionGenerator<AvroDeclarePartGenerator, AvroPropertyPartGenerator, AvroMapper>;
+
+/// make json string literal
+pub(crate) fn json_string(str: &str) -> String {
+    serde_json::Value::from(str).to_string()
+}
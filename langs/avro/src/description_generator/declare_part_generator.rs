@@ -0,0 +1,348 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor},
+        declare_part_generator::{
+            CustomizableAliasTypeDeclareGenerator, CustomizableCompositeTypeDeclareGenerator,
+            CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use serde_json::Value;
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::mapper::AvroMapper;
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct AvroDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<AvroMapper, ConcatFn, ConcatFn>,
+    namespace: Option<String>,
+    doc: Option<String>,
+}
+impl AvroDeclarePartGenerator {
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("", Self::concat_avro_alias),
+                CustomizableCompositeTypeDeclareGenerator::new("record", Self::concat_avro_record),
+            ),
+            namespace: None,
+            doc: None,
+        }
+    }
+    /// only root alias is meaningful, because avro can not name type except record
+    fn concat_avro_alias(_: &str, _: &TypeName, type_: String) -> String {
+        type_
+    }
+    fn concat_avro_record(identify: &str, type_name: &TypeName, fields: String) -> String {
+        format!(
+            "{{\"type\": \"{}\", \"name\": \"{}\", \"fields\": [\n{}]}}",
+            identify,
+            type_name.valid_lang_str(),
+            fields.trim_end().trim_end_matches(',')
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<AvroMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+    fn record_name(schema: &Value) -> Option<&str> {
+        if schema.get("type")? != "record" {
+            return None;
+        }
+        schema.get("name")?.as_str()
+    }
+    /// add namespace and doc to record
+    fn add_attributes(&self, mut schema: Value) -> Value {
+        if Self::record_name(&schema).is_none() {
+            return schema;
+        }
+        if let Value::Object(record) = &mut schema {
+            if let Some(namespace) = &self.namespace {
+                record.insert("namespace".to_string(), namespace.as_str().into());
+            }
+            if let Some(doc) = &self.doc {
+                record.insert("doc".to_string(), doc.as_str().into());
+            }
+        }
+        schema
+    }
+    /// replace first reference of each record by its definition,
+    /// because avro requires named type to be defined before it is referred
+    fn inline_records(
+        schema: Value,
+        records: &BTreeMap<String, Value>,
+        defined: &mut BTreeSet<String>,
+    ) -> Value {
+        match schema {
+            Value::String(name) => match records.get(&name) {
+                Some(record) if defined.insert(name.clone()) => {
+                    Self::inline_records(record.clone(), records, defined)
+                }
+                _ => Value::String(name),
+            },
+            Value::Array(union) => Value::Array(
+                union
+                    .into_iter()
+                    .map(|schema| Self::inline_records(schema, records, defined))
+                    .collect(),
+            ),
+            Value::Object(mut object) => {
+                let key = match object.get("type").and_then(Value::as_str) {
+                    Some("record") => "fields",
+                    Some("array") => "items",
+                    Some("map") => "values",
+                    _ => return Value::Object(object),
+                };
+                if let Some(schema) = object.remove(key) {
+                    let schema = if key == "fields" {
+                        Self::inline_fields(schema, records, defined)
+                    } else {
+                        Self::inline_records(schema, records, defined)
+                    };
+                    object.insert(key.to_string(), schema);
+                }
+                Value::Object(object)
+            }
+            schema => schema,
+        }
+    }
+    /// type of each field is schema
+    fn inline_fields(
+        fields: Value,
+        records: &BTreeMap<String, Value>,
+        defined: &mut BTreeSet<String>,
+    ) -> Value {
+        let Value::Array(fields) = fields else {
+            return fields;
+        };
+        fields
+            .into_iter()
+            .map(|mut field| {
+                if let Some(schema) = field.get_mut("type") {
+                    *schema = Self::inline_records(schema.take(), records, defined);
+                }
+                field
+            })
+            .collect()
+    }
+}
+impl Default for AvroDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for AvroDeclarePartGenerator {
+    type Mapper = AvroMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    /// empty object is record which has no fields
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        match alias_type.property_type() {
+            PropertyType::Any => self.generate_case_composite(
+                &CompositeTypeStructure::new(alias_type.type_name().clone(), BTreeMap::new()),
+                String::new(),
+            ),
+            _ => self.inner.generate_case_alias(alias_type, mapper),
+        }
+    }
+    /// each description is json of schema. records are inlined into root schema,
+    /// and if some roots are left, schema is union of them
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let schemas = descriptions
+            .iter()
+            .filter(|description| !description.is_empty())
+            .map(|description| {
+                let schema = serde_json::from_str(description).expect("avro schema is json");
+                self.add_attributes(schema)
+            })
+            .collect::<Vec<Value>>();
+        let records = schemas
+            .iter()
+            .filter_map(|schema| {
+                Self::record_name(schema).map(|name| (name.to_string(), schema.clone()))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut defined = BTreeSet::new();
+        let mut roots = Vec::new();
+        for schema in schemas {
+            if let Some(name) = Self::record_name(&schema) {
+                if !defined.insert(name.to_string()) {
+                    continue;
+                }
+            }
+            roots.push(Self::inline_records(schema, &records, &mut defined));
+        }
+        let schema = match roots.len() {
+            0 => return String::new(),
+            1 => roots.remove(0),
+            _ => Value::Array(roots),
+        };
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&schema).unwrap_or_default()
+        )
+    }
+}
+
+pub struct AvroDeclarePartGeneratorBuilder {
+    generator: AvroDeclarePartGenerator,
+}
+impl AvroDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: AvroDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> AvroDeclarePartGenerator {
+        self.generator
+    }
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.generator.namespace = Some(namespace.into());
+        self
+    }
+    /// json can not have comment, so comment is `doc` of record
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        self.generator.doc = Some(comment.into());
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for AvroDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type,
+    };
+
+    use super::*;
+    #[test]
+    fn namespaceを持つrecordとして宣言される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = AvroDeclarePartGeneratorBuilder::new()
+            .namespace("com.example")
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_concat(vec![sut.generate_case_composite(
+            &composite_type,
+            "    {\"name\": \"id\", \"type\": \"long\"},\n".to_string(),
+        )]);
+
+        assert_eq!(
+            result,
+            r#"{
+  "doc": "this is comment",
+  "fields": [
+    {
+      "name": "id",
+      "type": "long"
+    }
+  ],
+  "name": "Test",
+  "namespace": "com.example",
+  "type": "record"
+}
+"#
+        );
+    }
+    #[test]
+    fn recordは最初に参照された場所に展開される() {
+        let mapper = AvroMapper;
+        let sut = AvroDeclarePartGeneratorBuilder::new().build();
+        let array = sut.generate_case_alias(
+            &AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test"))),
+            &mapper,
+        );
+        let test = sut.generate_case_composite(
+            &CompositeTypeStructure::new("Test", BTreeMap::new()),
+            "    {\"name\": \"TestEmpty\", \"type\": \"TestEmpty\"},\n    {\"name\": \"other\", \"type\": [\"null\", \"TestEmpty\"], \"default\": null},\n".to_string(),
+        );
+        let empty = sut.generate_case_alias(
+            &AliasTypeStructure::new("TestEmpty", PropertyType::Any),
+            &mapper,
+        );
+
+        let result = sut.generate_case_concat(vec![array, test, empty]);
+
+        assert_eq!(
+            result,
+            r#"{
+  "items": {
+    "fields": [
+      {
+        "name": "TestEmpty",
+        "type": {
+          "fields": [],
+          "name": "TestEmpty",
+          "type": "record"
+        }
+      },
+      {
+        "default": null,
+        "name": "other",
+        "type": [
+          "null",
+          "TestEmpty"
+        ]
+      }
+    ],
+    "name": "Test",
+    "type": "record"
+  },
+  "type": "array"
+}
+"#
+        );
+    }
+}
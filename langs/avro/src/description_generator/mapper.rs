@@ -0,0 +1,75 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// custom type is referred by name, and definition is inlined at first reference by declare part
+pub struct AvroMapper;
+impl AvroMapper {
+    pub(crate) const NULLABLE_PREFIX: &'static str = "[\"null\", ";
+}
+
+impl TypeMapper for AvroMapper {
+    fn case_string(&self) -> TypeString {
+        "\"string\"".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "\"null\"".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        format!("\"{}\"", custom_type.valid_lang_str())
+    }
+    /// avro has no dynamic type, so any value is nullable string
+    fn case_any(&self) -> TypeString {
+        self.case_optional_type(self.case_string())
+    }
+    fn case_boolean(&self) -> TypeString {
+        "\"boolean\"".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!(
+            "{{\"type\": \"array\", \"items\": {}}}",
+            type_statement.into()
+        )
+    }
+    /// union can not contain union, so already nullable type is not wrapped
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement.starts_with(Self::NULLABLE_PREFIX) {
+            type_statement
+        } else {
+            format!("{}{}]", Self::NULLABLE_PREFIX, type_statement)
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "\"double\"".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "\"long\"".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "\"long\"".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_custom_type, make_optional_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 省略可能な型はnullとのunionになる() {
+        let mapper = AvroMapper;
+
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_custom_type(
+                "Test"
+            )))),
+            r#"["null", {"type": "array", "items": "Test"}]"#
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_any())),
+            r#"["null", "string"]"#
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{AddLastSideConvertor, AddLeftSideConvertor},
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{json_string, mapper::AvroMapper};
+
+pub struct AvroPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, AvroMapper>,
+}
+impl AvroPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = ",\n";
+    pub fn new() -> Self {
+        fn avro_property_concat(key: String, type_: String) -> String {
+            format!("{{\"name\": \"{}\", \"type\": {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(avro_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, doc: Option<String>) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(AvroFieldNameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(AvroFieldConvertor { doc }));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for AvroPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<AvroMapper> for AvroPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &AvroMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct AvroPropertyPartGeneratorBuilder {
+    generator: AvroPropertyPartGenerator,
+    doc: Option<String>,
+}
+impl AvroPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: AvroPropertyPartGenerator::new(),
+            doc: None,
+        }
+    }
+    pub fn build(self) -> AvroPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.doc);
+        generator
+    }
+    /// json can not have comment, so comment is `doc` of field
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        self.doc = Some(comment.into());
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for AvroPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// avro name allows only `[A-Za-z_][A-Za-z0-9_]*`, so only invalid key is renamed
+struct AvroFieldNameConvertor {}
+impl AvroFieldNameConvertor {
+    fn is_valid_name(key: &str) -> bool {
+        let mut chars = key.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        (first.is_ascii_alphabetic() || first == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+}
+impl Convertor<AvroMapper> for AvroFieldNameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &AvroMapper,
+    ) {
+        if Self::is_valid_name(property_key.as_str()) {
+            *acc = property_key.as_str().to_string();
+        }
+    }
+}
+
+/// nullable field has `default: null`, and field object is closed
+struct AvroFieldConvertor {
+    doc: Option<String>,
+}
+impl DescriptionConvertor<AvroMapper> for AvroFieldConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        _: &PropertyKey,
+        _: &PropertyType,
+        _: &AvroMapper,
+    ) -> Option<String> {
+        let mut acc = acc?;
+        if acc.contains(&format!("\"type\": {}", AvroMapper::NULLABLE_PREFIX)) {
+            acc.push_str(", \"default\": null");
+        }
+        if let Some(doc) = &self.doc {
+            acc.push_str(&format!(", \"doc\": {}", json_string(doc)));
+        }
+        acc.push('}');
+        Some(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_type::property_type_factories::{
+            make_custom_type, make_optional_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::AvroMapper, property_part_generator::AvroPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn 省略可能なフィールドはデフォルトがnullになる() {
+        let type_name: TypeName = "Test".into();
+        let mapper = AvroMapper;
+        let generator = AvroPropertyPartGeneratorBuilder::new()
+            .all_comment("this is \"comment\"")
+            .build();
+
+        let child = generator.generate(
+            &type_name,
+            &"user-child".into(),
+            &make_optional_type(make_custom_type("TestUserChild")),
+            &mapper,
+        );
+        let id = generator.generate(&type_name, &"user_id".into(), &make_usize_type(), &mapper);
+
+        assert_eq!(
+            child,
+            "    {\"name\": \"userChild\", \"type\": [\"null\", \"TestUserChild\"], \"default\": null, \"doc\": \"this is \\\"comment\\\"\"},\n"
+        );
+        assert_eq!(
+            id,
+            "    {\"name\": \"user_id\", \"type\": \"long\", \"doc\": \"this is \\\"comment\\\"\"},\n"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{AvroDeclarePartGenerator, AvroDeclarePartGeneratorBuilder},
+    mapper::AvroMapper,
+    property_part_generator::{AvroPropertyPartGenerator, AvroPropertyPartGeneratorBuilder},
+};
+
+pub struct AvroTypeDescriptionGeneratorBuilder {
+    pub declare_part: AvroDeclarePartGeneratorBuilder,
+    pub property_part: AvroPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl AvroTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl AvroTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{namespace,(namespace,impl Into<String>)}
+);
+impl AvroTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: AvroDeclarePartGeneratorBuilder::new(),
+            property_part: AvroPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<AvroDeclarePartGenerator, AvroPropertyPartGenerator, AvroMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, AvroMapper)
+    }
+}
+impl Default for AvroTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+                ("user-name", make_optional_type(make_string_type())),
+            ],
+        );
+        let root_data = TypeStructure::make_composite("RootData", vec![("value", make_any())]);
+        let generator = AvroTypeDescriptionGeneratorBuilder::new()
+            .declare_part_namespace("com.example")
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"{
+  "fields": [
+    {
+      "name": "data",
+      "type": {
+        "items": {
+          "fields": [
+            {
+              "default": null,
+              "name": "value",
+              "type": [
+                "null",
+                "string"
+              ]
+            }
+          ],
+          "name": "RootData",
+          "namespace": "com.example",
+          "type": "record"
+        },
+        "type": "array"
+      }
+    },
+    {
+      "name": "id",
+      "type": "long"
+    },
+    {
+      "default": null,
+      "name": "userName",
+      "type": [
+        "null",
+        "string"
+      ]
+    }
+  ],
+  "name": "Root",
+  "namespace": "com.example",
+  "type": "record"
+}
+"#
+        );
+    }
+}
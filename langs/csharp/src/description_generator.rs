@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::CSharpDeclarePartGenerator, mapper::CSharpMapper,
+    property_part_generator::CSharpPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type CSharpTypeDescriptionGenerator =
+    TypeDescriptionGenerator<CSharpDeclarePartGenerator, CSharpPropertyPartGenerator, CSharpMapper>;
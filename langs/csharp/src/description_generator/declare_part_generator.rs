@@ -0,0 +1,580 @@
+use std::str::FromStr;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::mapper::CSharpMapper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CSharpTypeStyle {
+    /// class with auto implemented properties
+    #[default]
+    Class,
+    /// positional record
+    Record,
+}
+impl FromStr for CSharpTypeStyle {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "class" | "Class" => Ok(Self::Class),
+            "record" | "Record" => Ok(Self::Record),
+            _ => Err(format!("{} is not define c# type style", str)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CSharpSerializer {
+    /// System.Text.Json `[JsonPropertyName]`
+    #[default]
+    SystemTextJson,
+    /// Newtonsoft.Json `[JsonProperty]`
+    Newtonsoft,
+}
+impl CSharpSerializer {
+    pub(crate) fn attribute(&self, original: &str) -> String {
+        match self {
+            Self::SystemTextJson => format!("JsonPropertyName(\"{}\")", original),
+            Self::Newtonsoft => format!("JsonProperty(\"{}\")", original),
+        }
+    }
+    fn enum_member_attribute(&self, original: &str) -> String {
+        match self {
+            Self::SystemTextJson => format!("JsonStringEnumMemberName({:?})", original),
+            Self::Newtonsoft => format!("EnumMember(Value = {:?})", original),
+        }
+    }
+    fn enum_converter(&self) -> &'static str {
+        match self {
+            Self::SystemTextJson => "JsonStringEnumConverter",
+            Self::Newtonsoft => "StringEnumConverter",
+        }
+    }
+}
+impl FromStr for CSharpSerializer {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "system_text_json" | "System.Text.Json" | "stj" => Ok(Self::SystemTextJson),
+            "newtonsoft" | "Newtonsoft" | "Newtonsoft.Json" => Ok(Self::Newtonsoft),
+            _ => Err(format!("{} is not define c# serializer", str)),
+        }
+    }
+}
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct CSharpDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<CSharpMapper, ConcatFn, ConcatFn>,
+    namespace: Option<String>,
+    serializer: CSharpSerializer,
+}
+impl CSharpDeclarePartGenerator {
+    const IMPORTS: [(&'static str, &'static str); 12] = [
+        (", Type ", "using System;"),
+        ("List<", "using System.Collections.Generic;"),
+        ("[EnumMember(", "using System.Runtime.Serialization;"),
+        ("JsonElement ", "using System.Text.Json;"),
+        ("JsonPropertyName(", "using System.Text.Json.Serialization;"),
+        (
+            "[JsonStringEnumMemberName(",
+            "using System.Text.Json.Serialization;",
+        ),
+        (": JsonConverter<", "using System.Text.Json.Serialization;"),
+        ("JsonProperty(", "using Newtonsoft.Json;"),
+        ("JsonSerializer serializer)", "using Newtonsoft.Json;"),
+        ("(typeof(StringEnumConverter))", "using Newtonsoft.Json;"),
+        (
+            "(typeof(StringEnumConverter))",
+            "using Newtonsoft.Json.Converters;",
+        ),
+        ("JToken", "using Newtonsoft.Json.Linq;"),
+    ];
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new(
+                    "public class",
+                    Self::concat_csharp_alias,
+                ),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "public class",
+                    Self::concat_csharp_class,
+                ),
+            ),
+            namespace: None,
+            serializer: CSharpSerializer::default(),
+        }
+    }
+    /// c# has no generic type alias, so array alias is declared as subclass of List
+    fn concat_csharp_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!(
+            "{} {} : {}\n{{\n}}",
+            identify,
+            type_name.valid_lang_str(),
+            type_
+        )
+    }
+    fn concat_csharp_class(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {}\n{{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    /// literal enum is c# enum which is (de)serialized as string of literal.
+    /// `JsonStringEnumMemberName` of System.Text.Json requires .NET 9
+    fn concat_csharp_enum(
+        &self,
+        type_name: &str,
+        values: &[String],
+        names: Vec<TypeName>,
+    ) -> String {
+        let members = values
+            .iter()
+            .zip(names)
+            .map(|(value, name)| {
+                format!(
+                    "    [{}]\n    {},\n",
+                    self.serializer.enum_member_attribute(value),
+                    name.valid_lang_str()
+                )
+            })
+            .collect::<String>();
+        format!(
+            "[JsonConverter(typeof({}))]\npublic enum {}\n{{\n{}}}",
+            self.serializer.enum_converter(),
+            type_name,
+            members
+        )
+    }
+    /// c# has no union type, so union is class which holds json value as it is.
+    /// the value is read and written by converter of the class
+    fn concat_csharp_union(&self, type_name: &str) -> String {
+        match self.serializer {
+            CSharpSerializer::SystemTextJson => format!(
+                r#"[JsonConverter(typeof({name}JsonConverter))]
+public class {name}
+{{
+    public JsonElement Value {{ get; set; }}
+}}
+
+public class {name}JsonConverter : JsonConverter<{name}>
+{{
+    public override {name} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+        new {name} {{ Value = JsonElement.ParseValue(ref reader) }};
+
+    public override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options) =>
+        value.Value.WriteTo(writer);
+}}"#,
+                name = type_name
+            ),
+            CSharpSerializer::Newtonsoft => format!(
+                r#"[JsonConverter(typeof({name}JsonConverter))]
+public class {name}
+{{
+    public JToken? Value {{ get; set; }}
+}}
+
+public class {name}JsonConverter : JsonConverter<{name}>
+{{
+    public override {name} ReadJson(JsonReader reader, Type objectType, {name}? existingValue, bool hasExistingValue, JsonSerializer serializer) =>
+        new {name} {{ Value = JToken.Load(reader) }};
+
+    public override void WriteJson(JsonWriter writer, {name}? value, JsonSerializer serializer) =>
+        (value?.Value ?? JValue.CreateNull()).WriteTo(writer);
+}}"#,
+                name = type_name
+            ),
+        }
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<CSharpMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for CSharpDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for CSharpDeclarePartGenerator {
+    type Mapper = CSharpMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        match alias_type.property_type() {
+            PropertyType::Array(_) => self.inner.generate_case_alias(alias_type, mapper),
+            _ => String::new(),
+        }
+    }
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, _: &Self::Mapper) -> String {
+        let type_name = enum_type.type_name().valid_lang_str();
+        match enum_type.representation() {
+            EnumRepresentation::Literal(values) => {
+                self.concat_csharp_enum(&type_name, values, enum_type.variant_names())
+            }
+            EnumRepresentation::Tagged { .. } | EnumRepresentation::Untagged(_) => {
+                self.concat_csharp_union(&type_name)
+            }
+        }
+    }
+    /// add usings, nullable context and namespace.
+    /// nullable context is enabled so that `T?` of reference type is valid
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let body = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        let usings = Self::IMPORTS
+            .iter()
+            .filter(|(used, _)| body.contains(used))
+            .fold(String::new(), |acc, (_, using)| {
+                if acc.lines().any(|line| line == *using) {
+                    acc
+                } else {
+                    format!("{}{}\n", acc, using)
+                }
+            });
+        let namespace = self
+            .namespace
+            .as_ref()
+            .map(|namespace| format!("namespace {};\n\n", namespace))
+            .unwrap_or_default();
+        if usings.is_empty() {
+            format!("#nullable enable\n\n{}{}\n", namespace, body)
+        } else {
+            format!("{}\n#nullable enable\n\n{}{}\n", usings, namespace, body)
+        }
+    }
+}
+
+pub struct CSharpDeclarePartGeneratorBuilder {
+    generator: CSharpDeclarePartGenerator,
+    style: CSharpTypeStyle,
+}
+impl CSharpDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: CSharpDeclarePartGenerator::new(),
+            style: CSharpTypeStyle::default(),
+        }
+    }
+    pub fn build(self) -> CSharpDeclarePartGenerator {
+        let mut generator = self.generator;
+        if self.style == CSharpTypeStyle::Record {
+            generator
+                .change_composite_generator()
+                .add_description_convertor(Box::new(CSharpRecordConvertor {}));
+        }
+        generator
+    }
+    pub fn style(mut self, style: CSharpTypeStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.generator.namespace = Some(namespace.into());
+        self
+    }
+    /// json library which (de)serializes enum
+    pub fn serializer(mut self, serializer: CSharpSerializer) -> Self {
+        self.generator.serializer = serializer;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for CSharpDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// rewrite `public class Name {...}` to positional record
+struct CSharpRecordConvertor {}
+impl CompositeTypeDeclareConvertor for CSharpRecordConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let name = composite_type.type_name().valid_lang_str();
+        let class_line = format!("public class {}\n{{\n", name);
+        let Some(start) = acc.find(&class_line) else {
+            return Some(acc);
+        };
+        let (header, declare) = acc.split_at(start);
+        let components = declare[class_line.len()..]
+            .trim_end_matches('}')
+            .trim_end_matches(",\n");
+        if components.is_empty() {
+            Some(format!("{}public record {}();", header, name))
+        } else {
+            Some(format!(
+                "{}public record {}(\n{}\n);",
+                header, name, components
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていないスタイルはエラーになる() {
+        assert_eq!(
+            CSharpTypeStyle::from_str("record"),
+            Ok(CSharpTypeStyle::Record)
+        );
+        assert!(CSharpTypeStyle::from_str("foo").is_err());
+    }
+    #[test]
+    fn 定義されていないシリアライザはエラーになる() {
+        assert_eq!(
+            CSharpSerializer::from_str("newtonsoft"),
+            Ok(CSharpSerializer::Newtonsoft)
+        );
+        assert!(CSharpSerializer::from_str("foo").is_err());
+    }
+    fn make_composite() -> CompositeTypeStructure {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        CompositeTypeStructure::new("Test", properties)
+    }
+    #[test]
+    fn classはブロックで宣言される() {
+        let sut = CSharpDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "    [JsonPropertyName(\"id\")]\n    public required long Id { get; set; }\n"
+                .to_string(),
+        );
+
+        assert_eq!(
+            result,
+            "// this is comment\npublic class Test\n{\n    [JsonPropertyName(\"id\")]\n    public required long Id { get; set; }\n}"
+        );
+    }
+    #[test]
+    fn recordは位置指定パラメータで宣言される() {
+        let sut = CSharpDeclarePartGeneratorBuilder::new()
+            .style(CSharpTypeStyle::Record)
+            .build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "    [property: JsonPropertyName(\"id\")] long Id,\n".to_string(),
+        );
+        let empty = sut.generate_case_composite(&make_composite(), String::new());
+
+        assert_eq!(
+            result,
+            "public record Test(\n    [property: JsonPropertyName(\"id\")] long Id\n);"
+        );
+        assert_eq!(empty, "public record Test();");
+    }
+    #[test]
+    fn 配列のaliasはlistを継承し配列以外は出力しない() {
+        let mapper = CSharpMapper;
+        let sut = CSharpDeclarePartGeneratorBuilder::new().build();
+
+        let array = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let primitive = AliasTypeStructure::new("TestId", make_usize_type());
+
+        assert_eq!(
+            sut.generate_case_alias(&array, &mapper),
+            "public class TestArray : List<Test>\n{\n}"
+        );
+        assert_eq!(sut.generate_case_alias(&primitive, &mapper), "");
+    }
+    #[test]
+    fn リテラルのenumは文字列として変換されるenumになる() {
+        let mapper = CSharpMapper;
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["active".to_string(), "in_progress".to_string()]),
+        );
+        let system_text_json = CSharpDeclarePartGeneratorBuilder::new().build();
+        let newtonsoft = CSharpDeclarePartGeneratorBuilder::new()
+            .serializer(CSharpSerializer::Newtonsoft)
+            .build();
+
+        assert_eq!(
+            system_text_json
+                .generate_case_concat(vec![system_text_json.generate_case_enum(&literal, &mapper)]),
+            r#"using System.Text.Json.Serialization;
+
+#nullable enable
+
+[JsonConverter(typeof(JsonStringEnumConverter))]
+public enum Status
+{
+    [JsonStringEnumMemberName("active")]
+    Active,
+    [JsonStringEnumMemberName("in_progress")]
+    InProgress,
+}
+"#
+        );
+        assert_eq!(
+            newtonsoft.generate_case_concat(vec![newtonsoft.generate_case_enum(&literal, &mapper)]),
+            r#"using System.Runtime.Serialization;
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+
+#nullable enable
+
+[JsonConverter(typeof(StringEnumConverter))]
+public enum Status
+{
+    [EnumMember(Value = "active")]
+    Active,
+    [EnumMember(Value = "in_progress")]
+    InProgress,
+}
+"#
+        );
+    }
+    #[test]
+    fn unionはjson値を持つクラスとそのコンバータになる() {
+        let mapper = CSharpMapper;
+        let untagged = EnumTypeStructure::new(
+            "Id",
+            EnumRepresentation::Untagged(vec![make_usize_type(), make_custom_type("Test")]),
+        );
+        let sut = CSharpDeclarePartGeneratorBuilder::new().build();
+
+        assert_eq!(
+            sut.generate_case_concat(vec![sut.generate_case_enum(&untagged, &mapper)]),
+            r#"using System;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+#nullable enable
+
+[JsonConverter(typeof(IdJsonConverter))]
+public class Id
+{
+    public JsonElement Value { get; set; }
+}
+
+public class IdJsonConverter : JsonConverter<Id>
+{
+    public override Id Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+        new Id { Value = JsonElement.ParseValue(ref reader) };
+
+    public override void Write(Utf8JsonWriter writer, Id value, JsonSerializerOptions options) =>
+        value.Value.WriteTo(writer);
+}
+"#
+        );
+    }
+    #[test]
+    fn usingとnamespaceが先頭に付与される() {
+        let sut = CSharpDeclarePartGeneratorBuilder::new()
+            .namespace("Example")
+            .build();
+
+        let result = sut.generate_case_concat(vec![
+            "public class Test\n{\n    [JsonProperty(\"names\")]\n    public required List<string> Names { get; set; }\n}"
+                .to_string(),
+        ]);
+
+        assert_eq!(
+            result,
+            r#"using System.Collections.Generic;
+using Newtonsoft.Json;
+
+#nullable enable
+
+namespace Example;
+
+public class Test
+{
+    [JsonProperty("names")]
+    public required List<string> Names { get; set; }
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+pub struct CSharpMapper;
+
+impl TypeMapper for CSharpMapper {
+    fn case_string(&self) -> TypeString {
+        "string".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "object?".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "object".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "bool".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("List<{}>", type_statement.into())
+    }
+    /// nullable reference types and nullable value types are both written as `T?`
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement.ends_with('?') {
+            type_statement
+        } else {
+            format!("{}?", type_statement)
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "long".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "long".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_optional_type, make_string_type, make_usize_type,
+    };
+
+    use super::CSharpMapper;
+
+    #[test]
+    fn optionalはnullable注釈で表現される() {
+        let mapper = CSharpMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_optional_type(
+                make_usize_type()
+            )))),
+            "List<long?>?"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_string_type())),
+            "string?"
+        );
+    }
+}
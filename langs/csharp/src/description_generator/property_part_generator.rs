@@ -0,0 +1,248 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use npc::fns::to_pascal;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{
+    declare_part_generator::{CSharpSerializer, CSharpTypeStyle},
+    mapper::CSharpMapper,
+};
+
+pub struct CSharpPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, CSharpMapper>,
+}
+impl CSharpPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    pub fn new() -> Self {
+        fn csharp_property_concat(key: String, type_: String) -> String {
+            format!("{} {}", type_, key)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(csharp_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, style: CSharpTypeStyle, serializer: CSharpSerializer) {
+        self.generator
+            .add_property_key_convertor(Box::new(CSharpRenameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(CSharpPropertyConvertor { style, serializer }));
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(match style {
+            CSharpTypeStyle::Record => ",\n",
+            CSharpTypeStyle::Class => "\n",
+        });
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for CSharpPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<CSharpMapper> for CSharpPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &CSharpMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct CSharpPropertyPartGeneratorBuilder {
+    generator: CSharpPropertyPartGenerator,
+    style: CSharpTypeStyle,
+    serializer: CSharpSerializer,
+}
+impl CSharpPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: CSharpPropertyPartGenerator::new(),
+            style: CSharpTypeStyle::default(),
+            serializer: CSharpSerializer::default(),
+        }
+    }
+    pub fn build(self) -> CSharpPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.style, self.serializer);
+        generator
+    }
+    pub fn style(mut self, style: CSharpTypeStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn serializer(mut self, serializer: CSharpSerializer) -> Self {
+        self.serializer = serializer;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for CSharpPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// property name used in c# type. original key is converted to pascal case
+fn csharp_property_name(property_key: &PropertyKey) -> String {
+    to_pascal(&property_key.invalid_lang_str())
+}
+
+struct CSharpRenameConvertor {}
+impl Convertor<CSharpMapper> for CSharpRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &CSharpMapper,
+    ) {
+        *acc = csharp_property_name(property_key);
+    }
+}
+
+/// make property declaration of each style.
+/// serializer attribute is added when property name is not same as original key.
+/// non-nullable property of class is `required` (C# 11), otherwise nullable context warns it (CS8618)
+struct CSharpPropertyConvertor {
+    style: CSharpTypeStyle,
+    serializer: CSharpSerializer,
+}
+impl DescriptionConvertor<CSharpMapper> for CSharpPropertyConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        _: &CSharpMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        // header lines (for example comments) are kept above property
+        let (header, property) = match acc.rfind('\n') {
+            Some(index) => acc.split_at(index + 1),
+            None => ("", acc.as_str()),
+        };
+        let attribute = if csharp_property_name(property_key) == property_key.as_str() {
+            None
+        } else {
+            Some(self.serializer.attribute(property_key.as_str()))
+        };
+        let modifier = match property_type {
+            PropertyType::Optional(_) => "",
+            _ => "required ",
+        };
+        match (self.style, attribute) {
+            (CSharpTypeStyle::Record, None) => Some(acc),
+            (CSharpTypeStyle::Record, Some(attribute)) => {
+                Some(format!("{}[property: {}] {}", header, attribute, property))
+            }
+            (CSharpTypeStyle::Class, None) => Some(format!(
+                "{}public {}{} {{ get; set; }}",
+                header, modifier, property
+            )),
+            (CSharpTypeStyle::Class, Some(attribute)) => Some(format!(
+                "{}[{}]\npublic {}{} {{ get; set; }}",
+                header, attribute, modifier, property
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{make_optional_type, make_string_type},
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        declare_part_generator::{CSharpSerializer, CSharpTypeStyle},
+        mapper::CSharpMapper,
+        property_part_generator::CSharpPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn classではjson_property_name属性を持つ自動実装プロパティになる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user_name".into();
+        let property_type = make_optional_type(make_string_type());
+        let mapper = CSharpMapper;
+        let generator = CSharpPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    // this is comment\n    [JsonPropertyName(\"user_name\")]\n    public string? UserName { get; set; }\n"
+        );
+    }
+    #[test]
+    fn classのnullでないプロパティはrequiredになる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "Name".into();
+        let mapper = CSharpMapper;
+        let generator = CSharpPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &make_string_type(), &mapper);
+
+        assert_eq!(result, "    public required string Name { get; set; }\n");
+    }
+    #[test]
+    fn recordではnewtonsoftの属性がプロパティを対象に付与される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "userName".into();
+        let property_type = make_string_type();
+        let mapper = CSharpMapper;
+        let generator = CSharpPropertyPartGeneratorBuilder::new()
+            .style(CSharpTypeStyle::Record)
+            .serializer(CSharpSerializer::Newtonsoft)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    [property: JsonProperty(\"userName\")] string UserName,\n"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        CSharpDeclarePartGenerator, CSharpDeclarePartGeneratorBuilder, CSharpSerializer,
+        CSharpTypeStyle,
+    },
+    mapper::CSharpMapper,
+    property_part_generator::{CSharpPropertyPartGenerator, CSharpPropertyPartGeneratorBuilder},
+};
+
+pub struct CSharpTypeDescriptionGeneratorBuilder {
+    pub declare_part: CSharpDeclarePartGeneratorBuilder,
+    pub property_part: CSharpPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl CSharpTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl CSharpTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{namespace,(namespace,impl Into<String>)}
+);
+impl CSharpTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: CSharpDeclarePartGeneratorBuilder::new(),
+            property_part: CSharpPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    /// set type style (class or record) to both declare part and property part
+    pub fn style(mut self, style: CSharpTypeStyle) -> Self {
+        self.declare_part = self.declare_part.style(style);
+        self.property_part = self.property_part.style(style);
+        self
+    }
+    pub fn class(self) -> Self {
+        self.style(CSharpTypeStyle::Class)
+    }
+    pub fn record(self) -> Self {
+        self.style(CSharpTypeStyle::Record)
+    }
+    /// set json library which attributes are used for renamed properties and enums
+    pub fn serializer(mut self, serializer: CSharpSerializer) -> Self {
+        self.declare_part = self.declare_part.serializer(serializer);
+        self.property_part = self.property_part.serializer(serializer);
+        self
+    }
+    pub fn system_text_json(self) -> Self {
+        self.serializer(CSharpSerializer::SystemTextJson)
+    }
+    pub fn newtonsoft(self) -> Self {
+        self.serializer(CSharpSerializer::Newtonsoft)
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        CSharpDeclarePartGenerator,
+        CSharpPropertyPartGenerator,
+        CSharpMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, CSharpMapper)
+    }
+}
+impl Default for CSharpTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_record() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![("user-name", make_optional_type(make_string_type()))],
+        );
+        let generator = CSharpTypeDescriptionGeneratorBuilder::new()
+            .record()
+            .newtonsoft()
+            .declare_part_namespace("Example")
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"using System.Collections.Generic;
+using Newtonsoft.Json;
+
+#nullable enable
+
+namespace Example;
+
+public record Root(
+    [property: JsonProperty("data")] List<RootData> Data,
+    [property: JsonProperty("id")] long Id
+);
+
+public record RootData(
+    [property: JsonProperty("user-name")] string? UserName
+);
+"#
+        );
+    }
+}
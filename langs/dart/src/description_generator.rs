@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::DartDeclarePartGenerator, mapper::DartMapper,
+    property_part_generator::DartPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type DartTypeDescriptionGenerator =
+    TypeDescriptionGenerator<DartDeclarePartGenerator, DartPropertyPartGenerator, DartMapper>;
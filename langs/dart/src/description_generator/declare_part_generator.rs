@@ -0,0 +1,391 @@
+use std::str::FromStr;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::{mapper::DartMapper, property_part_generator::dart_field_name};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DartClassStyle {
+    /// class with `@JsonSerializable()` and `fromJson`/`toJson`
+    #[default]
+    JsonSerializable,
+    /// immutable class with `@freezed`
+    Freezed,
+}
+impl DartClassStyle {
+    fn import(&self) -> &'static str {
+        match self {
+            Self::JsonSerializable => "import 'package:json_annotation/json_annotation.dart';",
+            Self::Freezed => "import 'package:freezed_annotation/freezed_annotation.dart';",
+        }
+    }
+    fn parts(&self, part: &str) -> String {
+        match self {
+            Self::JsonSerializable => format!("part '{}.g.dart';", part),
+            Self::Freezed => format!("part '{}.freezed.dart';\npart '{}.g.dart';", part, part),
+        }
+    }
+}
+impl FromStr for DartClassStyle {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "json_serializable" | "JsonSerializable" | "json" => Ok(Self::JsonSerializable),
+            "freezed" | "Freezed" => Ok(Self::Freezed),
+            _ => Err(format!("{} is not define dart class style", str)),
+        }
+    }
+}
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct DartDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<DartMapper, ConcatFn, ConcatFn>,
+    style: DartClassStyle,
+    part: Option<String>,
+}
+impl DartDeclarePartGenerator {
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("typedef", Self::concat_dart_alias),
+                CustomizableCompositeTypeDeclareGenerator::new("class", Self::concat_dart_class),
+            ),
+            style: DartClassStyle::default(),
+            part: None,
+        }
+    }
+    fn concat_dart_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{} {} = {};", identify, type_name.valid_lang_str(), type_)
+    }
+    fn concat_dart_class(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {} {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<DartMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for DartDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for DartDeclarePartGenerator {
+    type Mapper = DartMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// add annotation import and part directives of generated code.
+    /// part directives need name of output file, so they are added only when part is set
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let body = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        match self.part.as_ref() {
+            Some(part) => format!(
+                "{}\n\n{}\n\n{}\n",
+                self.style.import(),
+                self.style.parts(part),
+                body
+            ),
+            None => format!("{}\n\n{}\n", self.style.import(), body),
+        }
+    }
+}
+
+pub struct DartDeclarePartGeneratorBuilder {
+    generator: DartDeclarePartGenerator,
+}
+impl DartDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: DartDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> DartDeclarePartGenerator {
+        let mut generator = self.generator;
+        let style = generator.style;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(DartClassStyleConvertor { style }));
+        generator
+    }
+    pub fn style(mut self, style: DartClassStyle) -> Self {
+        self.generator.style = style;
+        self
+    }
+    /// file name without extension, which is used in part directives (for example `part 'user.g.dart';`)
+    pub fn part(mut self, part: impl Into<String>) -> Self {
+        self.generator.part = Some(part.into());
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for DartDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// rewrite `class Name {...}` to declaration of each style with `fromJson`/`toJson`
+struct DartClassStyleConvertor {
+    style: DartClassStyle,
+}
+impl DartClassStyleConvertor {
+    fn constructor(composite_type: &CompositeTypeStructure, body: &str) -> String {
+        let name = composite_type.type_name().valid_lang_str();
+        let params = composite_type
+            .iter()
+            .map(|(key, property_type)| (dart_field_name(key), property_type))
+            // fields removed by property part (for example blacklist) are not initialized
+            .filter(|(field, _)| body.contains(&format!(" {};\n", field)))
+            .fold(
+                String::new(),
+                |acc, (field, property_type)| match property_type {
+                    PropertyType::Optional(_) => format!("{}    this.{},\n", acc, field),
+                    _ => format!("{}    required this.{},\n", acc, field),
+                },
+            );
+        if params.is_empty() {
+            format!("  {}();\n", name)
+        } else {
+            format!("  {}({{\n{}  }});\n", name, params)
+        }
+    }
+}
+impl CompositeTypeDeclareConvertor for DartClassStyleConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let name = composite_type.type_name().valid_lang_str();
+        let class_line = format!("class {} {{\n", name);
+        let Some(start) = acc.find(&class_line) else {
+            return Some(acc);
+        };
+        let (header, declare) = acc.split_at(start);
+        let body = declare[class_line.len()..].trim_end_matches('}');
+        let from_json = format!(
+            "  factory {}.fromJson(Map<String, dynamic> json) => _${}FromJson(json);\n",
+            name, name
+        );
+        match self.style {
+            DartClassStyle::JsonSerializable => {
+                let fields = if body.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}\n", body)
+                };
+                Some(format!(
+                    "{}@JsonSerializable()\n{}{}{}\n{}\n  Map<String, dynamic> toJson() => _${}ToJson(this);\n}}",
+                    header,
+                    class_line,
+                    fields,
+                    Self::constructor(composite_type, body),
+                    from_json,
+                    name
+                ))
+            }
+            DartClassStyle::Freezed => {
+                let factory = if body.is_empty() {
+                    format!("  const factory {}() = _{};\n", name, name)
+                } else {
+                    format!("  const factory {}({{\n{}  }}) = _{};\n", name, body, name)
+                };
+                Some(format!(
+                    "{}@freezed\nclass {} with _${} {{\n{}\n{}}}",
+                    header, name, name, factory, from_json
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていないスタイルはエラーになる() {
+        assert_eq!(
+            DartClassStyle::from_str("freezed"),
+            Ok(DartClassStyle::Freezed)
+        );
+        assert!(DartClassStyle::from_str("foo").is_err());
+    }
+    fn make_composite() -> CompositeTypeStructure {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        properties.insert("user_name".into(), make_optional_type(make_string_type()));
+        CompositeTypeStructure::new("Test", properties)
+    }
+    #[test]
+    fn json_serializableはコンストラクタとfrom_jsonとto_jsonを持つ() {
+        let sut = DartDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "  final int id;\n  @JsonKey(name: 'user_name')\n  final String? userName;\n"
+                .to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"// this is comment
+@JsonSerializable()
+class Test {
+  final int id;
+  @JsonKey(name: 'user_name')
+  final String? userName;
+
+  Test({
+    required this.id,
+    this.userName,
+  });
+
+  factory Test.fromJson(Map<String, dynamic> json) => _$TestFromJson(json);
+
+  Map<String, dynamic> toJson() => _$TestToJson(this);
+}"#
+        );
+    }
+    #[test]
+    fn freezedはfactoryコンストラクタで宣言される() {
+        let sut = DartDeclarePartGeneratorBuilder::new()
+            .style(DartClassStyle::Freezed)
+            .build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "    required int id,\n    @JsonKey(name: 'user_name') String? userName,\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"@freezed
+class Test with _$Test {
+  const factory Test({
+    required int id,
+    @JsonKey(name: 'user_name') String? userName,
+  }) = _Test;
+
+  factory Test.fromJson(Map<String, dynamic> json) => _$TestFromJson(json);
+}"#
+        );
+    }
+    #[test]
+    fn alias型はtypedefとして出力される() {
+        let mapper = DartMapper;
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = DartDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "typedef TestArray = List<Test>;");
+    }
+    #[test]
+    fn partを指定した場合part指令が付与される() {
+        let sut = DartDeclarePartGeneratorBuilder::new()
+            .style(DartClassStyle::Freezed)
+            .part("test")
+            .build();
+
+        let result = sut.generate_case_concat(vec!["typedef TestArray = List<Test>;".to_string()]);
+
+        assert_eq!(
+            result,
+            r#"import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'test.freezed.dart';
+part 'test.g.dart';
+
+typedef TestArray = List<Test>;
+"#
+        );
+    }
+}
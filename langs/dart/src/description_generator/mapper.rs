@@ -0,0 +1,66 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+pub struct DartMapper;
+
+impl TypeMapper for DartMapper {
+    fn case_string(&self) -> TypeString {
+        "String".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "dynamic".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "dynamic".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "bool".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("List<{}>", type_statement.into())
+    }
+    /// dynamic is already nullable
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement == "dynamic" || type_statement.ends_with('?') {
+            type_statement
+        } else {
+            format!("{}?", type_statement)
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "int".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "int".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_optional_type, make_usize_type,
+    };
+
+    use super::DartMapper;
+
+    #[test]
+    fn optionalはnullable型で表現されdynamicはそのまま() {
+        let mapper = DartMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_usize_type()))),
+            "List<int?>"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_any())),
+            "dynamic"
+        );
+    }
+}
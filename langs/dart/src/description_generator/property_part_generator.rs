@@ -0,0 +1,249 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use npc::fns::to_camel;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{declare_part_generator::DartClassStyle, mapper::DartMapper};
+
+pub struct DartPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, DartMapper>,
+}
+impl DartPropertyPartGenerator {
+    pub fn new() -> Self {
+        fn dart_property_concat(key: String, type_: String) -> String {
+            format!("{} {}", type_, key)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(dart_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, style: DartClassStyle) {
+        self.generator
+            .add_property_key_convertor(Box::new(DartRenameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(DartFieldConvertor { style }));
+        // fields are declared in class body, and freezed parameters are declared in factory constructor
+        let (indent, last) = match style {
+            DartClassStyle::JsonSerializable => ("  ", ";\n"),
+            DartClassStyle::Freezed => ("    ", ",\n"),
+        };
+        let mut add_space_convertor = AddLeftSideConvertor::new(indent);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(last);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for DartPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<DartMapper> for DartPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &DartMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct DartPropertyPartGeneratorBuilder {
+    generator: DartPropertyPartGenerator,
+    style: DartClassStyle,
+}
+impl DartPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: DartPropertyPartGenerator::new(),
+            style: DartClassStyle::default(),
+        }
+    }
+    pub fn build(self) -> DartPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.style);
+        generator
+    }
+    pub fn style(mut self, style: DartClassStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for DartPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// field name used in dart class. original key is converted to camel case
+pub(crate) fn dart_field_name(property_key: &PropertyKey) -> String {
+    let name = to_camel(&property_key.invalid_lang_str());
+    if DartReservedWords::is_keyword(&name) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+/// `$` and `'` in original key are escaped, because `$` is used for string interpolation
+fn json_key_annotation(original: &str) -> String {
+    format!(
+        "@JsonKey(name: '{}')",
+        original
+            .replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('$', "\\$")
+    )
+}
+
+struct DartRenameConvertor {}
+impl Convertor<DartMapper> for DartRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &DartMapper,
+    ) {
+        *acc = dart_field_name(property_key);
+    }
+}
+
+/// make field or freezed parameter declaration.
+/// `@JsonKey` is added when field name is not same as original key
+struct DartFieldConvertor {
+    style: DartClassStyle,
+}
+impl DescriptionConvertor<DartMapper> for DartFieldConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        _: &DartMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        // header lines (for example comments) are kept above field
+        let (header, field) = match acc.rfind('\n') {
+            Some(index) => acc.split_at(index + 1),
+            None => ("", acc.as_str()),
+        };
+        let annotation = if dart_field_name(property_key) == property_key.as_str() {
+            None
+        } else {
+            Some(json_key_annotation(property_key.as_str()))
+        };
+        let required = if matches!(property_type, PropertyType::Optional(_)) {
+            ""
+        } else {
+            "required "
+        };
+        match (self.style, annotation) {
+            (DartClassStyle::JsonSerializable, None) => Some(format!("{}final {}", header, field)),
+            (DartClassStyle::JsonSerializable, Some(annotation)) => {
+                Some(format!("{}{}\nfinal {}", header, annotation, field))
+            }
+            (DartClassStyle::Freezed, None) => Some(format!("{}{}{}", header, required, field)),
+            (DartClassStyle::Freezed, Some(annotation)) => {
+                Some(format!("{}{} {}{}", header, annotation, required, field))
+            }
+        }
+    }
+}
+
+struct DartReservedWords;
+impl DartReservedWords {
+    const KEYWORDS: [&'static str; 33] = [
+        "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+        "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+        "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void",
+        "while", "with",
+    ];
+    fn is_keyword(word: &str) -> bool {
+        Self::KEYWORDS.contains(&word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        declare_part_generator::DartClassStyle, mapper::DartMapper,
+        property_part_generator::DartPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn フィールドはfinalで宣言されキー名が変わる場合json_keyを付与する() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user_name".into();
+        let property_type = make_optional_type(make_string_type());
+        let mapper = DartMapper;
+        let generator = DartPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "  // this is comment\n  @JsonKey(name: 'user_name')\n  final String? userName;\n"
+        );
+    }
+    #[test]
+    fn freezedでは必須のパラメータとして宣言される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "class".into();
+        let property_type = make_usize_type();
+        let mapper = DartMapper;
+        let generator = DartPropertyPartGeneratorBuilder::new()
+            .style(DartClassStyle::Freezed)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    @JsonKey(name: 'class') required int class_,\n");
+    }
+}
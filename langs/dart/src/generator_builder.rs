@@ -0,0 +1,153 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        DartClassStyle, DartDeclarePartGenerator, DartDeclarePartGeneratorBuilder,
+    },
+    mapper::DartMapper,
+    property_part_generator::{DartPropertyPartGenerator, DartPropertyPartGeneratorBuilder},
+};
+
+pub struct DartTypeDescriptionGeneratorBuilder {
+    pub declare_part: DartDeclarePartGeneratorBuilder,
+    pub property_part: DartPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl DartTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl DartTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{part,(part,impl Into<String>)}
+);
+impl DartTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: DartDeclarePartGeneratorBuilder::new(),
+            property_part: DartPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    /// set class style (json_serializable or freezed) to both declare part and property part
+    pub fn style(mut self, style: DartClassStyle) -> Self {
+        self.declare_part = self.declare_part.style(style);
+        self.property_part = self.property_part.style(style);
+        self
+    }
+    pub fn json_serializable(self) -> Self {
+        self.style(DartClassStyle::JsonSerializable)
+    }
+    pub fn freezed(self) -> Self {
+        self.style(DartClassStyle::Freezed)
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<DartDeclarePartGenerator, DartPropertyPartGenerator, DartMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, DartMapper)
+    }
+}
+impl Default for DartTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_json_serializable() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![("user-name", make_optional_type(make_string_type()))],
+        );
+        let generator = DartTypeDescriptionGeneratorBuilder::new()
+            .declare_part_part("root")
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"import 'package:json_annotation/json_annotation.dart';
+
+part 'root.g.dart';
+
+@JsonSerializable()
+class Root {
+  final List<RootData> data;
+  final int id;
+
+  Root({
+    required this.data,
+    required this.id,
+  });
+
+  factory Root.fromJson(Map<String, dynamic> json) => _$RootFromJson(json);
+
+  Map<String, dynamic> toJson() => _$RootToJson(this);
+}
+
+@JsonSerializable()
+class RootData {
+  @JsonKey(name: 'user-name')
+  final String? userName;
+
+  RootData({
+    this.userName,
+  });
+
+  factory RootData.fromJson(Map<String, dynamic> json) => _$RootDataFromJson(json);
+
+  Map<String, dynamic> toJson() => _$RootDataToJson(this);
+}
+"#
+        );
+    }
+}
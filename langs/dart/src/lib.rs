@@ -0,0 +1,2 @@
+pub mod description_generator;
+pub mod generator_builder;
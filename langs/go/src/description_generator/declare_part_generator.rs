@@ -4,9 +4,13 @@ use description_generator::{
         CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
     },
     type_description_generator::DeclarePartGenerator,
+    type_mapper::TypeMapper,
 };
 use npc::fns::to_camel;
-use structure::parts::type_name::TypeName;
+use structure::{
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
 
 use super::mapper::GoMapper;
 
@@ -97,6 +101,46 @@ impl GoDeclarePartGenerator {
             type_
         )
     }
+    /// literal enum is named string type and constants of it.
+    /// literal is escaped as go interpreted string literal
+    fn concat_go_literal_enum(type_name: &str, values: &[String], names: Vec<TypeName>) -> String {
+        let constants = values
+            .iter()
+            .zip(names)
+            .map(|(value, name)| {
+                format!(
+                    "\t{}{} {} = {:?}\n",
+                    type_name,
+                    name.valid_lang_str(),
+                    type_name,
+                    value
+                )
+            })
+            .collect::<String>();
+        format!("type {} string\n\nconst (\n{})", type_name, constants)
+    }
+    /// union is interface, and type of each variant implements marker method of it.
+    /// method can not be defined for type such as string, so union of them is any
+    fn concat_go_union<'a>(
+        type_name: &str,
+        variants: impl Iterator<Item = &'a PropertyType>,
+        mapper: &GoMapper,
+    ) -> String {
+        let variants = variants
+            .map(|variant| match variant {
+                PropertyType::CustomType(name) => Some(name.valid_lang_str()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(variants) = variants else {
+            return format!("type {} {}", type_name, mapper.case_any());
+        };
+        let marker = format!("is{}", type_name);
+        variants.into_iter().fold(
+            format!("type {} interface {{\n\t{}()\n}}\n", type_name, marker),
+            |acc, variant| format!("{}\nfunc ({}) {}() {{}}", acc, variant, marker),
+        )
+    }
 }
 impl DeclarePartGenerator for GoDeclarePartGenerator {
     type Mapper = GoMapper;
@@ -115,6 +159,20 @@ impl DeclarePartGenerator for GoDeclarePartGenerator {
     ) -> String {
         self.inner.generate_case_alias(alias_type, mapper)
     }
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, mapper: &Self::Mapper) -> String {
+        let type_name = enum_type.type_name().valid_lang_str();
+        match enum_type.representation() {
+            EnumRepresentation::Literal(values) => {
+                Self::concat_go_literal_enum(&type_name, values, enum_type.variant_names())
+            }
+            EnumRepresentation::Tagged { variants, .. } => {
+                Self::concat_go_union(&type_name, variants.iter().map(|(_, t)| t), mapper)
+            }
+            EnumRepresentation::Untagged(variants) => {
+                Self::concat_go_union(&type_name, variants.iter(), mapper)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -122,8 +180,9 @@ mod tests {
     use std::collections::BTreeMap;
 
     use structure::{
-        alias_type_structure::AliasTypeStructure, composite_type_structure::CompositeTypeStructure,
-        parts::property_type::property_type_factories::make_string_type,
+        alias_type_structure::AliasTypeStructure,
+        composite_type_structure::CompositeTypeStructure,
+        parts::property_type::property_type_factories::{make_custom_type, make_string_type},
     };
 
     use super::*;
@@ -167,6 +226,87 @@ mod tests {
         assert_eq!(result, "type Test string");
     }
     #[test]
+    fn リテラルのenumは文字列型と定数になる() {
+        let sut = GoDeclarePartGenerator::new();
+        let mapper = GoMapper {};
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["active".to_string(), "in_progress".to_string()]),
+        );
+        assert_eq!(
+            sut.generate_case_enum(&literal, &mapper),
+            r#"type Status string
+
+const (
+	StatusActive Status = "active"
+	StatusInProgress Status = "in_progress"
+)"#
+        );
+    }
+    #[test]
+    fn 空のリテラルの定数は型名と重ならない() {
+        let sut = GoDeclarePartGenerator::new();
+        let mapper = GoMapper {};
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["".to_string(), "1".to_string()]),
+        );
+        assert_eq!(
+            sut.generate_case_enum(&literal, &mapper),
+            r#"type Status string
+
+const (
+	StatusEmpty Status = ""
+	StatusV1 Status = "1"
+)"#
+        );
+    }
+    #[test]
+    fn リテラルの引用符とバックスラッシュはエスケープされる() {
+        let sut = GoDeclarePartGenerator::new();
+        let mapper = GoMapper {};
+        let literal = EnumTypeStructure::new(
+            "Path",
+            EnumRepresentation::Literal(vec![r#"a"b"#.to_string(), r"c\d".to_string()]),
+        );
+        assert_eq!(
+            sut.generate_case_enum(&literal, &mapper),
+            "type Path string\n\nconst (\n\tPathAB Path = \"a\\\"b\"\n\tPathCD Path = \"c\\\\d\"\n)"
+        );
+    }
+    #[test]
+    fn unionはinterfaceになり各variantがそれを実装する() {
+        let sut = GoDeclarePartGenerator::new();
+        let mapper = GoMapper {};
+        let tagged = EnumTypeStructure::new(
+            "Shape",
+            EnumRepresentation::Tagged {
+                tag: "type".to_string(),
+                variants: vec![
+                    ("circle".to_string(), make_custom_type("ShapeCircle")),
+                    ("square".to_string(), make_custom_type("ShapeSquare")),
+                ],
+            },
+        );
+        assert_eq!(
+            sut.generate_case_enum(&tagged, &mapper),
+            r#"type Shape interface {
+	isShape()
+}
+
+func (ShapeCircle) isShape() {}
+func (ShapeSquare) isShape() {}"#
+        );
+        let untagged = EnumTypeStructure::new(
+            "Id",
+            EnumRepresentation::Untagged(vec![make_string_type(), make_custom_type("Uuid")]),
+        );
+        assert_eq!(
+            sut.generate_case_enum(&untagged, &mapper),
+            "type Id interface {}"
+        );
+    }
+    #[test]
     fn pubの設定をするとtypenameがパスカルケースになる() {
         let type_name = TypeName::new("Test");
         let composite_type = CompositeTypeStructure::new(type_name, BTreeMap::new());
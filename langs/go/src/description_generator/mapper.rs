@@ -22,9 +22,9 @@ impl TypeMapper for GoMapper {
     fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
         format!("[]{}", type_statement.into())
     }
-    fn case_optional_type<T: Into<TypeString>>(&self, _type_statement: T) -> TypeString {
-        todo!("go is not defined optional")
-        //format!("", type_statement.into())
+    /// go has no optional, so nil of pointer means absence
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("*{}", type_statement.into())
     }
     fn case_float(&self) -> TypeString {
         "float64".to_string()
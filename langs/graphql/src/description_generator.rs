@@ -0,0 +1,15 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::GraphqlDeclarePartGenerator, mapper::GraphqlMapper,
+    property_part_generator::GraphqlPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type GraphqlTypeDescriptionGenerator = TypeDescriptionGenerator<
+    GraphqlDeclarePartGenerator,
+    GraphqlPropertyPartGenerator,
+    GraphqlMapper,
+>;
@@ -0,0 +1,303 @@
+use std::collections::BTreeSet;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CustomizableAliasTypeDeclareGenerator, CustomizableCompositeTypeDeclareGenerator,
+            CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure, composite_type_structure::CompositeTypeStructure,
+    parts::type_name::TypeName,
+};
+
+use super::mapper::GraphqlMapper;
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct GraphqlDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<GraphqlMapper, ConcatFn, ConcatFn>,
+    any_scalar: String,
+    input_types: bool,
+}
+impl GraphqlDeclarePartGenerator {
+    const INPUT_SUFFIX: &'static str = "Input";
+    const BUILTIN_SCALARS: [&'static str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("scalar", Self::concat_graphql_scalar),
+                CustomizableCompositeTypeDeclareGenerator::new("type", Self::concat_graphql_object),
+            ),
+            any_scalar: GraphqlMapper::DEFAULT_ANY_SCALAR.to_string(),
+            input_types: false,
+        }
+    }
+    /// graphql can not alias existing type, so alias is declared as custom scalar
+    fn concat_graphql_scalar(identify: &str, type_name: &TypeName, _: String) -> String {
+        format!("{} {}", identify, type_name.valid_lang_str())
+    }
+    fn concat_graphql_object(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {} {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<GraphqlMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+    /// field type is `key: [Type!]!` form
+    fn field_base_type(line: &str) -> Option<&str> {
+        let line = line.trim();
+        if line.starts_with('#') {
+            return None;
+        }
+        let (_, type_) = line.split_once(": ")?;
+        Some(type_.trim_matches(|c| c == '[' || c == ']' || c == '!'))
+    }
+    /// input type can refer only scalars and other input types
+    fn to_input_references(description: &str, scalars: &BTreeSet<&str>) -> String {
+        let mut in_input = false;
+        description
+            .lines()
+            .map(|line| {
+                if line.starts_with("input ") {
+                    in_input = true;
+                    return line.to_string();
+                }
+                if line == "}" {
+                    in_input = false;
+                    return line.to_string();
+                }
+                match Self::field_base_type(line) {
+                    Some(base) if in_input && !scalars.contains(base) => {
+                        let (key, type_) = line.split_once(": ").unwrap();
+                        format!(
+                            "{}: {}",
+                            key,
+                            type_.replacen(base, &format!("{}{}", base, Self::INPUT_SUFFIX), 1)
+                        )
+                    }
+                    _ => line.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+impl Default for GraphqlDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for GraphqlDeclarePartGenerator {
+    type Mapper = GraphqlMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        let type_ = self
+            .inner
+            .generate_case_composite(composite_type, properties_statement.clone());
+        if !self.input_types || type_.is_empty() {
+            return type_;
+        }
+        let input_name: TypeName = format!(
+            "{}{}",
+            composite_type.type_name().valid_lang_str(),
+            Self::INPUT_SUFFIX
+        )
+        .into();
+        format!(
+            "{}\n\n{}",
+            type_,
+            Self::concat_graphql_object("input", &input_name, properties_statement)
+        )
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// declare scalars of any type and integer when they are used,
+    /// and resolve references of input types
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let descriptions = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .collect::<Vec<_>>();
+        let mut scalars = descriptions
+            .iter()
+            .flat_map(|description| description.lines())
+            .filter_map(|line| line.strip_prefix("scalar "))
+            .collect::<BTreeSet<_>>();
+        let used_scalars = [self.any_scalar.as_str(), GraphqlMapper::LONG_SCALAR]
+            .into_iter()
+            .filter(|scalar| {
+                !scalars.contains(scalar)
+                    && descriptions
+                        .iter()
+                        .flat_map(|description| description.lines())
+                        .any(|line| Self::field_base_type(line) == Some(scalar))
+            })
+            .collect::<Vec<_>>();
+        scalars.insert(self.any_scalar.as_str());
+        scalars.insert(GraphqlMapper::LONG_SCALAR);
+        scalars.extend(Self::BUILTIN_SCALARS);
+        let body = descriptions
+            .iter()
+            .map(|description| Self::to_input_references(description, &scalars))
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        let header = used_scalars.into_iter().fold(String::new(), |acc, scalar| {
+            format!("{}scalar {}\n\n", acc, scalar)
+        });
+        format!("{}{}\n", header, body)
+    }
+}
+
+pub struct GraphqlDeclarePartGeneratorBuilder {
+    generator: GraphqlDeclarePartGenerator,
+}
+impl GraphqlDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: GraphqlDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> GraphqlDeclarePartGenerator {
+        self.generator
+    }
+    /// scalar name for `PropertyType::Any`. it must be same as mapper's one
+    pub fn any_scalar(mut self, any_scalar: impl Into<String>) -> Self {
+        self.generator.any_scalar = any_scalar.into();
+        self
+    }
+    /// declare `input` type for each `type`
+    pub fn input_types(mut self) -> Self {
+        self.generator.input_types = true;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("# {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for GraphqlDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type,
+    };
+
+    use super::*;
+    #[test]
+    fn typeとして宣言される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = GraphqlDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "  id: Int!\n".to_string());
+
+        assert_eq!(result, "# this is comment\ntype Test {\n  id: Int!\n}");
+    }
+    #[test]
+    fn aliasはscalarとして宣言される() {
+        let mapper = GraphqlMapper::default();
+        let sut = GraphqlDeclarePartGeneratorBuilder::new().build();
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "scalar TestArray");
+    }
+    #[test]
+    fn input型はscalar以外の参照をinput型に置き換える() {
+        let sut = GraphqlDeclarePartGeneratorBuilder::new()
+            .input_types()
+            .build();
+        let test = sut.generate_case_composite(
+            &CompositeTypeStructure::new("Test", BTreeMap::new()),
+            "  child: [TestChild!]!\n  empty: TestEmpty\n  value: JSON\n".to_string(),
+        );
+
+        let result = sut.generate_case_concat(vec![test, "scalar TestEmpty".to_string()]);
+
+        assert_eq!(
+            result,
+            r#"scalar JSON
+
+type Test {
+  child: [TestChild!]!
+  empty: TestEmpty
+  value: JSON
+}
+
+input TestInput {
+  child: [TestChildInput!]!
+  empty: TestEmpty
+  value: JSON
+}
+
+scalar TestEmpty
+"#
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// every type is non null (`!`) first, and optional type removes it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphqlMapper {
+    any_scalar: String,
+}
+impl GraphqlMapper {
+    pub const DEFAULT_ANY_SCALAR: &'static str = "JSON";
+    /// `Int` of graphql is 32 bit signed, so integer (64 bit) is custom scalar `Long`
+    /// which is serialized as json number (same as `Long` of graphql-scalars)
+    pub const LONG_SCALAR: &'static str = "Long";
+    const NON_NULL: char = '!';
+    pub fn new(any_scalar: impl Into<String>) -> Self {
+        Self {
+            any_scalar: any_scalar.into(),
+        }
+    }
+    pub fn any_scalar(&self) -> &str {
+        &self.any_scalar
+    }
+}
+impl Default for GraphqlMapper {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ANY_SCALAR)
+    }
+}
+
+impl TypeMapper for GraphqlMapper {
+    fn case_string(&self) -> TypeString {
+        "String!".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        self.any_scalar.clone()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        format!("{}{}", custom_type.valid_lang_str(), Self::NON_NULL)
+    }
+    fn case_any(&self) -> TypeString {
+        format!("{}{}", self.any_scalar, Self::NON_NULL)
+    }
+    fn case_boolean(&self) -> TypeString {
+        "Boolean!".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("[{}]{}", type_statement.into(), Self::NON_NULL)
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        match type_statement.strip_suffix(Self::NON_NULL) {
+            Some(nullable) => nullable.to_string(),
+            None => type_statement,
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "Float!".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        format!("{}{}", Self::LONG_SCALAR, Self::NON_NULL)
+    }
+    fn case_usize(&self) -> TypeString {
+        format!("{}{}", Self::LONG_SCALAR, Self::NON_NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_custom_type, make_isize_type, make_optional_type,
+        make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 必須は感嘆符が付き省略可能な型は外れる() {
+        let mapper = GraphqlMapper::default();
+
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_string_type())),
+            "[String!]!"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_optional_type(
+                make_custom_type("Test")
+            )))),
+            "[Test]"
+        );
+        assert_eq!(
+            GraphqlMapper::new("Any").case_property_type(&make_any()),
+            "Any!"
+        );
+    }
+    #[test]
+    fn 整数は64bitのlong_scalarになる() {
+        let mapper = GraphqlMapper::default();
+
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_isize_type())),
+            "Long"
+        );
+        assert_eq!(mapper.case_property_type(&make_usize_type()), "Long!");
+    }
+}
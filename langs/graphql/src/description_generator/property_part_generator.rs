@@ -0,0 +1,152 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{Convertor, CustomizablePropertyDescriptionGenerator},
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::mapper::GraphqlMapper;
+
+pub struct GraphqlPropertyPartGenerator {
+    generator:
+        CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, GraphqlMapper>,
+}
+impl GraphqlPropertyPartGenerator {
+    const INDENT: &'static str = "  ";
+    const NEXT_LINE: &'static str = "\n";
+    pub fn new() -> Self {
+        fn graphql_property_concat(key: String, type_: String) -> String {
+            format!("{}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(graphql_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(GraphqlFieldNameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for GraphqlPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<GraphqlMapper> for GraphqlPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &GraphqlMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct GraphqlPropertyPartGeneratorBuilder {
+    generator: GraphqlPropertyPartGenerator,
+}
+impl GraphqlPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: GraphqlPropertyPartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> GraphqlPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors();
+        generator
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("# {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for GraphqlPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// graphql name allows only `[_A-Za-z][_0-9A-Za-z]*`
+struct GraphqlFieldNameConvertor {}
+impl Convertor<GraphqlMapper> for GraphqlFieldNameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &GraphqlMapper,
+    ) {
+        *acc = property_key.invalid_lang_str();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_type::property_type_factories::{
+            make_any, make_array_type, make_optional_type, make_string_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::GraphqlMapper, property_part_generator::GraphqlPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn 必須のフィールドには感嘆符が付く() {
+        let type_name: TypeName = "Test".into();
+        let mapper = GraphqlMapper::default();
+        let generator = GraphqlPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let names = generator.generate(
+            &type_name,
+            &"user-names".into(),
+            &make_array_type(make_string_type()),
+            &mapper,
+        );
+        let value = generator.generate(
+            &type_name,
+            &"value".into(),
+            &make_optional_type(make_any()),
+            &mapper,
+        );
+
+        assert_eq!(names, "  # this is comment\n  userNames: [String!]!\n");
+        assert_eq!(value, "  # this is comment\n  value: JSON\n");
+    }
+}
@@ -0,0 +1,145 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{GraphqlDeclarePartGenerator, GraphqlDeclarePartGeneratorBuilder},
+    mapper::GraphqlMapper,
+    property_part_generator::{GraphqlPropertyPartGenerator, GraphqlPropertyPartGeneratorBuilder},
+};
+
+pub struct GraphqlTypeDescriptionGeneratorBuilder {
+    pub declare_part: GraphqlDeclarePartGeneratorBuilder,
+    pub property_part: GraphqlPropertyPartGeneratorBuilder,
+    mapper: GraphqlMapper,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl GraphqlTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl GraphqlTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{input_types,}
+);
+impl GraphqlTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: GraphqlDeclarePartGeneratorBuilder::new(),
+            property_part: GraphqlPropertyPartGeneratorBuilder::new(),
+            mapper: GraphqlMapper::default(),
+        }
+    }
+    /// scalar name for `PropertyType::Any` (default is `JSON`)
+    pub fn any_scalar(mut self, any_scalar: impl Into<String>) -> Self {
+        let any_scalar = any_scalar.into();
+        self.declare_part = self.declare_part.any_scalar(any_scalar.clone());
+        self.mapper = GraphqlMapper::new(any_scalar);
+        self
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        GraphqlDeclarePartGenerator,
+        GraphqlPropertyPartGenerator,
+        GraphqlMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, self.mapper)
+    }
+}
+impl Default for GraphqlTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+                ("user-name", make_optional_type(make_string_type())),
+            ],
+        );
+        let root_data = TypeStructure::make_composite("RootData", vec![("value", make_any())]);
+        let root_array =
+            TypeStructure::make_alias("RootArray", make_array_type(make_custom_type("Root")));
+        let generator = GraphqlTypeDescriptionGeneratorBuilder::new()
+            .any_scalar("Any")
+            .declare_part_input_types()
+            .build();
+
+        let result = generator.generate_concat_define(vec![root_array, root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"scalar Any
+
+scalar Long
+
+scalar RootArray
+
+type Root {
+  data: [RootData!]!
+  id: Long!
+  userName: String
+}
+
+input RootInput {
+  data: [RootDataInput!]!
+  id: Long!
+  userName: String
+}
+
+type RootData {
+  value: Any!
+}
+
+input RootDataInput {
+  value: Any!
+}
+"#
+        );
+    }
+}
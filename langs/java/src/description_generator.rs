@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::JavaDeclarePartGenerator, mapper::JavaMapper,
+    property_part_generator::JavaPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type JavaTypeDescriptionGenerator =
+    TypeDescriptionGenerator<JavaDeclarePartGenerator, JavaPropertyPartGenerator, JavaMapper>;
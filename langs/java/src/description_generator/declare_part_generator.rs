@@ -0,0 +1,559 @@
+use std::str::FromStr;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+    type_mapper::TypeMapper,
+};
+use npc::fns::{to_constant, to_pascal};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::{mapper::JavaMapper, property_part_generator::java_field_name};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JavaClassStyle {
+    /// class with private fields and getters and setters
+    #[default]
+    Pojo,
+    /// java 16 record
+    Record,
+    /// class with lombok `@Data`
+    Lombok,
+}
+impl FromStr for JavaClassStyle {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "pojo" | "Pojo" | "POJO" | "class" => Ok(Self::Pojo),
+            "record" | "Record" => Ok(Self::Record),
+            "lombok" | "Lombok" | "data" => Ok(Self::Lombok),
+            _ => Err(format!("{} is not define java class style", str)),
+        }
+    }
+}
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct JavaDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<JavaMapper, ConcatFn, ConcatFn>,
+    package: Option<String>,
+}
+impl JavaDeclarePartGenerator {
+    const IMPORTS: [(&'static str, &'static str); 6] = [
+        ("List<", "import java.util.List;"),
+        ("extends ArrayList<", "import java.util.ArrayList;"),
+        (
+            "@JsonCreator\n",
+            "import com.fasterxml.jackson.annotation.JsonCreator;",
+        ),
+        (
+            "@JsonProperty(",
+            "import com.fasterxml.jackson.annotation.JsonProperty;",
+        ),
+        (
+            "@JsonValue\n",
+            "import com.fasterxml.jackson.annotation.JsonValue;",
+        ),
+        ("@Data\n", "import lombok.Data;"),
+    ];
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("public class", Self::concat_java_alias),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "public class",
+                    Self::concat_java_class,
+                ),
+            ),
+            package: None,
+        }
+    }
+    /// java has no type alias, so array alias is declared as subclass of ArrayList
+    fn concat_java_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!(
+            "{} {} extends Array{} {{\n}}",
+            identify,
+            type_name.valid_lang_str(),
+            type_
+        )
+    }
+    fn concat_java_class(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {} {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    /// literal enum is java enum, and each constant is renamed to literal by jackson
+    fn concat_java_enum(type_name: &str, values: &[String], names: Vec<TypeName>) -> String {
+        let constants = values
+            .iter()
+            .zip(names)
+            .map(|(value, name)| {
+                format!(
+                    "    @JsonProperty({:?})\n    {}",
+                    value,
+                    to_constant(&name.valid_lang_str())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!("public enum {} {{\n{}\n}}", type_name, constants)
+    }
+    /// java has no union type, so union is class which wraps any value as it is
+    fn concat_java_union(type_name: &str, any: &str) -> String {
+        format!(
+            r#"public class {name} {{
+    @JsonValue
+    private final {any} value;
+
+    @JsonCreator
+    public {name}({any} value) {{
+        this.value = value;
+    }}
+
+    public {any} getValue() {{
+        return value;
+    }}
+}}"#,
+            name = type_name,
+            any = any
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<JavaMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for JavaDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for JavaDeclarePartGenerator {
+    type Mapper = JavaMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        match alias_type.property_type() {
+            PropertyType::Array(_) => self.inner.generate_case_alias(alias_type, mapper),
+            _ => String::new(),
+        }
+    }
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, mapper: &Self::Mapper) -> String {
+        let type_name = enum_type.type_name().valid_lang_str();
+        match enum_type.representation() {
+            EnumRepresentation::Literal(values) => {
+                Self::concat_java_enum(&type_name, values, enum_type.variant_names())
+            }
+            EnumRepresentation::Tagged { .. } | EnumRepresentation::Untagged(_) => {
+                Self::concat_java_union(&type_name, &mapper.case_any())
+            }
+        }
+    }
+    /// add package and imports which are used in descriptions
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let body = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        let imports = Self::IMPORTS
+            .iter()
+            .filter(|(used, _)| body.contains(used))
+            .fold(String::new(), |acc, (_, import)| {
+                format!("{}{}\n", acc, import)
+            });
+        let package = self
+            .package
+            .as_ref()
+            .map(|package| format!("package {};\n\n", package))
+            .unwrap_or_default();
+        if imports.is_empty() {
+            format!("{}{}\n", package, body)
+        } else {
+            format!("{}{}\n{}\n", package, imports, body)
+        }
+    }
+}
+
+pub struct JavaDeclarePartGeneratorBuilder {
+    generator: JavaDeclarePartGenerator,
+    style: JavaClassStyle,
+}
+impl JavaDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: JavaDeclarePartGenerator::new(),
+            style: JavaClassStyle::default(),
+        }
+    }
+    pub fn build(self) -> JavaDeclarePartGenerator {
+        let mut generator = self.generator;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(JavaClassStyleConvertor {
+                style: self.style,
+                mapper: JavaMapper,
+            }));
+        generator
+    }
+    pub fn style(mut self, style: JavaClassStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.generator.package = Some(package.into());
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for JavaDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// rewrite `public class Name {...}` to declaration of each style
+struct JavaClassStyleConvertor {
+    style: JavaClassStyle,
+    mapper: JavaMapper,
+}
+impl JavaClassStyleConvertor {
+    fn accessors(&self, composite_type: &CompositeTypeStructure, body: &str) -> String {
+        composite_type
+            .iter()
+            .map(|(key, property_type)| (java_field_name(key), property_type))
+            // fields removed by property part (for example blacklist) have no accessors
+            .filter(|(field, _)| body.contains(&format!(" {};\n", field)))
+            .fold(String::new(), |acc, (field, property_type)| {
+                let type_ = self.mapper.case_property_type(property_type);
+                let method = accessor_name(&field);
+                format!(
+                    "{}\n    public {} get{}() {{\n        return {};\n    }}\n\n    public void set{}({} {}) {{\n        this.{} = {};\n    }}\n",
+                    acc, type_, method, field, method, type_, field, field, field
+                )
+            })
+    }
+}
+/// `Object#getClass` is final, so accessors of `class_` keep escaped name like lombok
+fn accessor_name(field: &str) -> String {
+    let method = to_pascal(field.trim_end_matches('_'));
+    if method == "Class" {
+        format!("{}_", method)
+    } else {
+        method
+    }
+}
+impl CompositeTypeDeclareConvertor for JavaClassStyleConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let name = composite_type.type_name().valid_lang_str();
+        let class_line = format!("public class {} {{\n", name);
+        let Some(start) = acc.find(&class_line) else {
+            return Some(acc);
+        };
+        let (header, declare) = acc.split_at(start);
+        let body = declare[class_line.len()..].trim_end_matches('}');
+        match self.style {
+            JavaClassStyle::Pojo => Some(format!(
+                "{}{}{}{}}}",
+                header,
+                class_line,
+                body,
+                self.accessors(composite_type, body)
+            )),
+            JavaClassStyle::Lombok => Some(format!("{}@Data\n{}", header, declare)),
+            JavaClassStyle::Record => {
+                let components = body.trim_end_matches(",\n");
+                if components.is_empty() {
+                    Some(format!("{}public record {}() {{\n}}", header, name))
+                } else {
+                    Some(format!(
+                        "{}public record {}(\n{}\n) {{\n}}",
+                        header, name, components
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていないスタイルはエラーになる() {
+        assert_eq!(
+            JavaClassStyle::from_str("record"),
+            Ok(JavaClassStyle::Record)
+        );
+        assert!(JavaClassStyle::from_str("foo").is_err());
+    }
+    fn make_composite() -> CompositeTypeStructure {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        properties.insert("user_name".into(), make_string_type());
+        CompositeTypeStructure::new("Test", properties)
+    }
+    #[test]
+    fn pojoはgetterとsetterを持つ() {
+        let sut = JavaDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "    private Long id;\n    @JsonProperty(\"user_name\")\n    private String userName;\n"
+                .to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"public class Test {
+    private Long id;
+    @JsonProperty("user_name")
+    private String userName;
+
+    public Long getId() {
+        return id;
+    }
+
+    public void setId(Long id) {
+        this.id = id;
+    }
+
+    public String getUserName() {
+        return userName;
+    }
+
+    public void setUserName(String userName) {
+        this.userName = userName;
+    }
+}"#
+        );
+    }
+    #[test]
+    fn object_getclassと衝突するアクセサはエスケープした名前を使う() {
+        let mut properties = BTreeMap::new();
+        properties.insert("class".into(), make_string_type());
+        let composite = CompositeTypeStructure::new("Test", properties);
+        let sut = JavaDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(
+            &composite,
+            "    @JsonProperty(\"class\")\n    private String class_;\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"public class Test {
+    @JsonProperty("class")
+    private String class_;
+
+    public String getClass_() {
+        return class_;
+    }
+
+    public void setClass_(String class_) {
+        this.class_ = class_;
+    }
+}"#
+        );
+    }
+    #[test]
+    fn lombokはdataアノテーションが付与される() {
+        let sut = JavaDeclarePartGeneratorBuilder::new()
+            .style(JavaClassStyle::Lombok)
+            .all_comment("this is comment")
+            .build();
+
+        let result =
+            sut.generate_case_composite(&make_composite(), "    private Long id;\n".to_string());
+
+        assert_eq!(
+            result,
+            "// this is comment\n@Data\npublic class Test {\n    private Long id;\n}"
+        );
+    }
+    #[test]
+    fn recordはコンポーネントを引数に持つ() {
+        let sut = JavaDeclarePartGeneratorBuilder::new()
+            .style(JavaClassStyle::Record)
+            .build();
+
+        let result = sut.generate_case_composite(
+            &make_composite(),
+            "    Long id,\n    @JsonProperty(\"user_name\") String userName,\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"public record Test(
+    Long id,
+    @JsonProperty("user_name") String userName
+) {
+}"#
+        );
+    }
+    #[test]
+    fn 配列のaliasはarray_listを継承し配列以外は出力しない() {
+        let mapper = JavaMapper;
+        let sut = JavaDeclarePartGeneratorBuilder::new().build();
+
+        let array = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let primitive = AliasTypeStructure::new("TestId", make_usize_type());
+
+        assert_eq!(
+            sut.generate_case_alias(&array, &mapper),
+            "public class TestArray extends ArrayList<Test> {\n}"
+        );
+        assert_eq!(sut.generate_case_alias(&primitive, &mapper), "");
+    }
+    #[test]
+    fn リテラルのenumはjson_propertyでリテラルに対応するenumになる() {
+        let mapper = JavaMapper;
+        let sut = JavaDeclarePartGeneratorBuilder::new().build();
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["active".to_string(), "in_progress".to_string()]),
+        );
+
+        let result = sut.generate_case_concat(vec![sut.generate_case_enum(&literal, &mapper)]);
+
+        assert_eq!(
+            result,
+            r#"import com.fasterxml.jackson.annotation.JsonProperty;
+
+public enum Status {
+    @JsonProperty("active")
+    ACTIVE,
+    @JsonProperty("in_progress")
+    IN_PROGRESS
+}
+"#
+        );
+    }
+    #[test]
+    fn unionは値をそのまま持つクラスになる() {
+        let mapper = JavaMapper;
+        let sut = JavaDeclarePartGeneratorBuilder::new().build();
+        let untagged = EnumTypeStructure::new(
+            "Id",
+            EnumRepresentation::Untagged(vec![make_string_type(), make_usize_type()]),
+        );
+
+        let result = sut.generate_case_enum(&untagged, &mapper);
+
+        assert_eq!(
+            result,
+            r#"public class Id {
+    @JsonValue
+    private final Object value;
+
+    @JsonCreator
+    public Id(Object value) {
+        this.value = value;
+    }
+
+    public Object getValue() {
+        return value;
+    }
+}"#
+        );
+    }
+    #[test]
+    fn packageと使用しているimportが先頭に付与される() {
+        let sut = JavaDeclarePartGeneratorBuilder::new()
+            .package("com.example")
+            .build();
+
+        let result = sut.generate_case_concat(vec![
+            "public class Test {\n    private List<String> names;\n}".to_string(),
+        ]);
+
+        assert_eq!(
+            result,
+            r#"package com.example;
+
+import java.util.List;
+
+public class Test {
+    private List<String> names;
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// use boxed types, because json value can be null and generics need reference types
+pub struct JavaMapper;
+
+impl TypeMapper for JavaMapper {
+    fn case_string(&self) -> TypeString {
+        "String".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "Object".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "Object".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "Boolean".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("List<{}>", type_statement.into())
+    }
+    /// reference types are nullable in java
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        type_statement.into()
+    }
+    fn case_float(&self) -> TypeString {
+        "Double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "Long".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "Long".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_optional_type, make_usize_type,
+    };
+
+    use super::JavaMapper;
+
+    #[test]
+    fn optionalはそのままの参照型で表現される() {
+        let mapper = JavaMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_usize_type()))),
+            "List<Long>"
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use npc::fns::to_camel;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{declare_part_generator::JavaClassStyle, mapper::JavaMapper};
+
+pub struct JavaPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, JavaMapper>,
+}
+impl JavaPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    pub fn new() -> Self {
+        fn java_property_concat(key: String, type_: String) -> String {
+            format!("{} {}", type_, key)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(java_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, style: JavaClassStyle) {
+        self.generator
+            .add_property_key_convertor(Box::new(JavaRenameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(JavaFieldConvertor { style }));
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(match style {
+            JavaClassStyle::Record => ",\n",
+            _ => ";\n",
+        });
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for JavaPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<JavaMapper> for JavaPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &JavaMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct JavaPropertyPartGeneratorBuilder {
+    generator: JavaPropertyPartGenerator,
+    style: JavaClassStyle,
+}
+impl JavaPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: JavaPropertyPartGenerator::new(),
+            style: JavaClassStyle::default(),
+        }
+    }
+    pub fn build(self) -> JavaPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.style);
+        generator
+    }
+    pub fn style(mut self, style: JavaClassStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for JavaPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// field name used in java class. original key is converted to camel case
+pub(crate) fn java_field_name(property_key: &PropertyKey) -> String {
+    let name = to_camel(&property_key.invalid_lang_str());
+    if JavaReservedWords::is_keyword(&name) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+struct JavaRenameConvertor {}
+impl Convertor<JavaMapper> for JavaRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &JavaMapper,
+    ) {
+        *acc = java_field_name(property_key);
+    }
+}
+
+/// make field declaration of each style.
+/// jackson `@JsonProperty` is added when field name is not same as original key
+struct JavaFieldConvertor {
+    style: JavaClassStyle,
+}
+impl DescriptionConvertor<JavaMapper> for JavaFieldConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &JavaMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        // header lines (for example comments) are kept above field
+        let (header, field) = match acc.rfind('\n') {
+            Some(index) => acc.split_at(index + 1),
+            None => ("", acc.as_str()),
+        };
+        let annotation = if java_field_name(property_key) == property_key.as_str() {
+            String::new()
+        } else {
+            format!("@JsonProperty(\"{}\")", property_key.as_str())
+        };
+        match (self.style, annotation.is_empty()) {
+            (JavaClassStyle::Record, true) => Some(acc),
+            (JavaClassStyle::Record, false) => Some(format!("{}{} {}", header, annotation, field)),
+            (_, true) => Some(format!("{}private {}", header, field)),
+            (_, false) => Some(format!("{}{}\nprivate {}", header, annotation, field)),
+        }
+    }
+}
+
+struct JavaReservedWords;
+impl JavaReservedWords {
+    const KEYWORDS: [&'static str; 53] = [
+        "abstract",
+        "assert",
+        "boolean",
+        "break",
+        "byte",
+        "case",
+        "catch",
+        "char",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "extends",
+        "final",
+        "finally",
+        "float",
+        "for",
+        "goto",
+        "if",
+        "implements",
+        "import",
+        "instanceof",
+        "int",
+        "interface",
+        "long",
+        "native",
+        "new",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "short",
+        "static",
+        "strictfp",
+        "super",
+        "switch",
+        "synchronized",
+        "this",
+        "throw",
+        "throws",
+        "transient",
+        "try",
+        "void",
+        "volatile",
+        "while",
+        "true",
+        "false",
+        "null",
+    ];
+    fn is_keyword(word: &str) -> bool {
+        Self::KEYWORDS.contains(&word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{make_string_type, make_usize_type},
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        declare_part_generator::JavaClassStyle, mapper::JavaMapper,
+        property_part_generator::JavaPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn フィールドはprivateで宣言される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id".into();
+        let property_type = make_usize_type();
+        let mapper = JavaMapper;
+        let generator = JavaPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    // this is comment\n    private Long id;\n");
+    }
+    #[test]
+    fn キー名が変わる場合json_propertyを付与する() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user_name".into();
+        let property_type = make_string_type();
+        let mapper = JavaMapper;
+        let generator = JavaPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    @JsonProperty(\"user_name\")\n    private String userName;\n"
+        );
+    }
+    #[test]
+    fn recordではコンポーネントとして宣言される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "class".into();
+        let property_type = make_string_type();
+        let mapper = JavaMapper;
+        let generator = JavaPropertyPartGeneratorBuilder::new()
+            .style(JavaClassStyle::Record)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    @JsonProperty(\"class\") String class_,\n");
+    }
+}
@@ -0,0 +1,144 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        JavaClassStyle, JavaDeclarePartGenerator, JavaDeclarePartGeneratorBuilder,
+    },
+    mapper::JavaMapper,
+    property_part_generator::{JavaPropertyPartGenerator, JavaPropertyPartGeneratorBuilder},
+};
+
+pub struct JavaTypeDescriptionGeneratorBuilder {
+    pub declare_part: JavaDeclarePartGeneratorBuilder,
+    pub property_part: JavaPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl JavaTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl JavaTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{package,(package,impl Into<String>)}
+);
+impl JavaTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: JavaDeclarePartGeneratorBuilder::new(),
+            property_part: JavaPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    /// set class style (POJO, record or lombok) to both declare part and property part
+    pub fn style(mut self, style: JavaClassStyle) -> Self {
+        self.declare_part = self.declare_part.style(style);
+        self.property_part = self.property_part.style(style);
+        self
+    }
+    pub fn pojo(self) -> Self {
+        self.style(JavaClassStyle::Pojo)
+    }
+    pub fn record(self) -> Self {
+        self.style(JavaClassStyle::Record)
+    }
+    pub fn lombok(self) -> Self {
+        self.style(JavaClassStyle::Lombok)
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<JavaDeclarePartGenerator, JavaPropertyPartGenerator, JavaMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, JavaMapper)
+    }
+}
+impl Default for JavaTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_string_type, make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_record() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data =
+            TypeStructure::make_composite("RootData", vec![("user-name", make_string_type())]);
+        let generator = JavaTypeDescriptionGeneratorBuilder::new()
+            .record()
+            .declare_part_package("com.example")
+            .build();
+
+        let root = generator.generate_concat_define(vec![root]);
+        let root_data = generator.generate_concat_define(vec![root_data]);
+
+        assert_eq!(
+            root,
+            r#"package com.example;
+
+import java.util.List;
+
+public record Root(
+    List<RootData> data,
+    Long id
+) {
+}
+"#
+        );
+        assert_eq!(
+            root_data,
+            r#"package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public record RootData(
+    @JsonProperty("user-name") String userName
+) {
+}
+"#
+        );
+    }
+}
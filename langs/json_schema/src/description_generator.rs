@@ -0,0 +1,32 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::JsonSchemaDeclarePartGenerator, mapper::JsonSchemaMapper,
+    property_part_generator::JsonSchemaPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type JsonSchemaTypeDescriptionGenerator = TypeDescriptionGenerator<
+    JsonSchemaDeclarePartGenerator,
+    JsonSchemaPropertyPartGenerator,
+    JsonSchemaMapper,
+>;
+
+/// make json string literal
+pub(crate) fn json_string(str: &str) -> String {
+    let escaped = str.chars().fold(String::new(), |mut acc, c| {
+        match c {
+            '"' => acc.push_str("\\\""),
+            '\\' => acc.push_str("\\\\"),
+            '\n' => acc.push_str("\\n"),
+            '\r' => acc.push_str("\\r"),
+            '\t' => acc.push_str("\\t"),
+            c if c.is_control() => acc.push_str(&format!("\\u{:04x}", c as u32)),
+            c => acc.push(c),
+        }
+        acc
+    });
+    format!("\"{}\"", escaped)
+}
@@ -0,0 +1,291 @@
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor},
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::{json_string, mapper::JsonSchemaMapper};
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+/// each type is declared as `$defs` entry, and first type is referred from root schema
+pub struct JsonSchemaDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<JsonSchemaMapper, ConcatFn, ConcatFn>,
+    id: Option<String>,
+}
+impl JsonSchemaDeclarePartGenerator {
+    const SCHEMA: &'static str = "https://json-schema.org/draft/2020-12/schema";
+    const INDENT: &'static str = "    ";
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("", Self::concat_alias_def),
+                CustomizableCompositeTypeDeclareGenerator::new("", Self::concat_object_def),
+            ),
+            id: None,
+        }
+    }
+    fn concat_alias_def(_: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{}: {}", json_string(&type_name.valid_lang_str()), type_)
+    }
+    fn concat_object_def(_: &str, type_name: &TypeName, properties: String) -> String {
+        let properties = properties.trim_end_matches(",\n");
+        let properties = if properties.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}\n  }}", properties)
+        };
+        format!(
+            "{}: {{\n  \"type\": \"object\",\n  \"properties\": {}\n}}",
+            json_string(&type_name.valid_lang_str()),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<JsonSchemaMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+    fn indent(description: &str) -> String {
+        description
+            .lines()
+            .map(|line| format!("{}{}", Self::INDENT, line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+impl Default for JsonSchemaDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for JsonSchemaDeclarePartGenerator {
+    type Mapper = JsonSchemaMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// wrap `$defs` entries into one schema document
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let descriptions = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .collect::<Vec<_>>();
+        let Some(root) = descriptions.first() else {
+            return String::new();
+        };
+        // first entry key is root type name
+        let root = &root[..root.find("\": ").unwrap_or_default()];
+        let root = root.trim_start_matches('"');
+        let id = self
+            .id
+            .as_ref()
+            .map(|id| format!("  \"$id\": {},\n", json_string(id)))
+            .unwrap_or_default();
+        let defs = descriptions
+            .iter()
+            .map(|description| Self::indent(description))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "{{\n  \"$schema\": {},\n{}  \"$ref\": {},\n  \"$defs\": {{\n{}\n  }}\n}}\n",
+            json_string(Self::SCHEMA),
+            id,
+            json_string(&format!("{}{}", JsonSchemaMapper::DEFS, root)),
+            defs
+        )
+    }
+}
+
+pub struct JsonSchemaDeclarePartGeneratorBuilder {
+    generator: JsonSchemaDeclarePartGenerator,
+}
+impl JsonSchemaDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: JsonSchemaDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> JsonSchemaDeclarePartGenerator {
+        let mut generator = self.generator;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(JsonSchemaRequiredConvertor {}));
+        generator
+    }
+    /// `$id` of root schema
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.generator.id = Some(id.into());
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for JsonSchemaDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// add `required` which lists not optional properties
+struct JsonSchemaRequiredConvertor {}
+impl CompositeTypeDeclareConvertor for JsonSchemaRequiredConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let required = composite_type
+            .iter()
+            .filter(|(_, property_type)| !matches!(property_type, PropertyType::Optional(_)))
+            .map(|(key, _)| json_string(key.as_str()))
+            // properties removed by property part (for example blacklist) are not required
+            .filter(|key| acc.contains(&format!("    {}: ", key)))
+            .collect::<Vec<_>>();
+        if required.is_empty() {
+            return Some(acc);
+        }
+        let Some(object) = acc.strip_suffix("\n}") else {
+            return Some(acc);
+        };
+        Some(format!(
+            "{},\n  \"required\": [{}]\n}}",
+            object,
+            required.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn optionalではないプロパティはrequiredに含まれる() {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        properties.insert("name".into(), make_optional_type(make_string_type()));
+        let composite_type = CompositeTypeStructure::new("Test", properties);
+        let sut = JsonSchemaDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(
+            &composite_type,
+            r#"    "id": {"type": "integer", "minimum": 0},
+    "name": {"anyOf": [{"type": "string"}, {"type": "null"}]},
+"#
+            .to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#""Test": {
+  "type": "object",
+  "properties": {
+    "id": {"type": "integer", "minimum": 0},
+    "name": {"anyOf": [{"type": "string"}, {"type": "null"}]}
+  },
+  "required": ["id"]
+}"#
+        );
+    }
+    #[test]
+    fn プロパティがない型は空のpropertiesを持つ() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = JsonSchemaDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(&composite_type, String::new());
+
+        assert_eq!(
+            result,
+            "\"Test\": {\n  \"type\": \"object\",\n  \"properties\": {}\n}"
+        );
+    }
+    #[test]
+    fn alias型はそのままdefsの要素になる() {
+        let mapper = JsonSchemaMapper;
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = JsonSchemaDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(
+            result,
+            r##""TestArray": {"type": "array", "items": {"$ref": "#/$defs/Test"}}"##
+        );
+    }
+    #[test]
+    fn 最初の型がルートとして参照される() {
+        let sut = JsonSchemaDeclarePartGeneratorBuilder::new()
+            .id("https://example.com/test.schema.json")
+            .build();
+
+        let result = sut.generate_case_concat(vec![
+            r#""TestArray": {"type": "array", "items": {"type": "string"}}"#.to_string(),
+            String::new(),
+        ]);
+
+        assert_eq!(
+            result,
+            r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$id": "https://example.com/test.schema.json",
+  "$ref": "#/$defs/TestArray",
+  "$defs": {
+    "TestArray": {"type": "array", "items": {"type": "string"}}
+  }
+}
+"##
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+use super::json_string;
+
+/// map property type to schema. custom type refers to `$defs` entry
+pub struct JsonSchemaMapper;
+impl JsonSchemaMapper {
+    pub(crate) const DEFS: &'static str = "#/$defs/";
+}
+
+impl TypeMapper for JsonSchemaMapper {
+    fn case_string(&self) -> TypeString {
+        r#"{"type": "string"}"#.to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        r#"{"type": "null"}"#.to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        format!(
+            r#"{{"$ref": {}}}"#,
+            json_string(&format!("{}{}", Self::DEFS, custom_type.valid_lang_str()))
+        )
+    }
+    fn case_any(&self) -> TypeString {
+        "{}".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        r#"{"type": "boolean"}"#.to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!(r#"{{"type": "array", "items": {}}}"#, type_statement.into())
+    }
+    /// optional value can be null. any schema already accepts null
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement == self.case_any() {
+            return type_statement;
+        }
+        format!(r#"{{"anyOf": [{}, {}]}}"#, type_statement, self.case_null())
+    }
+    fn case_datetime(&self) -> TypeString {
+        r#"{"type": "string", "format": "date-time"}"#.to_string()
+    }
+    fn case_float(&self) -> TypeString {
+        r#"{"type": "number"}"#.to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        r#"{"type": "integer"}"#.to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        r#"{"type": "integer", "minimum": 0}"#.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_custom_type, make_optional_type,
+    };
+
+    use super::JsonSchemaMapper;
+
+    #[test]
+    fn 配列はitemsでカスタム型はrefで表現される() {
+        let mapper = JsonSchemaMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_custom_type(
+                "Test"
+            )))),
+            r##"{"type": "array", "items": {"anyOf": [{"$ref": "#/$defs/Test"}, {"type": "null"}]}}"##
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_any())),
+            "{}"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{AddLastSideConvertor, AddLeftSideConvertor},
+        property_part_generator::{Convertor, CustomizablePropertyDescriptionGenerator},
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{json_string, mapper::JsonSchemaMapper};
+
+pub struct JsonSchemaPropertyPartGenerator {
+    generator:
+        CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, JsonSchemaMapper>,
+}
+impl JsonSchemaPropertyPartGenerator {
+    /// properties are nested in `"properties"` of `$defs` entry
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = ",\n";
+    pub fn new() -> Self {
+        fn json_schema_property_concat(key: String, type_: String) -> String {
+            format!("{}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(json_schema_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(JsonSchemaKeyConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for JsonSchemaPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<JsonSchemaMapper> for JsonSchemaPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &JsonSchemaMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct JsonSchemaPropertyPartGeneratorBuilder {
+    generator: JsonSchemaPropertyPartGenerator,
+}
+impl JsonSchemaPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: JsonSchemaPropertyPartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> JsonSchemaPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors();
+        generator
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for JsonSchemaPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// schema keeps original key as is
+struct JsonSchemaKeyConvertor {}
+impl Convertor<JsonSchemaMapper> for JsonSchemaKeyConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &JsonSchemaMapper,
+    ) {
+        *acc = json_string(property_key.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey, property_type::property_type_factories::make_string_type,
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::JsonSchemaMapper, property_part_generator::JsonSchemaPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn キーは元の名前のまま出力される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user \"name\"".into();
+        let property_type = make_string_type();
+        let mapper = JsonSchemaMapper;
+        let generator = JsonSchemaPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    \"user \\\"name\\\"\": {\"type\": \"string\"},\n"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        JsonSchemaDeclarePartGenerator, JsonSchemaDeclarePartGeneratorBuilder,
+    },
+    mapper::JsonSchemaMapper,
+    property_part_generator::{
+        JsonSchemaPropertyPartGenerator, JsonSchemaPropertyPartGeneratorBuilder,
+    },
+};
+
+pub struct JsonSchemaTypeDescriptionGeneratorBuilder {
+    pub declare_part: JsonSchemaDeclarePartGeneratorBuilder,
+    pub property_part: JsonSchemaPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl JsonSchemaTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl JsonSchemaTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{id,(id,impl Into<String>)}
+);
+impl JsonSchemaTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: JsonSchemaDeclarePartGeneratorBuilder::new(),
+            property_part: JsonSchemaPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        JsonSchemaDeclarePartGenerator,
+        JsonSchemaPropertyPartGenerator,
+        JsonSchemaMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, JsonSchemaMapper)
+    }
+}
+impl Default for JsonSchemaTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![("user-name", make_optional_type(make_string_type()))],
+        );
+        let generator = JsonSchemaTypeDescriptionGeneratorBuilder::new().build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$ref": "#/$defs/Root",
+  "$defs": {
+    "Root": {
+      "type": "object",
+      "properties": {
+        "data": {"type": "array", "items": {"$ref": "#/$defs/RootData"}},
+        "id": {"type": "integer", "minimum": 0}
+      },
+      "required": ["data", "id"]
+    },
+    "RootData": {
+      "type": "object",
+      "properties": {
+        "user-name": {"anyOf": [{"type": "string"}, {"type": "null"}]}
+      }
+    }
+  }
+}
+"##
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::KotlinDeclarePartGenerator, mapper::KotlinMapper,
+    property_part_generator::KotlinPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type KotlinTypeDescriptionGenerator =
+    TypeDescriptionGenerator<KotlinDeclarePartGenerator, KotlinPropertyPartGenerator, KotlinMapper>;
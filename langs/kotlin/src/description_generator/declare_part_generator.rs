@@ -0,0 +1,334 @@
+use std::str::FromStr;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure, composite_type_structure::CompositeTypeStructure,
+    parts::type_name::TypeName,
+};
+
+use super::mapper::KotlinMapper;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KotlinSerializer {
+    /// kotlinx.serialization `@Serializable` and `@SerialName`
+    #[default]
+    Kotlinx,
+    /// moshi codegen `@JsonClass` and `@Json`
+    Moshi,
+}
+impl KotlinSerializer {
+    fn class_annotation(&self) -> &'static str {
+        match self {
+            Self::Kotlinx => "@Serializable",
+            Self::Moshi => "@JsonClass(generateAdapter = true)",
+        }
+    }
+    pub(crate) fn rename_annotation(&self, original: &str) -> String {
+        match self {
+            Self::Kotlinx => format!("@SerialName(\"{}\")", original),
+            Self::Moshi => format!("@Json(name = \"{}\")", original),
+        }
+    }
+}
+impl FromStr for KotlinSerializer {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "kotlinx" | "Kotlinx" | "serialization" => Ok(Self::Kotlinx),
+            "moshi" | "Moshi" => Ok(Self::Moshi),
+            _ => Err(format!("{} is not define kotlin serializer", str)),
+        }
+    }
+}
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct KotlinDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<KotlinMapper, ConcatFn, ConcatFn>,
+    package: Option<String>,
+}
+impl KotlinDeclarePartGenerator {
+    const IMPORTS: [(&'static str, &'static str); 5] = [
+        (
+            "@Serializable\n",
+            "import kotlinx.serialization.Serializable",
+        ),
+        ("@SerialName(", "import kotlinx.serialization.SerialName"),
+        ("@JsonClass(", "import com.squareup.moshi.JsonClass"),
+        ("@Json(", "import com.squareup.moshi.Json"),
+        (
+            "JsonElement",
+            "import kotlinx.serialization.json.JsonElement",
+        ),
+    ];
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("typealias", Self::concat_kotlin_alias),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "data class",
+                    Self::concat_kotlin_class,
+                ),
+            ),
+            package: None,
+        }
+    }
+    fn concat_kotlin_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{} {} = {}", identify, type_name.valid_lang_str(), type_)
+    }
+    fn concat_kotlin_class(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {}(\n{})",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<KotlinMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for KotlinDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for KotlinDeclarePartGenerator {
+    type Mapper = KotlinMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// add package and imports which are used in descriptions
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let body = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        let imports = Self::IMPORTS
+            .iter()
+            .filter(|(used, _)| body.contains(used))
+            .fold(String::new(), |acc, (_, import)| {
+                format!("{}{}\n", acc, import)
+            });
+        let package = self
+            .package
+            .as_ref()
+            .map(|package| format!("package {}\n\n", package))
+            .unwrap_or_default();
+        if imports.is_empty() {
+            format!("{}{}\n", package, body)
+        } else {
+            format!("{}{}\n{}\n", package, imports, body)
+        }
+    }
+}
+
+pub struct KotlinDeclarePartGeneratorBuilder {
+    generator: KotlinDeclarePartGenerator,
+    serializer: KotlinSerializer,
+}
+impl KotlinDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: KotlinDeclarePartGenerator::new(),
+            serializer: KotlinSerializer::default(),
+        }
+    }
+    pub fn build(self) -> KotlinDeclarePartGenerator {
+        let mut generator = self.generator;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(KotlinDataClassConvertor {
+                serializer: self.serializer,
+            }));
+        generator
+    }
+    pub fn serializer(mut self, serializer: KotlinSerializer) -> Self {
+        self.serializer = serializer;
+        self
+    }
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.generator.package = Some(package.into());
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for KotlinDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// add class annotation of serializer.
+/// data class must have at least one property, so empty type is declared as normal class
+struct KotlinDataClassConvertor {
+    serializer: KotlinSerializer,
+}
+impl CompositeTypeDeclareConvertor for KotlinDataClassConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let name = composite_type.type_name().valid_lang_str();
+        let class_line = format!("data class {}(\n", name);
+        let Some(start) = acc.find(&class_line) else {
+            return Some(acc);
+        };
+        let (header, declare) = acc.split_at(start);
+        let annotation = self.serializer.class_annotation();
+        if declare[class_line.len()..].trim_end_matches(')').is_empty() {
+            return Some(format!("{}{}\nclass {}", header, annotation, name));
+        }
+        Some(format!("{}{}\n{}", header, annotation, declare))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていないシリアライザはエラーになる() {
+        assert_eq!(
+            KotlinSerializer::from_str("moshi"),
+            Ok(KotlinSerializer::Moshi)
+        );
+        assert!(KotlinSerializer::from_str("foo").is_err());
+    }
+    fn make_composite() -> CompositeTypeStructure {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        CompositeTypeStructure::new("Test", properties)
+    }
+    #[test]
+    fn data_classにserializableが付与される() {
+        let sut = KotlinDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result =
+            sut.generate_case_composite(&make_composite(), "    val id: Long,\n".to_string());
+
+        assert_eq!(
+            result,
+            "// this is comment\n@Serializable\ndata class Test(\n    val id: Long,\n)"
+        );
+    }
+    #[test]
+    fn moshiではjson_classが付与されプロパティがない型は通常のclassになる() {
+        let sut = KotlinDeclarePartGeneratorBuilder::new()
+            .serializer(KotlinSerializer::Moshi)
+            .build();
+
+        let result = sut.generate_case_composite(&make_composite(), "".to_string());
+
+        assert_eq!(result, "@JsonClass(generateAdapter = true)\nclass Test");
+    }
+    #[test]
+    fn alias型はtypealiasとして出力される() {
+        let mapper = KotlinMapper::default();
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = KotlinDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "typealias TestArray = List<Test>");
+    }
+    #[test]
+    fn packageと使用しているimportが先頭に付与される() {
+        let sut = KotlinDeclarePartGeneratorBuilder::new()
+            .package("com.example")
+            .build();
+
+        let result = sut.generate_case_concat(vec![
+            "@Serializable\ndata class Test(\n    @SerialName(\"user_name\") val userName: String,\n)"
+                .to_string(),
+        ]);
+
+        assert_eq!(
+            result,
+            r#"package com.example
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Test(
+    @SerialName("user_name") val userName: String,
+)
+"#
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+use super::declare_part_generator::KotlinSerializer;
+
+/// kotlinx.serialization can not serialize `Any`, so any value is `JsonElement` of it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KotlinMapper {
+    serializer: KotlinSerializer,
+}
+impl KotlinMapper {
+    pub fn new(serializer: KotlinSerializer) -> Self {
+        Self { serializer }
+    }
+}
+
+impl TypeMapper for KotlinMapper {
+    fn case_string(&self) -> TypeString {
+        "String".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        format!("{}?", self.case_any())
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        match self.serializer {
+            KotlinSerializer::Kotlinx => "JsonElement".to_string(),
+            KotlinSerializer::Moshi => "Any".to_string(),
+        }
+    }
+    fn case_boolean(&self) -> TypeString {
+        "Boolean".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("List<{}>", type_statement.into())
+    }
+    /// null type is already nullable, so `?` is not added twice
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement.ends_with('?') {
+            type_statement
+        } else {
+            format!("{}?", type_statement)
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "Double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "Long".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "Long".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_optional_type, make_usize_type,
+    };
+
+    use super::*;
+
+    #[test]
+    fn optionalはnullable型で表現される() {
+        let mapper = KotlinMapper::new(KotlinSerializer::Moshi);
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_optional_type(
+                make_usize_type()
+            )))),
+            "List<Long?>?"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_any())),
+            "Any?"
+        );
+    }
+    #[test]
+    fn kotlinxではanyはjson_elementになる() {
+        let mapper = KotlinMapper::default();
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_any())),
+            "List<JsonElement>"
+        );
+        assert_eq!(mapper.case_null(), "JsonElement?");
+    }
+}
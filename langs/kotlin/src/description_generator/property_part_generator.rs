@@ -0,0 +1,315 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
+};
+use npc::fns::to_camel;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{declare_part_generator::KotlinSerializer, mapper::KotlinMapper};
+
+pub struct KotlinPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, KotlinMapper>,
+}
+impl KotlinPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = ",\n";
+    pub fn new() -> Self {
+        fn kotlin_property_concat(key: String, type_: String) -> String {
+            format!("val {}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(kotlin_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, serializer: KotlinSerializer, all_optional: bool) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(KotlinRenameConvertor {}));
+        if all_optional {
+            self.generator
+                .add_property_type_convertor(Box::new(KotlinAllOptionalConvertor {}));
+        }
+        self.generator
+            .add_statement_convertor(Box::new(KotlinPropertyConvertor {
+                serializer,
+                all_optional,
+            }));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for KotlinPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<KotlinMapper> for KotlinPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &KotlinMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct KotlinPropertyPartGeneratorBuilder {
+    generator: KotlinPropertyPartGenerator,
+    serializer: KotlinSerializer,
+    all_optional: bool,
+}
+impl KotlinPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: KotlinPropertyPartGenerator::new(),
+            serializer: KotlinSerializer::default(),
+            all_optional: false,
+        }
+    }
+    pub fn build(self) -> KotlinPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.serializer, self.all_optional);
+        generator
+    }
+    pub fn serializer(mut self, serializer: KotlinSerializer) -> Self {
+        self.serializer = serializer;
+        self
+    }
+    pub fn all_optional(mut self) -> Self {
+        self.all_optional = true;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for KotlinPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// property name used in kotlin class. original key is converted to camel case
+fn kotlin_property_name(property_key: &PropertyKey) -> String {
+    to_camel(&property_key.invalid_lang_str())
+}
+
+struct KotlinRenameConvertor {}
+impl Convertor<KotlinMapper> for KotlinRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &KotlinMapper,
+    ) {
+        let name = kotlin_property_name(property_key);
+        // hard keywords can be used as name by escaping with backticks
+        *acc = if KotlinReservedWords::is_keyword(&name) {
+            format!("`{}`", name)
+        } else {
+            name
+        };
+    }
+}
+
+struct KotlinAllOptionalConvertor {}
+impl Convertor<KotlinMapper> for KotlinAllOptionalConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        _: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &KotlinMapper,
+    ) {
+        if !matches!(property_type, PropertyType::Optional(_)) {
+            *acc = mapper.case_optional_type(acc.clone());
+        }
+    }
+}
+
+/// add serial name annotation when property name is not same as original key,
+/// and `null` default to nullable property so that missing key can be decoded
+struct KotlinPropertyConvertor {
+    serializer: KotlinSerializer,
+    all_optional: bool,
+}
+impl DescriptionConvertor<KotlinMapper> for KotlinPropertyConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        _: &KotlinMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        // header lines (for example comments) are kept above property
+        let (header, property) = match acc.rfind('\n') {
+            Some(index) => acc.split_at(index + 1),
+            None => ("", acc.as_str()),
+        };
+        let is_optional = self.all_optional || matches!(property_type, PropertyType::Optional(_));
+        let default = if is_optional { " = null" } else { "" };
+        if kotlin_property_name(property_key) == property_key.as_str() {
+            return Some(format!("{}{}{}", header, property, default));
+        }
+        Some(format!(
+            "{}{} {}{}",
+            header,
+            self.serializer.rename_annotation(property_key.as_str()),
+            property,
+            default
+        ))
+    }
+}
+
+struct KotlinReservedWords;
+impl KotlinReservedWords {
+    const KEYWORDS: [&'static str; 28] = [
+        "as",
+        "break",
+        "class",
+        "continue",
+        "do",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "in",
+        "interface",
+        "is",
+        "null",
+        "object",
+        "package",
+        "return",
+        "super",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typealias",
+        "typeof",
+        "val",
+        "var",
+        "when",
+        "while",
+    ];
+    fn is_keyword(word: &str) -> bool {
+        Self::KEYWORDS.contains(&word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        declare_part_generator::KotlinSerializer, mapper::KotlinMapper,
+        property_part_generator::KotlinPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn nullableなプロパティはnullがデフォルト値になる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id".into();
+        let property_type = make_optional_type(make_usize_type());
+        let mapper = KotlinMapper::default();
+        let generator = KotlinPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    // this is comment\n    val id: Long? = null,\n"
+        );
+    }
+    #[test]
+    fn キー名が変わる場合serial_nameを付与する() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user_name".into();
+        let property_type = make_string_type();
+        let mapper = KotlinMapper::default();
+        let generator = KotlinPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    @SerialName(\"user_name\") val userName: String,\n"
+        );
+    }
+    #[test]
+    fn moshiではjsonアノテーションを付与する() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user-name".into();
+        let property_type = make_string_type();
+        let mapper = KotlinMapper::default();
+        let generator = KotlinPropertyPartGeneratorBuilder::new()
+            .serializer(KotlinSerializer::Moshi)
+            .all_optional()
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    @Json(name = \"user-name\") val userName: String? = null,\n"
+        );
+    }
+    #[test]
+    fn 予約語はバッククォートで囲まれる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "class".into();
+        let property_type = make_string_type();
+        let mapper = KotlinMapper::default();
+        let generator = KotlinPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    val `class`: String,\n");
+    }
+}
@@ -0,0 +1,163 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        KotlinDeclarePartGenerator, KotlinDeclarePartGeneratorBuilder, KotlinSerializer,
+    },
+    mapper::KotlinMapper,
+    property_part_generator::{KotlinPropertyPartGenerator, KotlinPropertyPartGeneratorBuilder},
+};
+
+pub struct KotlinTypeDescriptionGeneratorBuilder {
+    pub declare_part: KotlinDeclarePartGeneratorBuilder,
+    pub property_part: KotlinPropertyPartGeneratorBuilder,
+    mapper: KotlinMapper,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl KotlinTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl KotlinTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{all_optional,}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{package,(package,impl Into<String>)}
+);
+impl KotlinTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: KotlinDeclarePartGeneratorBuilder::new(),
+            property_part: KotlinPropertyPartGeneratorBuilder::new(),
+            mapper: KotlinMapper::default(),
+        }
+    }
+    /// set serialization library (kotlinx.serialization or moshi) to declare part, property part and mapper
+    pub fn serializer(mut self, serializer: KotlinSerializer) -> Self {
+        self.declare_part = self.declare_part.serializer(serializer);
+        self.property_part = self.property_part.serializer(serializer);
+        self.mapper = KotlinMapper::new(serializer);
+        self
+    }
+    pub fn kotlinx(self) -> Self {
+        self.serializer(KotlinSerializer::Kotlinx)
+    }
+    pub fn moshi(self) -> Self {
+        self.serializer(KotlinSerializer::Moshi)
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        KotlinDeclarePartGenerator,
+        KotlinPropertyPartGenerator,
+        KotlinMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, self.mapper)
+    }
+}
+impl Default for KotlinTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_moshi() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![("user-name", make_optional_type(make_string_type()))],
+        );
+        let generator = KotlinTypeDescriptionGeneratorBuilder::new()
+            .moshi()
+            .declare_part_package("com.example")
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"package com.example
+
+import com.squareup.moshi.JsonClass
+import com.squareup.moshi.Json
+
+@JsonClass(generateAdapter = true)
+data class Root(
+    val data: List<RootData>,
+    val id: Long,
+)
+
+@JsonClass(generateAdapter = true)
+data class RootData(
+    @Json(name = "user-name") val userName: String? = null,
+)
+"#
+        );
+    }
+    #[test]
+    fn kotlinxではanyはjson_elementになりimportされる() {
+        let root = TypeStructure::make_composite("Root", vec![("meta", make_any())]);
+        let generator = KotlinTypeDescriptionGeneratorBuilder::new().build();
+
+        let result = generator.generate_concat_define(vec![root]);
+
+        assert_eq!(
+            result,
+            r#"import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonElement
+
+@Serializable
+data class Root(
+    val meta: JsonElement,
+)
+"#
+        );
+    }
+}
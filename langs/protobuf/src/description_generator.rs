@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::ProtoDeclarePartGenerator, mapper::ProtoMapper,
+    property_part_generator::ProtoPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type ProtoTypeDescriptionGenerator =
+    TypeDescriptionGenerator<ProtoDeclarePartGenerator, ProtoPropertyPartGenerator, ProtoMapper>;
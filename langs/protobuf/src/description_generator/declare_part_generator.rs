@@ -0,0 +1,382 @@
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            AssignmentConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+    type_mapper::TypeMapper,
+};
+use npc::fns::{to_constant, to_snake};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::{mapper::ProtoMapper, property_part_generator::proto_field_type};
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct ProtoDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<ProtoMapper, ConcatFn, ConcatFn>,
+    package: Option<String>,
+}
+impl ProtoDeclarePartGenerator {
+    const SYNTAX: &'static str = "syntax = \"proto3\";";
+    const STRUCT_IMPORT: &'static str = "import \"google/protobuf/struct.proto\";";
+    const STRUCT_TYPES: [&'static str; 3] = [
+        "google.protobuf.Value ",
+        "google.protobuf.ListValue ",
+        "google.protobuf.NullValue ",
+    ];
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("message", Self::concat_proto_alias),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "message",
+                    Self::concat_proto_message,
+                ),
+            ),
+            package: None,
+        }
+    }
+    /// proto has no type alias, so array alias is declared as message which wraps items
+    fn concat_proto_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!(
+            "{} {} {{\n  {} items = 1;\n}}",
+            identify,
+            type_name.valid_lang_str(),
+            type_
+        )
+    }
+    fn concat_proto_message(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {} {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    /// literal enum is proto3 enum. values are prefixed by enum name because of scoping rule,
+    /// and zero value is unspecified
+    fn concat_proto_enum(type_name: &str, names: Vec<TypeName>) -> String {
+        let prefix = to_constant(type_name);
+        let values = names
+            .iter()
+            .enumerate()
+            .fold(String::new(), |acc, (i, name)| {
+                format!(
+                    "{}  {}_{} = {};\n",
+                    acc,
+                    prefix,
+                    to_constant(&name.valid_lang_str()),
+                    i + 1
+                )
+            });
+        format!(
+            "enum {} {{\n  {}_UNSPECIFIED = 0;\n{}}}",
+            type_name, prefix, values
+        )
+    }
+    /// union is message which has oneof of variants.
+    /// oneof can not have repeated field, so union including array is message of any value
+    fn concat_proto_union<'a>(
+        type_name: &str,
+        variants: impl Iterator<Item = &'a PropertyType>,
+        names: Vec<TypeName>,
+        mapper: &ProtoMapper,
+    ) -> String {
+        let variants = variants
+            .map(|variant| match variant {
+                PropertyType::Optional(inner) => inner.as_ref(),
+                _ => variant,
+            })
+            .map(|variant| match variant {
+                PropertyType::Array(_) | PropertyType::Optional(_) => None,
+                _ => Some(mapper.case_property_type(variant)),
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(variants) = variants else {
+            return format!(
+                "message {} {{\n  {} value = 1;\n}}",
+                type_name,
+                mapper.case_any()
+            );
+        };
+        let fields = variants.iter().zip(names).enumerate().fold(
+            String::new(),
+            |acc, (i, (type_, name))| {
+                format!(
+                    "{}    {} {} = {};\n",
+                    acc,
+                    type_,
+                    to_snake(&name.valid_lang_str()),
+                    i + 1
+                )
+            },
+        );
+        format!(
+            "message {} {{\n  oneof value {{\n{}  }}\n}}",
+            type_name, fields
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<ProtoMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for ProtoDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for ProtoDeclarePartGenerator {
+    type Mapper = ProtoMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        match alias_type.property_type() {
+            PropertyType::Array(_) => self.inner.generate_case_alias(alias_type, mapper),
+            _ => String::new(),
+        }
+    }
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, mapper: &Self::Mapper) -> String {
+        let type_name = enum_type.type_name().valid_lang_str();
+        let names = enum_type.variant_names();
+        match enum_type.representation() {
+            EnumRepresentation::Literal(_) => Self::concat_proto_enum(&type_name, names),
+            EnumRepresentation::Tagged { variants, .. } => Self::concat_proto_union(
+                &type_name,
+                variants.iter().map(|(_, variant)| variant),
+                names,
+                mapper,
+            ),
+            EnumRepresentation::Untagged(variants) => {
+                Self::concat_proto_union(&type_name, variants.iter(), names, mapper)
+            }
+        }
+    }
+    /// add syntax, package and import of well known types
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        let body = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .unwrap_or_default();
+        if body.is_empty() {
+            return body;
+        }
+        let package = self
+            .package
+            .as_ref()
+            .map(|package| format!("package {};\n\n", package))
+            .unwrap_or_default();
+        let import = if Self::STRUCT_TYPES.iter().any(|used| body.contains(used)) {
+            format!("{}\n\n", Self::STRUCT_IMPORT)
+        } else {
+            String::new()
+        };
+        format!("{}\n\n{}{}{}\n", Self::SYNTAX, package, import, body)
+    }
+}
+
+pub struct ProtoDeclarePartGeneratorBuilder {
+    generator: ProtoDeclarePartGenerator,
+}
+impl ProtoDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: ProtoDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> ProtoDeclarePartGenerator {
+        let mut generator = self.generator;
+        generator
+            .change_alias_generator()
+            .add_type_convertor(Box::new(ProtoAliasLabelConvertor {}));
+        generator
+    }
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.generator.package = Some(package.into());
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for ProtoDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// items of array alias has label in same way as field of message
+struct ProtoAliasLabelConvertor {}
+impl AssignmentConvertor<ProtoMapper> for ProtoAliasLabelConvertor {
+    fn convert(&self, acc: &mut String, alias_type: &AliasTypeStructure, mapper: &ProtoMapper) {
+        *acc = proto_field_type(alias_type.property_type(), mapper);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn messageとして宣言される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = ProtoDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "  uint64 id = 1;\n".to_string());
+
+        assert_eq!(
+            result,
+            "// this is comment\nmessage Test {\n  uint64 id = 1;\n}"
+        );
+    }
+    #[test]
+    fn 配列のaliasはitemsを持つmessageになり配列以外は出力しない() {
+        let mapper = ProtoMapper;
+        let sut = ProtoDeclarePartGeneratorBuilder::new().build();
+
+        let array = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let primitive = AliasTypeStructure::new("TestId", make_usize_type());
+
+        assert_eq!(
+            sut.generate_case_alias(&array, &mapper),
+            "message TestArray {\n  repeated Test items = 1;\n}"
+        );
+        assert_eq!(sut.generate_case_alias(&primitive, &mapper), "");
+    }
+    #[test]
+    fn リテラルのenumは型名を接頭辞に持つenumになる() {
+        let mapper = ProtoMapper;
+        let sut = ProtoDeclarePartGeneratorBuilder::new().build();
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["active".to_string(), "in_progress".to_string()]),
+        );
+
+        let result = sut.generate_case_concat(vec![sut.generate_case_enum(&literal, &mapper)]);
+
+        assert_eq!(
+            result,
+            r#"syntax = "proto3";
+
+enum Status {
+  STATUS_UNSPECIFIED = 0;
+  STATUS_ACTIVE = 1;
+  STATUS_IN_PROGRESS = 2;
+}
+"#
+        );
+    }
+    #[test]
+    fn unionはoneofを持つmessageになり配列を含む場合は任意の値になる() {
+        let mapper = ProtoMapper;
+        let sut = ProtoDeclarePartGeneratorBuilder::new().build();
+        let untagged = EnumTypeStructure::new(
+            "Id",
+            EnumRepresentation::Untagged(vec![make_string_type(), make_custom_type("Uuid")]),
+        );
+        let with_array = EnumTypeStructure::new(
+            "Ids",
+            EnumRepresentation::Untagged(vec![
+                make_string_type(),
+                make_array_type(make_string_type()),
+            ]),
+        );
+
+        assert_eq!(
+            sut.generate_case_enum(&untagged, &mapper),
+            "message Id {\n  oneof value {\n    string string = 1;\n    Uuid uuid = 2;\n  }\n}"
+        );
+        assert_eq!(
+            sut.generate_case_enum(&with_array, &mapper),
+            "message Ids {\n  google.protobuf.Value value = 1;\n}"
+        );
+    }
+    #[test]
+    fn syntaxとpackageと使用しているimportが先頭に付与される() {
+        let mapper = ProtoMapper;
+        let sut = ProtoDeclarePartGeneratorBuilder::new()
+            .package("example.v1")
+            .build();
+        let array = AliasTypeStructure::new("Values", make_array_type(make_any()));
+
+        let result = sut.generate_case_concat(vec![sut.generate_case_alias(&array, &mapper)]);
+
+        assert_eq!(
+            result,
+            r#"syntax = "proto3";
+
+package example.v1;
+
+import "google/protobuf/struct.proto";
+
+message Values {
+  repeated google.protobuf.Value items = 1;
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// field label (`repeated`, `optional`) is decided by property part,
+/// because proto3 can not nest labels
+pub struct ProtoMapper;
+
+impl TypeMapper for ProtoMapper {
+    fn case_string(&self) -> TypeString {
+        "string".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "google.protobuf.NullValue".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "google.protobuf.Value".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "bool".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("repeated {}", type_statement.into())
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("optional {}", type_statement.into())
+    }
+    fn case_float(&self) -> TypeString {
+        "double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "int64".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "uint64".to_string()
+    }
+}
@@ -0,0 +1,278 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
+};
+use npc::fns::{to_camel, to_snake};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use crate::field_number_lock::FieldNumberLock;
+
+use super::mapper::ProtoMapper;
+
+pub struct ProtoPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, ProtoMapper>,
+}
+impl ProtoPropertyPartGenerator {
+    const INDENT: &'static str = "  ";
+    const NEXT_LINE: &'static str = "\n";
+    pub fn new() -> Self {
+        fn proto_property_concat(key: String, type_: String) -> String {
+            format!("{} {}", type_, key)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(proto_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, lock: FieldNumberLock) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(ProtoRenameConvertor {}));
+        self.generator
+            .add_property_type_convertor(Box::new(ProtoLabelConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(ProtoFieldNumberConvertor { lock }));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for ProtoPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<ProtoMapper> for ProtoPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &ProtoMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct ProtoPropertyPartGeneratorBuilder {
+    generator: ProtoPropertyPartGenerator,
+    lock: FieldNumberLock,
+}
+impl ProtoPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: ProtoPropertyPartGenerator::new(),
+            lock: FieldNumberLock::new(),
+        }
+    }
+    pub fn build(self) -> ProtoPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.lock);
+        generator
+    }
+    /// field numbers are read from and assigned to this lock
+    pub fn lock(mut self, lock: FieldNumberLock) -> Self {
+        self.lock = lock;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for ProtoPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// field type with label.
+/// repeated field can not be optional, and nested array is expressed by `google.protobuf.ListValue`
+pub(crate) fn proto_field_type(property_type: &PropertyType, mapper: &ProtoMapper) -> String {
+    match property_type {
+        PropertyType::Optional(inner) => match inner.as_ref() {
+            PropertyType::Array(_) | PropertyType::Optional(_) => proto_field_type(inner, mapper),
+            // google.protobuf.Value can be null
+            PropertyType::Any => mapper.case_any(),
+            _ => mapper.case_optional_type(mapper.case_property_type(inner)),
+        },
+        PropertyType::Array(inner) => {
+            let mut inner = inner.as_ref();
+            while let PropertyType::Optional(optional) = inner {
+                inner = optional.as_ref();
+            }
+            match inner {
+                PropertyType::Array(_) => "google.protobuf.ListValue".to_string(),
+                _ => mapper.case_array_type(mapper.case_property_type(inner)),
+            }
+        }
+        _ => mapper.case_property_type(property_type),
+    }
+}
+
+struct ProtoRenameConvertor {}
+impl Convertor<ProtoMapper> for ProtoRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &ProtoMapper,
+    ) {
+        *acc = to_snake(&property_key.invalid_lang_str());
+    }
+}
+
+struct ProtoLabelConvertor {}
+impl Convertor<ProtoMapper> for ProtoLabelConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        _: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &ProtoMapper,
+    ) {
+        *acc = proto_field_type(property_type, mapper);
+    }
+}
+
+/// add locked field number.
+/// `json_name` is added when original key is not same as default json name (lower camel case of field name)
+struct ProtoFieldNumberConvertor {
+    lock: FieldNumberLock,
+}
+impl DescriptionConvertor<ProtoMapper> for ProtoFieldNumberConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &ProtoMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        let number = self
+            .lock
+            .number(&type_name.valid_lang_str(), property_key.as_str());
+        let field = to_snake(&property_key.invalid_lang_str());
+        if to_camel(&field) == property_key.as_str() {
+            Some(format!("{} = {};", acc, number))
+        } else {
+            Some(format!(
+                "{} = {} [json_name = \"{}\"];",
+                acc,
+                number,
+                property_key
+                    .as_str()
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_any, make_array_type, make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::{
+        description_generator::{
+            mapper::ProtoMapper, property_part_generator::ProtoPropertyPartGeneratorBuilder,
+        },
+        field_number_lock::FieldNumberLock,
+    };
+
+    #[test]
+    fn ロックされた番号が使われ新しいキーには次の番号が振られる() {
+        let type_name: TypeName = "Test".into();
+        let mapper = ProtoMapper;
+        let lock = FieldNumberLock::from_lock_str("Test\tid\t1\nTest\tremoved\t2\n").unwrap();
+        let generator = ProtoPropertyPartGeneratorBuilder::new()
+            .lock(lock.clone())
+            .all_comment("this is comment")
+            .build();
+
+        let name = generator.generate(
+            &type_name,
+            &"name".into(),
+            &make_optional_type(make_string_type()),
+            &mapper,
+        );
+        let id = generator.generate(&type_name, &"id".into(), &make_usize_type(), &mapper);
+
+        assert_eq!(name, "  // this is comment\n  optional string name = 3;\n");
+        assert_eq!(id, "  // this is comment\n  uint64 id = 1;\n");
+        assert_eq!(
+            lock.to_lock_string(),
+            "Test\tid\t1\nTest\tremoved\t2\nTest\tname\t3\n"
+        );
+    }
+    #[test]
+    fn 配列はrepeatedで入れ子の配列はlist_valueになる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user-names".into();
+        let mapper = ProtoMapper;
+        let generator = ProtoPropertyPartGeneratorBuilder::new().build();
+
+        let names = generator.generate(
+            &type_name,
+            &property_key,
+            &make_optional_type(make_array_type(make_optional_type(make_string_type()))),
+            &mapper,
+        );
+        let nested = generator.generate(
+            &type_name,
+            &"matrix".into(),
+            &make_array_type(make_array_type(make_usize_type())),
+            &mapper,
+        );
+        let any = generator.generate(
+            &type_name,
+            &"value".into(),
+            &make_optional_type(make_any()),
+            &mapper,
+        );
+
+        assert_eq!(
+            names,
+            "  repeated string user_names = 1 [json_name = \"user-names\"];\n"
+        );
+        assert_eq!(nested, "  google.protobuf.ListValue matrix = 2;\n");
+        assert_eq!(any, "  google.protobuf.Value value = 3;\n");
+    }
+}
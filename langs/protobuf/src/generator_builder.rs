@@ -0,0 +1,130 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::{
+    description_generator::{
+        declare_part_generator::{ProtoDeclarePartGenerator, ProtoDeclarePartGeneratorBuilder},
+        mapper::ProtoMapper,
+        property_part_generator::{ProtoPropertyPartGenerator, ProtoPropertyPartGeneratorBuilder},
+    },
+    field_number_lock::FieldNumberLock,
+};
+
+pub struct ProtoTypeDescriptionGeneratorBuilder {
+    pub declare_part: ProtoDeclarePartGeneratorBuilder,
+    pub property_part: ProtoPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl ProtoTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl ProtoTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{lock,(lock,FieldNumberLock)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+    ,{package,(package,impl Into<String>)}
+);
+impl ProtoTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: ProtoDeclarePartGeneratorBuilder::new(),
+            property_part: ProtoPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<ProtoDeclarePartGenerator, ProtoPropertyPartGenerator, ProtoMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, ProtoMapper)
+    }
+}
+impl Default for ProtoTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+                ("user-name", make_optional_type(make_string_type())),
+            ],
+        );
+        let root_data = TypeStructure::make_composite("RootData", vec![("value", make_any())]);
+        let lock = FieldNumberLock::from_lock_str("Root\tid\t1\n").unwrap();
+        let generator = ProtoTypeDescriptionGeneratorBuilder::new()
+            .property_part_lock(lock.clone())
+            .declare_part_package("example.v1")
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"syntax = "proto3";
+
+package example.v1;
+
+import "google/protobuf/struct.proto";
+
+message Root {
+  repeated RootData data = 2;
+  uint64 id = 1;
+  optional string user_name = 3 [json_name = "user-name"];
+}
+
+message RootData {
+  google.protobuf.Value value = 1;
+}
+"#
+        );
+        assert_eq!(
+            lock.to_lock_string(),
+            "Root\tid\t1\nRoot\tdata\t2\nRoot\tuser-name\t3\nRootData\tvalue\t1\n"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod description_generator;
+pub mod field_number_lock;
+pub mod generator_builder;
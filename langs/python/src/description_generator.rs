@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::PythonDeclarePartGenerator, mapper::PythonMapper,
+    property_part_generator::PythonPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type PythonTypeDescriptionGenerator =
+    TypeDescriptionGenerator<PythonDeclarePartGenerator, PythonPropertyPartGenerator, PythonMapper>;
@@ -0,0 +1,315 @@
+use std::str::FromStr;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+    type_mapper::TypeMapper,
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure, composite_type_structure::CompositeTypeStructure,
+    parts::type_name::TypeName,
+};
+
+use super::{mapper::PythonMapper, property_part_generator::is_valid_python_identifier};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PythonModelStyle {
+    #[default]
+    Dataclass,
+    TypedDict,
+    Pydantic,
+}
+impl PythonModelStyle {
+    fn header(&self) -> &'static str {
+        match self {
+            Self::Dataclass => {
+                "from __future__ import annotations\n\nfrom dataclasses import dataclass, field\nfrom typing import Any, List, Optional"
+            }
+            Self::TypedDict => {
+                "from __future__ import annotations\n\nfrom typing import Any, List, Optional, TypedDict"
+            }
+            Self::Pydantic => {
+                "from __future__ import annotations\n\nfrom typing import Any, List, Optional\n\nfrom pydantic import BaseModel, Field"
+            }
+        }
+    }
+}
+impl FromStr for PythonModelStyle {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "dataclass" | "Dataclass" => Ok(Self::Dataclass),
+            "typeddict" | "typed_dict" | "TypedDict" => Ok(Self::TypedDict),
+            "pydantic" | "Pydantic" | "BaseModel" => Ok(Self::Pydantic),
+            _ => Err(format!("{} is not define python model style", str)),
+        }
+    }
+}
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct PythonDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<PythonMapper, ConcatFn, ConcatFn>,
+    style: PythonModelStyle,
+}
+impl PythonDeclarePartGenerator {
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("", Self::concat_python_alias),
+                CustomizableCompositeTypeDeclareGenerator::new_indent_lang("class"),
+            ),
+            style: PythonModelStyle::default(),
+        }
+    }
+    fn concat_python_alias(_: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{} = {}", type_name.valid_lang_str(), type_)
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<PythonMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for PythonDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for PythonDeclarePartGenerator {
+    type Mapper = PythonMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// children are declared before parents,
+    /// because module level statements (alias, functional TypedDict) are evaluated immediately
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        descriptions
+            .into_iter()
+            .rev()
+            .filter(|description| !description.is_empty())
+            .fold(self.style.header().to_string(), |acc, cur| {
+                format!("{}\n\n\n{}", acc, cur.trim_end())
+            })
+            + "\n"
+    }
+}
+
+pub struct PythonDeclarePartGeneratorBuilder {
+    generator: PythonDeclarePartGenerator,
+}
+impl PythonDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: PythonDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> PythonDeclarePartGenerator {
+        let mut generator = self.generator;
+        let style = generator.style;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(PythonModelStyleConvertor { style }));
+        generator
+    }
+    pub fn style(mut self, style: PythonModelStyle) -> Self {
+        self.generator.style = style;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("# {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for PythonDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// add decorator or base class of each style, and `pass` to empty class.
+/// TypedDict which has keys that can not be identifier is declared by functional syntax
+struct PythonModelStyleConvertor {
+    style: PythonModelStyle,
+}
+impl PythonModelStyleConvertor {
+    fn to_functional_typed_dict(composite_type: &CompositeTypeStructure) -> String {
+        let mapper = PythonMapper;
+        let name = composite_type.type_name().valid_lang_str();
+        let fields = composite_type
+            .iter()
+            .fold(String::new(), |acc, (key, property_type)| {
+                format!(
+                    "{}    \"{}\": \"{}\",\n",
+                    acc,
+                    key.as_str(),
+                    mapper.case_property_type(property_type)
+                )
+            });
+        format!("{} = TypedDict(\"{}\", {{\n{}}})", name, name, fields)
+    }
+}
+impl CompositeTypeDeclareConvertor for PythonModelStyleConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let mut acc = acc?;
+        let name = composite_type.type_name().valid_lang_str();
+        let class_line = format!("class {}:\n", name);
+        let Some(class_start) = acc.find(&class_line) else {
+            return Some(acc);
+        };
+        if self.style == PythonModelStyle::TypedDict
+            && composite_type
+                .iter()
+                .any(|(key, _)| !is_valid_python_identifier(key.as_str()))
+        {
+            return Some(format!(
+                "{}{}",
+                &acc[..class_start],
+                Self::to_functional_typed_dict(composite_type)
+            ));
+        }
+        if acc.ends_with(&class_line) {
+            acc.push_str("    pass\n");
+        }
+        let declare = match self.style {
+            PythonModelStyle::Dataclass => format!("@dataclass\nclass {}:\n", name),
+            PythonModelStyle::TypedDict => format!("class {}(TypedDict):\n", name),
+            PythonModelStyle::Pydantic => format!("class {}(BaseModel):\n", name),
+        };
+        Some(acc.replacen(&class_line, &declare, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていないスタイルはエラーになる() {
+        assert_eq!(
+            PythonModelStyle::from_str("pydantic"),
+            Ok(PythonModelStyle::Pydantic)
+        );
+        assert!(PythonModelStyle::from_str("foo").is_err());
+    }
+    #[test]
+    fn dataclassはデコレータが付与される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = PythonDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "    id: int\n".to_string());
+
+        assert_eq!(
+            result,
+            "# this is comment\n@dataclass\nclass Test:\n    id: int\n"
+        );
+    }
+    #[test]
+    fn pydanticはbase_modelを継承する() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = PythonDeclarePartGeneratorBuilder::new()
+            .style(PythonModelStyle::Pydantic)
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "".to_string());
+
+        assert_eq!(result, "class Test(BaseModel):\n    pass\n");
+    }
+    #[test]
+    fn 識別子に使えないキーを持つtyped_dictは関数形式で宣言される() {
+        let mut properties = BTreeMap::new();
+        properties.insert("user-name".into(), make_string_type());
+        properties.insert("data".into(), make_array_type(make_custom_type("TestData")));
+        let composite_type = CompositeTypeStructure::new("Test", properties);
+        let sut = PythonDeclarePartGeneratorBuilder::new()
+            .style(PythonModelStyle::TypedDict)
+            .build();
+
+        let result = sut.generate_case_composite(
+            &composite_type,
+            "    data: List[TestData]\n    user-name: str\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"Test = TypedDict("Test", {
+    "data": "List[TestData]",
+    "user-name": "str",
+})"#
+        );
+    }
+    #[test]
+    fn alias型は代入文として出力される() {
+        let mapper = PythonMapper;
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = PythonDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "TestArray = List[Test]");
+    }
+}
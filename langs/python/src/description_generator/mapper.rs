@@ -0,0 +1,58 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+pub struct PythonMapper;
+
+impl TypeMapper for PythonMapper {
+    fn case_string(&self) -> TypeString {
+        "str".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "None".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "Any".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "bool".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("List[{}]", type_statement.into())
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("Optional[{}]", type_statement.into())
+    }
+    fn case_float(&self) -> TypeString {
+        "float".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "int".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "int".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type,
+    };
+
+    use super::PythonMapper;
+
+    #[test]
+    fn typingの型で表現される() {
+        let mapper = PythonMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_custom_type(
+                "Test"
+            )))),
+            "List[Optional[Test]]"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
+};
+use npc::fns::to_snake;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::{declare_part_generator::PythonModelStyle, mapper::PythonMapper};
+
+pub struct PythonPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, PythonMapper>,
+}
+impl PythonPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = "\n";
+    pub fn new() -> Self {
+        fn python_property_concat(key: String, type_: String) -> String {
+            format!("{}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(python_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, style: PythonModelStyle, all_optional: bool) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(PythonRenameConvertor { style }));
+        if all_optional {
+            self.generator
+                .add_property_type_convertor(Box::new(PythonAllOptionalConvertor {}));
+        }
+        self.generator
+            .add_statement_convertor(Box::new(PythonAddAliasConvertor {
+                style,
+                all_optional,
+            }));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for PythonPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<PythonMapper> for PythonPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &PythonMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct PythonPropertyPartGeneratorBuilder {
+    generator: PythonPropertyPartGenerator,
+    style: PythonModelStyle,
+    all_optional: bool,
+}
+impl PythonPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: PythonPropertyPartGenerator::new(),
+            style: PythonModelStyle::default(),
+            all_optional: false,
+        }
+    }
+    pub fn build(self) -> PythonPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.style, self.all_optional);
+        generator
+    }
+    pub fn style(mut self, style: PythonModelStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn all_optional(mut self) -> Self {
+        self.all_optional = true;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("# {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for PythonPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// attribute name used in python class.
+/// original key is converted to snake case and escaped when it is keyword
+pub(crate) fn python_attribute_name(property_key: &PropertyKey) -> String {
+    let name = to_snake(&property_key.invalid_lang_str());
+    if PythonReservedWords::is_keyword(&name) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+/// TypedDict can not rename key, so key is used as is if possible
+pub(crate) fn is_valid_python_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !PythonReservedWords::is_keyword(key)
+}
+
+struct PythonRenameConvertor {
+    style: PythonModelStyle,
+}
+impl Convertor<PythonMapper> for PythonRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &PythonMapper,
+    ) {
+        *acc = match self.style {
+            PythonModelStyle::TypedDict => property_key.as_str().to_string(),
+            _ => python_attribute_name(property_key),
+        }
+    }
+}
+
+struct PythonAllOptionalConvertor {}
+impl Convertor<PythonMapper> for PythonAllOptionalConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        _: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &PythonMapper,
+    ) {
+        if !matches!(property_type, PropertyType::Optional(_)) {
+            *acc = mapper.case_optional_type(acc.clone());
+        }
+    }
+}
+
+/// add alias of original key when attribute name is not same as original key.
+/// dataclass has no alias, so original key is kept in field metadata
+struct PythonAddAliasConvertor {
+    style: PythonModelStyle,
+    all_optional: bool,
+}
+impl DescriptionConvertor<PythonMapper> for PythonAddAliasConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        _: &PythonMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        let original = property_key.as_str();
+        let need_alias = python_attribute_name(property_key) != original;
+        let is_optional = self.all_optional || matches!(property_type, PropertyType::Optional(_));
+        match self.style {
+            PythonModelStyle::Pydantic => match (need_alias, is_optional) {
+                (true, true) => Some(format!(
+                    "{} = Field(default=None, alias=\"{}\")",
+                    acc, original
+                )),
+                (true, false) => Some(format!("{} = Field(alias=\"{}\")", acc, original)),
+                (false, true) => Some(format!("{} = None", acc)),
+                (false, false) => Some(acc),
+            },
+            PythonModelStyle::Dataclass if need_alias => Some(format!(
+                "{} = field(metadata={{\"alias\": \"{}\"}})",
+                acc, original
+            )),
+            _ => Some(acc),
+        }
+    }
+}
+
+struct PythonReservedWords;
+impl PythonReservedWords {
+    const KEYWORDS: [&'static str; 35] = [
+        "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+        "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+        "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield",
+    ];
+    fn is_keyword(word: &str) -> bool {
+        Self::KEYWORDS.contains(&word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        declare_part_generator::PythonModelStyle, mapper::PythonMapper,
+        property_part_generator::PythonPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn dataclassではキー名が変わる場合metadataに元のキーを残す() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "userId".into();
+        let property_type = make_usize_type();
+        let mapper = PythonMapper;
+        let generator = PythonPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    user_id: int = field(metadata={\"alias\": \"userId\"})\n"
+        );
+    }
+    #[test]
+    fn pydanticではキー名が変わる場合aliasを付与する() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "userId".into();
+        let property_type = make_optional_type(make_string_type());
+        let mapper = PythonMapper;
+        let generator = PythonPropertyPartGeneratorBuilder::new()
+            .style(PythonModelStyle::Pydantic)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    user_id: Optional[str] = Field(default=None, alias=\"userId\")\n"
+        );
+    }
+    #[test]
+    fn pydanticでは全てのプロパティをoptionalにできる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id".into();
+        let property_type = make_usize_type();
+        let mapper = PythonMapper;
+        let generator = PythonPropertyPartGeneratorBuilder::new()
+            .style(PythonModelStyle::Pydantic)
+            .all_optional()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    # this is comment\n    id: Optional[int] = None\n"
+        );
+    }
+    #[test]
+    fn 予約語は末尾にアンダースコアがつく() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "class".into();
+        let property_type = make_string_type();
+        let mapper = PythonMapper;
+        let generator = PythonPropertyPartGeneratorBuilder::new()
+            .style(PythonModelStyle::Pydantic)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    class_: str = Field(alias=\"class\")\n");
+    }
+    #[test]
+    fn typed_dictではキー名をそのまま使う() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "userId".into();
+        let property_type = make_string_type();
+        let mapper = PythonMapper;
+        let generator = PythonPropertyPartGeneratorBuilder::new()
+            .style(PythonModelStyle::TypedDict)
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    userId: str\n");
+    }
+}
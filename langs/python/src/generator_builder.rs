@@ -0,0 +1,151 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        PythonDeclarePartGenerator, PythonDeclarePartGeneratorBuilder, PythonModelStyle,
+    },
+    mapper::PythonMapper,
+    property_part_generator::{PythonPropertyPartGenerator, PythonPropertyPartGeneratorBuilder},
+};
+
+pub struct PythonTypeDescriptionGeneratorBuilder {
+    pub declare_part: PythonDeclarePartGeneratorBuilder,
+    pub property_part: PythonPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl PythonTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl PythonTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_optional,}
+    ,{all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+);
+impl PythonTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: PythonDeclarePartGeneratorBuilder::new(),
+            property_part: PythonPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    /// set model style (dataclass, TypedDict or pydantic) to both declare part and property part
+    pub fn style(mut self, style: PythonModelStyle) -> Self {
+        self.declare_part = self.declare_part.style(style);
+        self.property_part = self.property_part.style(style);
+        self
+    }
+    pub fn dataclass(self) -> Self {
+        self.style(PythonModelStyle::Dataclass)
+    }
+    pub fn typed_dict(self) -> Self {
+        self.style(PythonModelStyle::TypedDict)
+    }
+    pub fn pydantic(self) -> Self {
+        self.style(PythonModelStyle::Pydantic)
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        PythonDeclarePartGenerator,
+        PythonPropertyPartGenerator,
+        PythonMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, PythonMapper)
+    }
+}
+impl Default for PythonTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_pydantic() {
+        let root_array =
+            TypeStructure::make_alias("RootArray", make_array_type(make_custom_type("Root")));
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![
+                ("userName", make_string_type()),
+                ("age", make_optional_type(make_usize_type())),
+            ],
+        );
+        let generator = PythonTypeDescriptionGeneratorBuilder::new()
+            .pydantic()
+            .declare_part_all_comment("this is type")
+            .build();
+
+        let tobe = r#"from __future__ import annotations
+
+from typing import Any, List, Optional
+
+from pydantic import BaseModel, Field
+
+
+# this is type
+class RootData(BaseModel):
+    age: Optional[int] = None
+    user_name: str = Field(alias="userName")
+
+
+# this is type
+class Root(BaseModel):
+    data: List[RootData]
+    id: int
+
+
+# this is type
+RootArray = List[Root]
+"#;
+        let expect = generator.generate_concat_define(vec![root_array, root, root_data]);
+        assert_eq!(expect, tobe)
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use description_generator::{
     customizable::{
         declare_part_convetors::{
@@ -9,10 +11,15 @@ use description_generator::{
         },
     },
     type_description_generator::DeclarePartGenerator,
+    type_mapper::TypeMapper,
+};
+use structure::{
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::type_name::TypeName,
 };
-use structure::parts::type_name::TypeName;
 
-use super::mapper::RustMapper;
+use super::{mapper::RustMapper, property_part_generator::RustReservedWords};
 impl DeclarePartGenerator for RustDeclarePartGenerator {
     type Mapper = RustMapper;
     fn generate_case_alias(
@@ -30,6 +37,52 @@ impl DeclarePartGenerator for RustDeclarePartGenerator {
         self.inner
             .generate_case_composite(composite_type, properties_statement)
     }
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, mapper: &Self::Mapper) -> String {
+        let names = enum_type.variant_names();
+        let (attr, variants) = match enum_type.representation() {
+            EnumRepresentation::Literal(values) => (
+                None,
+                values
+                    .iter()
+                    .zip(&names)
+                    .map(|(value, name)| Self::enum_variant(name, Some(value), None))
+                    .collect::<String>(),
+            ),
+            EnumRepresentation::Tagged { tag, variants } => (
+                Some(format!("#[serde(tag = \"{}\")]", tag)),
+                variants
+                    .iter()
+                    .zip(&names)
+                    .map(|((value, type_), name)| {
+                        Self::enum_variant(
+                            name,
+                            Some(value),
+                            Some(mapper.case_property_type(type_)),
+                        )
+                    })
+                    .collect(),
+            ),
+            EnumRepresentation::Untagged(variants) => (
+                Some("#[serde(untagged)]".to_string()),
+                variants
+                    .iter()
+                    .zip(&names)
+                    .map(|(type_, name)| {
+                        Self::enum_variant(name, None, Some(mapper.case_property_type(type_)))
+                    })
+                    .collect(),
+            ),
+        };
+        let type_name = enum_type.type_name();
+        let description = self.enum_generator.generate_type_define(
+            &CompositeTypeStructure::new(type_name.clone(), BTreeMap::new()),
+            variants,
+        );
+        match attr {
+            Some(attr) => Self::insert_before_declare(description, &attr, type_name),
+            None => description,
+        }
+    }
 }
 impl RustDeclarePartGenerator {
     fn new() -> Self {
@@ -61,8 +114,50 @@ impl RustDeclarePartGenerator {
                     concat_composite_description_use_curly_bracket,
                 ),
             ),
+            enum_generator: CustomizableCompositeTypeDeclareGenerator::new(
+                "enum",
+                concat_composite_description_use_curly_bracket,
+            ),
         }
     }
+    /// variant is renamed, if name is not same as literal.
+    /// keyword (for example `Self`) is escaped by suffix
+    fn enum_variant(name: &TypeName, literal: Option<&String>, type_: Option<String>) -> String {
+        let name = name.valid_lang_str();
+        let reserved_words = RustReservedWords::new();
+        let name = if reserved_words.is_reserved_keywords(&name)
+            || reserved_words.is_strict_keywords(&name)
+        {
+            format!("{}_", name)
+        } else {
+            name
+        };
+        let rename = match literal {
+            Some(literal) if literal != &name => {
+                format!("    #[serde(rename = \"{}\")]\n", literal)
+            }
+            _ => String::new(),
+        };
+        match type_ {
+            Some(type_) => format!("{}    {}({}),\n", rename, name, type_),
+            None => format!("{}    {},\n", rename, name),
+        }
+    }
+    /// attribute of serde must be after derive, so it is put just before declaration
+    fn insert_before_declare(description: String, attr: &str, type_name: &TypeName) -> String {
+        let declare = format!("enum {} {{", type_name.valid_lang_str());
+        description
+            .lines()
+            .map(|line| {
+                if line.ends_with(&declare) {
+                    format!("{}\n{}", attr, line)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
     fn change_alias_generator(
         &mut self,
     ) -> &mut CustomizableAliasTypeDeclareGenerator<RustMapper, fn(&str, &TypeName, String) -> String>
@@ -74,6 +169,11 @@ impl RustDeclarePartGenerator {
     ) -> &mut CustomizableCompositeTypeDeclareGenerator<fn(&str, &TypeName, String) -> String> {
         self.inner.change_composite_generator()
     }
+    fn change_enum_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<fn(&str, &TypeName, String) -> String> {
+        &mut self.enum_generator
+    }
 }
 
 pub struct RustDeclarePartGeneratorBuilder {
@@ -97,6 +197,9 @@ impl RustDeclarePartGeneratorBuilder {
         self.generator
             .change_composite_generator()
             .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_description_convertor(convertor.to_declare_part());
         self.generator
             .change_alias_generator()
             .add_description_convertor(Box::new(convertor));
@@ -109,6 +212,16 @@ impl RustDeclarePartGeneratorBuilder {
         self.set_all_derive(derives)
     }
     pub fn set_all_derive(mut self, derives: Vec<impl Into<String>>) -> Self {
+        let convertor = Self::derive_convertor(derives);
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    fn derive_convertor(derives: Vec<impl Into<String>>) -> AddHeaderConvertor {
         let derive_description = format!(
             "#[derive({})]",
             derives
@@ -122,6 +235,11 @@ impl RustDeclarePartGeneratorBuilder {
         );
         let mut convertor = AddHeaderConvertor::new(derive_description);
         convertor.all();
+        convertor
+    }
+    /// row type of sqlx, for types made from sql ddl. enum is not row
+    pub fn derive_sqlx_from_row(mut self) -> Self {
+        let convertor = Self::derive_convertor(vec!["sqlx::FromRow"]);
         self.generator
             .change_composite_generator()
             .add_description_convertor(convertor.to_declare_part());
@@ -144,6 +262,9 @@ impl RustDeclarePartGeneratorBuilder {
         self.generator
             .change_composite_generator()
             .add_type_identify_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_type_identify_convertor(convertor.to_declare_part());
         self
     }
     pub fn all_attrs(mut self, attrs: Vec<impl Into<String>>) -> Self {
@@ -157,6 +278,9 @@ impl RustDeclarePartGeneratorBuilder {
         self.generator
             .change_composite_generator()
             .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_description_convertor(convertor.to_declare_part());
         self.generator
             .change_alias_generator()
             .add_description_convertor(convertor.to_declare_part());
@@ -168,6 +292,9 @@ impl RustDeclarePartGeneratorBuilder {
         self.generator
             .change_composite_generator()
             .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_description_convertor(convertor.to_declare_part());
         self.generator
             .change_alias_generator()
             .add_description_convertor(convertor.to_declare_part());
@@ -179,6 +306,9 @@ impl RustDeclarePartGeneratorBuilder {
         self.generator
             .change_composite_generator()
             .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_enum_generator()
+            .add_description_convertor(convertor.to_declare_part());
         self.generator
             .change_alias_generator()
             .add_description_convertor(convertor.to_declare_part());
@@ -205,6 +335,8 @@ pub struct RustDeclarePartGenerator {
         fn(&str, &TypeName, String) -> String,
         fn(&str, &TypeName, String) -> String,
     >,
+    enum_generator:
+        CustomizableCompositeTypeDeclareGenerator<fn(&str, &TypeName, String) -> String>,
 }
 
 #[cfg(test)]
@@ -214,7 +346,12 @@ mod tests {
     use structure::{
         alias_type_structure::AliasTypeStructure,
         composite_type_structure::CompositeTypeStructure,
-        parts::{property_type::property_type_factories::make_string_type, type_name::TypeName},
+        parts::{
+            property_type::property_type_factories::{
+                make_custom_type, make_string_type, make_usize_type,
+            },
+            type_name::TypeName,
+        },
     };
 
     use crate::description_generator::mapper::RustMapper;
@@ -333,6 +470,24 @@ pub struct Test {
             .set_all_derive(vec!["Debug", "Clone"])
             .build();
         let tobe = r#"#[derive(Debug,Clone)]
+struct Test {
+    id: usize,
+}"#;
+        assert_eq!(
+            generator.generate_case_composite(&composite_type, format!("    id: usize,\n"),),
+            tobe
+        );
+    }
+    #[test]
+    fn test_case_composite_derive_sqlx_from_row() {
+        let type_name: TypeName = "Test".into();
+        let composite_type = CompositeTypeStructure::new(type_name, BTreeMap::new());
+        let generator = RustDeclarePartGeneratorBuilder::new()
+            .set_all_derive_with_serde(vec!["Debug"])
+            .derive_sqlx_from_row()
+            .build();
+        let tobe = r#"#[derive(sqlx::FromRow)]
+#[derive(Debug,serde::Deserialize,serde::Serialize)]
 struct Test {
     id: usize,
 }"#;
@@ -360,6 +515,88 @@ pub struct Test {
         );
     }
     #[test]
+    fn enumはderiveとpubが付きリテラルと異なる名前はrenameされる() {
+        let mapper = RustMapper;
+        let generator = RustDeclarePartGeneratorBuilder::new()
+            .set_all_derive_with_serde(vec!["Debug"])
+            .derive_sqlx_from_row()
+            .pub_all()
+            .build();
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["active".to_string(), "Done".to_string()]),
+        );
+        let tobe = r#"#[derive(Debug,serde::Deserialize,serde::Serialize)]
+pub enum Status {
+    #[serde(rename = "active")]
+    Active,
+    Done,
+}"#;
+        assert_eq!(generator.generate_case_enum(&literal, &mapper), tobe);
+    }
+    #[test]
+    fn 識別子にならないリテラルのvariantは代わりの名前でrenameされる() {
+        let mapper = RustMapper;
+        let generator = RustDeclarePartGeneratorBuilder::new().build();
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(
+                vec!["", "1", "a-b", "self"]
+                    .into_iter()
+                    .map(|s| s.to_string())
+                    .collect(),
+            ),
+        );
+        let tobe = r#"enum Status {
+    #[serde(rename = "")]
+    Empty,
+    #[serde(rename = "1")]
+    V1,
+    #[serde(rename = "a-b")]
+    AB,
+    #[serde(rename = "self")]
+    Self_,
+}"#;
+        assert_eq!(generator.generate_case_enum(&literal, &mapper), tobe);
+    }
+    #[test]
+    fn tagged_unionとuntagged_unionはserdeの属性がderiveの後に付く() {
+        let mapper = RustMapper;
+        let generator = RustDeclarePartGeneratorBuilder::new()
+            .set_all_derive(vec!["Debug"])
+            .build();
+        let tagged = EnumTypeStructure::new(
+            "Shape",
+            EnumRepresentation::Tagged {
+                tag: "type".to_string(),
+                variants: vec![
+                    ("circle".to_string(), make_custom_type("ShapeCircle")),
+                    ("square".to_string(), make_custom_type("ShapeSquare")),
+                ],
+            },
+        );
+        let tobe = r#"#[derive(Debug)]
+#[serde(tag = "type")]
+enum Shape {
+    #[serde(rename = "circle")]
+    Circle(ShapeCircle),
+    #[serde(rename = "square")]
+    Square(ShapeSquare),
+}"#;
+        assert_eq!(generator.generate_case_enum(&tagged, &mapper), tobe);
+        let untagged = EnumTypeStructure::new(
+            "Id",
+            EnumRepresentation::Untagged(vec![make_string_type(), make_usize_type()]),
+        );
+        let tobe = r#"#[derive(Debug)]
+#[serde(untagged)]
+enum Id {
+    String(String),
+    Usize(usize),
+}"#;
+        assert_eq!(generator.generate_case_enum(&untagged, &mapper), tobe);
+    }
+    #[test]
     fn 不正な文字列は型名に指定できない() {
         let type_name: TypeName = "Test:invalidName".into();
         let mapper = RustMapper;
@@ -35,6 +35,120 @@ impl TypeMapper for RustMapper {
         "usize".to_string()
     }
 }
+
+/// types which sqlx can decode from column of row.
+/// decoding of sqlx is strict, so integer keeps width of column
+pub struct RustSqlxMapper;
+
+impl TypeMapper for RustSqlxMapper {
+    fn case_string(&self) -> TypeString {
+        RustMapper.case_string()
+    }
+    fn case_null(&self) -> TypeString {
+        RustMapper.case_null()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        RustMapper.case_custom_type(custom_type)
+    }
+    fn case_any(&self) -> TypeString {
+        RustMapper.case_any()
+    }
+    fn case_boolean(&self) -> TypeString {
+        RustMapper.case_boolean()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        RustMapper.case_array_type(type_statement)
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        RustMapper.case_optional_type(type_statement)
+    }
+    fn case_float(&self) -> TypeString {
+        RustMapper.case_float()
+    }
+    /// width is unknown, so it is as same as BIGINT
+    fn case_isize(&self) -> TypeString {
+        "i64".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "i64".to_string()
+    }
+    fn case_sized_integer(&self, bits: u16, signed: bool) -> TypeString {
+        match (bits, signed) {
+            (0..=8, true) => "i8",
+            (9..=16, true) => "i16",
+            (17..=32, true) => "i32",
+            (_, true) => "i64",
+            (0..=8, false) => "u8",
+            (9..=16, false) => "u16",
+            (17..=32, false) => "u32",
+            (_, false) => "u64",
+        }
+        .to_string()
+    }
+    fn case_datetime(&self) -> TypeString {
+        "chrono::NaiveDateTime".to_string()
+    }
+    fn case_zoned_datetime(&self) -> TypeString {
+        "chrono::DateTime<chrono::Utc>".to_string()
+    }
+    fn case_date(&self) -> TypeString {
+        "chrono::NaiveDate".to_string()
+    }
+    fn case_time(&self) -> TypeString {
+        "chrono::NaiveTime".to_string()
+    }
+    fn case_uuid(&self) -> TypeString {
+        "uuid::Uuid".to_string()
+    }
+    fn case_binary(&self) -> TypeString {
+        "Vec<u8>".to_string()
+    }
+    fn case_decimal(&self) -> TypeString {
+        "rust_decimal::Decimal".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use structure::parts::property_type::property_type_factories::{
+        make_binary_type, make_date_type, make_datetime_type, make_decimal_type,
+        make_optional_type, make_sized_isize_type, make_sized_usize_type, make_time_type,
+        make_uuid_type, make_zoned_datetime_type,
+    };
+
+    use super::*;
+    #[test]
+    fn sqlxの型は列の幅とタイムゾーンを保つ() {
+        let mapper = RustSqlxMapper;
+
+        assert_eq!(mapper.case_property_type(&make_sized_isize_type(16)), "i16");
+        assert_eq!(mapper.case_property_type(&make_sized_isize_type(32)), "i32");
+        assert_eq!(mapper.case_property_type(&make_sized_isize_type(64)), "i64");
+        assert_eq!(mapper.case_property_type(&make_sized_usize_type(32)), "u32");
+        assert_eq!(
+            mapper.case_property_type(&make_decimal_type()),
+            "rust_decimal::Decimal"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_datetime_type()),
+            "chrono::NaiveDateTime"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_zoned_datetime_type())),
+            "Option<chrono::DateTime<chrono::Utc>>"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_date_type()),
+            "chrono::NaiveDate"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_time_type()),
+            "chrono::NaiveTime"
+        );
+        assert_eq!(mapper.case_property_type(&make_uuid_type()), "uuid::Uuid");
+        assert_eq!(mapper.case_property_type(&make_binary_type()), "Vec<u8>");
+    }
+}
 //fn replace_cannot_use_char(str: &str) -> String {
 //str.replace(cannot_use_char, "")
 //}
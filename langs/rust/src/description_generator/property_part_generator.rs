@@ -8,10 +8,11 @@ use description_generator::{
         },
     },
     type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
 };
 use npc::fns::{is_snake, to_snake};
 
-use super::mapper::RustMapper;
+use super::mapper::{RustMapper, RustSqlxMapper};
 pub enum RustVisibility {
     Private,
     Public,
@@ -136,6 +137,13 @@ impl RustPropertyPartGeneratorBuilder {
             .add_property_type_convertor(Box::new(convertor));
         self
     }
+    /// type of field is decodable by sqlx from column
+    pub fn sqlx_types(mut self) -> Self {
+        self.generator
+            .generator
+            .add_base_property_type_convertor(Box::new(RustSqlxTypeConvertor {}));
+        self
+    }
     pub fn pub_all(self) -> Self {
         self.all_visibility(RustVisibility::Public)
     }
@@ -186,6 +194,25 @@ impl RustPropertyPartGenerator {
         self.generator
             .add_statement_convertor(Box::new(add_last_side_convertor));
     }
+    /// xml attribute (for example `@id`) and element (`id`) are same field name,
+    /// so field of attribute is suffixed. serde rename keeps original key
+    fn is_colliding_attribute(
+        composite_type: &structure::composite_type_structure::CompositeTypeStructure,
+        property_key: &structure::parts::property_key::PropertyKey,
+    ) -> bool {
+        if !property_key
+            .as_str()
+            .starts_with(|c: char| !c.is_ascii_alphanumeric())
+        {
+            return false;
+        }
+        let rename_convertor = RustRenameConvertor::new();
+        let field = rename_convertor.field_name(&property_key.invalid_lang_str(), property_key);
+        composite_type.iter().any(|(key, _)| {
+            key != property_key
+                && rename_convertor.field_name(&key.invalid_lang_str(), key) == field
+        })
+    }
 }
 impl PropertyPartGenerator<RustMapper> for RustPropertyPartGenerator {
     fn generate(
@@ -198,6 +225,29 @@ impl PropertyPartGenerator<RustMapper> for RustPropertyPartGenerator {
         self.generator
             .generate(type_name, property_key, property_type, mapper)
     }
+    fn generate_among(
+        &self,
+        composite_type: &structure::composite_type_structure::CompositeTypeStructure,
+        property_key: &structure::parts::property_key::PropertyKey,
+        property_type: &structure::parts::property_type::PropertyType,
+        mapper: &RustMapper,
+    ) -> String {
+        if !Self::is_colliding_attribute(composite_type, property_key) {
+            return self.generate(
+                composite_type.type_name(),
+                property_key,
+                property_type,
+                mapper,
+            );
+        }
+        self.generator.generate_with_key(
+            composite_type.type_name(),
+            property_key,
+            property_type,
+            mapper,
+            |key| format!("{}_attr", key),
+        )
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -206,9 +256,15 @@ mod tests {
         property_part_generator::{RustPropertyPartGeneratorBuilder, RustVisibility},
     };
     use description_generator::type_description_generator::PropertyPartGenerator;
-    use structure::parts::{
-        property_key::PropertyKey, property_type::property_type_factories::make_usize_type,
-        type_name::TypeName,
+    use structure::{
+        composite_type_structure::CompositeTypeStructure,
+        parts::{
+            property_key::PropertyKey,
+            property_type::property_type_factories::{
+                make_array_type, make_sized_isize_type, make_usize_type,
+            },
+            type_name::TypeName,
+        },
     };
     #[test]
     fn test_case_set_whitelist_with_key() {
@@ -280,6 +336,22 @@ mod tests {
         );
     }
     #[test]
+    fn sqlxの型は他のコンバータが変換する前に決まる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "string".into();
+        let property_type = make_array_type(make_sized_isize_type(32));
+        let mapper = RustMapper;
+        let generator = RustPropertyPartGeneratorBuilder::new()
+            .all_optional()
+            .sqlx_types()
+            .build();
+        let tobe = "    string: Option<Vec<i32>>,\n";
+        assert_eq!(
+            generator.generate(&type_name, &property_key, &property_type, &mapper,),
+            tobe
+        );
+    }
+    #[test]
     fn test_case_set_blacklist_with_key() {
         let type_name: TypeName = "Test".into();
         let property_key: PropertyKey = "id".into();
@@ -378,6 +450,49 @@ mod tests {
         );
     }
     #[test]
+    fn xmlの属性と本文はserdeのrenameで表現される() {
+        let type_name: TypeName = "Test".into();
+        let property_type = make_usize_type();
+        let mapper = RustMapper;
+        let generator = RustPropertyPartGeneratorBuilder::new().build();
+        assert_eq!(
+            generator.generate(&type_name, &"@id".into(), &property_type, &mapper,),
+            "    #[serde(rename = \"@id\")]\n    id: usize,\n"
+        );
+        assert_eq!(
+            generator.generate(&type_name, &"$value".into(), &property_type, &mapper,),
+            "    #[serde(rename = \"$value\")]\n    value: usize,\n"
+        );
+    }
+    #[test]
+    fn 要素と同じフィールド名になるxmlの属性だけに接尾辞が付く() {
+        let composite_type = CompositeTypeStructure::new(
+            "Test",
+            vec![
+                (PropertyKey::from("@id"), make_usize_type()),
+                (PropertyKey::from("id"), make_usize_type()),
+                (PropertyKey::from("@name"), make_usize_type()),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let property_type = make_usize_type();
+        let mapper = RustMapper;
+        let generator = RustPropertyPartGeneratorBuilder::new().build();
+        assert_eq!(
+            generator.generate_among(&composite_type, &"@id".into(), &property_type, &mapper),
+            "    #[serde(rename = \"@id\")]\n    id_attr: usize,\n"
+        );
+        assert_eq!(
+            generator.generate_among(&composite_type, &"id".into(), &property_type, &mapper),
+            "    id: usize,\n"
+        );
+        assert_eq!(
+            generator.generate_among(&composite_type, &"@name".into(), &property_type, &mapper),
+            "    #[serde(rename = \"@name\")]\n    name: usize,\n"
+        );
+    }
+    #[test]
     fn test_case_not_use_str() {
         let type_name: TypeName = "Test".into();
         let property_key: PropertyKey = "id:value".into();
@@ -457,7 +572,7 @@ impl RustRenameJudger {
     fn cannot_use_char(c: char) -> bool {
         match c {
             ':' | ';' | '#' | '$' | '%' | '&' | '~' | '=' | '|' | '\"' | '\'' | '{' | '}' | '?'
-            | '!' | '<' | '>' | '[' | ']' | '*' | '^' => true,
+            | '!' | '<' | '>' | '[' | ']' | '*' | '^' | '@' => true,
             _ => false,
         }
     }
@@ -471,33 +586,54 @@ impl RustRenameConvertor {
             judger: RustRenameJudger::new(),
         }
     }
-}
-impl Convertor<RustMapper> for RustRenameConvertor {
-    fn convert(
+    /// field name of property key, keyword is escaped
+    fn field_name(
         &self,
-        acc: &mut String,
-        _: &structure::parts::type_name::TypeName,
+        acc: &str,
         property_key: &structure::parts::property_key::PropertyKey,
-        _: &structure::parts::property_type::PropertyType,
-        _: &RustMapper,
-    ) -> () {
+    ) -> String {
         if self
             .judger
             .reserved_words
             .is_reserved_keywords(property_key.as_str())
         {
-            *acc = format!("r#{}", acc);
-            return;
+            return format!("r#{}", acc);
         }
         if self
             .judger
             .reserved_words
             .is_strict_keywords(property_key.as_str())
         {
-            *acc = format!("{}_", acc);
-            return;
+            return format!("{}_", acc);
         }
-        *acc = to_snake(&property_key.invalid_lang_str());
+        to_snake(&property_key.invalid_lang_str())
+    }
+}
+impl Convertor<RustMapper> for RustRenameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &structure::parts::type_name::TypeName,
+        property_key: &structure::parts::property_key::PropertyKey,
+        _: &structure::parts::property_type::PropertyType,
+        _: &RustMapper,
+    ) -> () {
+        *acc = self.field_name(acc, property_key);
+    }
+}
+/// type of field is chosen by sqlx mapper instead of rust mapper.
+/// it is base type convertor, so other convertors (for example optional) wrap the sqlx type
+struct RustSqlxTypeConvertor {}
+impl Convertor<RustMapper> for RustSqlxTypeConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &structure::parts::type_name::TypeName,
+        _: &structure::parts::property_key::PropertyKey,
+        property_type: &structure::parts::property_type::PropertyType,
+        _: &RustMapper,
+    ) -> () {
+        *acc = RustSqlxMapper.case_property_type(property_type);
     }
 }
 pub struct RustAddSerdeRenameConvertor {
@@ -64,6 +64,7 @@ impl_declare_part_methods!(
     ,{all_attrs_with_serde,(attrs,Vec<impl Into<String>>)}
     ,{set_all_derive,(derives,Vec<impl Into<String>>)}
     ,{set_all_derive_with_serde,(derives,Vec<impl Into<String>>)}
+    ,{derive_sqlx_from_row,}
     ,{set_whitelist,(list,Vec<impl Into<String>>)}
     ,{set_blacklist,(list,Vec<impl Into<String>>)}
 );
@@ -74,6 +75,12 @@ impl RustTypeDescriptionGeneratorBuilder {
             property_part: RustPropertyPartGeneratorBuilder::new(),
         }
     }
+    /// derive sqlx::FromRow, and type of each field is decodable from column
+    pub fn from_row(mut self) -> Self {
+        self.declare_part = self.declare_part.derive_sqlx_from_row();
+        self.property_part = self.property_part.sqlx_types();
+        self
+    }
     pub fn build(
         self,
     ) -> TypeDescriptionGenerator<RustDeclarePartGenerator, RustPropertyPartGenerator, RustMapper>
@@ -105,7 +112,9 @@ mod tests {
     use super::*;
     use structure::{
         parts::property_type::property_type_factories::{
-            make_array_type, make_custom_type, make_string_type, make_usize_type,
+            make_array_type, make_custom_type, make_datetime_type, make_decimal_type,
+            make_optional_type, make_sized_isize_type, make_sized_usize_type, make_string_type,
+            make_usize_type, make_zoned_datetime_type,
         },
         type_structure::TypeStructure,
     };
@@ -183,4 +192,55 @@ pub struct RootDataResults {
         }
         assert_eq!(expect, tobe)
     }
+    #[test]
+    fn xmlの属性と要素が同じフィールド名になる場合は属性に接尾辞を付ける() {
+        let user = TypeStructure::make_composite(
+            "User",
+            vec![("@id", make_string_type()), ("id", make_string_type())],
+        );
+        let generator = RustTypeDescriptionGeneratorBuilder::new()
+            .property_part_pub_all()
+            .build();
+
+        assert_eq!(
+            generator.generate(vec![user]),
+            vec![
+                r#"struct User {
+    #[serde(rename = "@id")]
+    pub id_attr: String,
+    pub id: String,
+}"#
+            ]
+        );
+    }
+    #[test]
+    fn from_rowの型はsqlxが扱える型のフィールドを持つ() {
+        let order = TypeStructure::make_composite(
+            "Order",
+            vec![
+                ("id", make_sized_isize_type(32)),
+                ("price", make_decimal_type()),
+                ("quantity", make_sized_usize_type(16)),
+                ("created_at", make_datetime_type()),
+                ("shipped_at", make_optional_type(make_zoned_datetime_type())),
+            ],
+        );
+        let generator = RustTypeDescriptionGeneratorBuilder::new()
+            .from_row()
+            .build();
+
+        assert_eq!(
+            generator.generate(vec![order]),
+            vec![
+                r#"#[derive(sqlx::FromRow)]
+struct Order {
+    created_at: chrono::NaiveDateTime,
+    id: i32,
+    price: rust_decimal::Decimal,
+    quantity: u16,
+    shipped_at: Option<chrono::DateTime<chrono::Utc>>,
+}"#
+            ]
+        );
+    }
 }
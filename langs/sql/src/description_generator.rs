@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::SqlDeclarePartGenerator, mapper::SqlMapper,
+    property_part_generator::SqlPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type SqlTypeDescriptionGenerator =
+    TypeDescriptionGenerator<SqlDeclarePartGenerator, SqlPropertyPartGenerator, SqlMapper>;
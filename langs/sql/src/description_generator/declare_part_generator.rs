@@ -0,0 +1,357 @@
+use std::collections::BTreeMap;
+
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CustomizableAliasTypeDeclareGenerator, CustomizableCompositeTypeDeclareGenerator,
+            CustomizableDeclarePartGenerator, TypeIdentifyConvertor,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+    type_mapper::TypeMapper,
+};
+use npc::fns::to_snake;
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{property_type::PropertyType, type_name::TypeName},
+};
+
+use super::{
+    mapper::{SqlArrayStyle, SqlDialect, SqlMapper},
+    property_part_generator::{column_name, join_table_element, unwrap_optional},
+};
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct SqlDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<SqlMapper, ConcatFn, ConcatFn>,
+    mapper: SqlMapper,
+}
+impl SqlDeclarePartGenerator {
+    const JOIN_TABLE_VALUE: &'static str = "value";
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("CREATE TABLE", Self::concat_sql_table),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "CREATE TABLE",
+                    Self::concat_sql_table,
+                ),
+            ),
+            mapper: SqlMapper::default(),
+        }
+    }
+    /// table name is already added to identify by `SqlTableNameConvertor`
+    fn concat_sql_table(identify: &str, _: &TypeName, columns: String) -> String {
+        Self::create_table(identify, columns)
+    }
+    fn create_table(identify: &str, columns: String) -> String {
+        format!(
+            "{} (\n{}\n);",
+            identify,
+            columns.trim_end_matches('\n').trim_end_matches(',')
+        )
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+    fn primary_key_column(&self) -> String {
+        format!(
+            "  {} {},\n",
+            SqlMapper::PRIMARY_KEY,
+            self.mapper.case_primary_key()
+        )
+    }
+    fn foreign_key_column(&self, table: &str) -> String {
+        format!(
+            "  {} {} NOT NULL,\n",
+            self.mapper
+                .identifier(&format!("{}{}", table, SqlMapper::PRIMARY_KEY)),
+            self.mapper.case_foreign_key()
+        )
+    }
+    fn foreign_key_constraint(&self, column: &str, table: &str) -> String {
+        format!(
+            "  FOREIGN KEY ({}) REFERENCES {} ({}),\n",
+            column,
+            self.mapper.identifier(table),
+            SqlMapper::PRIMARY_KEY
+        )
+    }
+    /// array of custom type is join table of both tables,
+    /// and array of primitive is join table which has value column
+    fn join_table(&self, table: &str, column: &str, element: &PropertyType) -> String {
+        let (element, nullable) = unwrap_optional(element);
+        let mut columns = self.foreign_key_column(table);
+        let (join_table, value) = match element {
+            PropertyType::CustomType(child) => {
+                let child = to_snake(&child.valid_lang_str());
+                columns.push_str(&self.foreign_key_column(&child));
+                let constraint = self.foreign_key_constraint(
+                    &self
+                        .mapper
+                        .identifier(&format!("{}{}", child, SqlMapper::PRIMARY_KEY)),
+                    &child,
+                );
+                (format!("{}_{}", table, child), constraint)
+            }
+            _ => {
+                columns.push_str(&format!(
+                    "  {} {}{},\n",
+                    Self::JOIN_TABLE_VALUE,
+                    self.mapper.case_property_type(element),
+                    if nullable { "" } else { " NOT NULL" }
+                ));
+                (format!("{}_{}", table, column), String::new())
+            }
+        };
+        columns.push_str(
+            &self.foreign_key_constraint(
+                &self
+                    .mapper
+                    .identifier(&format!("{}{}", table, SqlMapper::PRIMARY_KEY)),
+                table,
+            ),
+        );
+        columns.push_str(&value);
+        Self::create_table(
+            &format!("CREATE TABLE {}", self.mapper.identifier(&join_table)),
+            columns,
+        )
+    }
+}
+impl Default for SqlDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for SqlDeclarePartGenerator {
+    type Mapper = SqlMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        let table = to_snake(&composite_type.type_name().valid_lang_str());
+        let mut columns = self.primary_key_column();
+        columns.push_str(&properties_statement);
+        let mut join_tables = Vec::new();
+        composite_type.iter().for_each(|(key, property_type)| {
+            if let Some(element) = join_table_element(property_type, &self.mapper) {
+                let column = to_snake(&key.invalid_lang_str());
+                join_tables.push(self.join_table(&table, &column, element));
+                return;
+            }
+            if let PropertyType::CustomType(child) = unwrap_optional(property_type).0 {
+                columns.push_str(&self.foreign_key_constraint(
+                    &column_name(key, property_type, &self.mapper),
+                    &to_snake(&child.valid_lang_str()),
+                ));
+            }
+        });
+        let description = self.inner.generate_case_composite(composite_type, columns);
+        if description.is_empty() {
+            return description;
+        }
+        join_tables.insert(0, description);
+        join_tables.join("\n\n")
+    }
+    /// empty object is table which has only surrogate key, and others are not declared
+    fn generate_case_alias(&self, alias_type: &AliasTypeStructure, _: &Self::Mapper) -> String {
+        match alias_type.property_type() {
+            PropertyType::Any => self.generate_case_composite(
+                &CompositeTypeStructure::new(alias_type.type_name().clone(), BTreeMap::new()),
+                String::new(),
+            ),
+            _ => String::new(),
+        }
+    }
+    /// child tables are declared before parent tables which refer them
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        descriptions
+            .into_iter()
+            .rev()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .map(|body| format!("{}\n", body))
+            .unwrap_or_default()
+    }
+}
+
+pub struct SqlDeclarePartGeneratorBuilder {
+    generator: SqlDeclarePartGenerator,
+}
+impl SqlDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: SqlDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> SqlDeclarePartGenerator {
+        let mut generator = self.generator;
+        let mapper = generator.mapper;
+        generator
+            .change_composite_generator()
+            .add_type_identify_convertor(Box::new(SqlTableNameConvertor { mapper }));
+        generator
+    }
+    /// dialect and array style must be same as mapper's one
+    pub fn dialect(mut self, dialect: SqlDialect) -> Self {
+        self.generator.mapper = SqlMapper::new(dialect, self.generator.mapper.array_style());
+        self
+    }
+    pub fn array_style(mut self, array_style: SqlArrayStyle) -> Self {
+        self.generator.mapper = SqlMapper::new(self.generator.mapper.dialect(), array_style);
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("-- {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for SqlDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SqlTableNameConvertor {
+    mapper: SqlMapper,
+}
+impl TypeIdentifyConvertor for SqlTableNameConvertor {
+    fn convert(&self, acc: &mut String, type_name: &TypeName) {
+        *acc = format!("{} {}", acc, self.mapper.table_name(type_name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn create_tableとして宣言され外部キーの制約が付与される() {
+        let composite_type = CompositeTypeStructure::new(
+            "Test",
+            BTreeMap::from([
+                (
+                    "child".into(),
+                    make_optional_type(make_custom_type("TestChild")),
+                ),
+                ("name".into(), make_string_type()),
+            ]),
+        );
+        let sut = SqlDeclarePartGeneratorBuilder::new()
+            .dialect(SqlDialect::Sqlite)
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(
+            &composite_type,
+            "  child_id INTEGER,\n  name TEXT NOT NULL,\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"-- this is comment
+CREATE TABLE test (
+  _id INTEGER PRIMARY KEY AUTOINCREMENT,
+  child_id INTEGER,
+  name TEXT NOT NULL,
+  FOREIGN KEY (child_id) REFERENCES test_child (_id)
+);"#
+        );
+    }
+    #[test]
+    fn 配列はjoin_tableとして宣言される() {
+        let composite_type = CompositeTypeStructure::new(
+            "Test",
+            BTreeMap::from([
+                ("data".into(), make_array_type(make_custom_type("TestData"))),
+                (
+                    "tags".into(),
+                    make_array_type(make_optional_type(make_string_type())),
+                ),
+            ]),
+        );
+        let sut = SqlDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(&composite_type, String::new());
+
+        assert_eq!(
+            result,
+            r#"CREATE TABLE test (
+  _id BIGSERIAL PRIMARY KEY
+);
+
+CREATE TABLE test_test_data (
+  test_id BIGINT NOT NULL,
+  test_data_id BIGINT NOT NULL,
+  FOREIGN KEY (test_id) REFERENCES test (_id),
+  FOREIGN KEY (test_data_id) REFERENCES test_data (_id)
+);
+
+CREATE TABLE test_tags (
+  test_id BIGINT NOT NULL,
+  value TEXT,
+  FOREIGN KEY (test_id) REFERENCES test (_id)
+);"#
+        );
+    }
+    #[test]
+    fn 空のオブジェクトはサロゲートキーだけのテーブルになり子から順に宣言される() {
+        let mapper = SqlMapper::default();
+        let sut = SqlDeclarePartGeneratorBuilder::new()
+            .dialect(SqlDialect::Mysql)
+            .build();
+        let empty = AliasTypeStructure::new("TestEmpty", PropertyType::Any);
+        let array = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+
+        let result = sut.generate_case_concat(vec![
+            sut.generate_case_alias(&array, &mapper),
+            "CREATE TABLE test (\n  _id BIGINT AUTO_INCREMENT PRIMARY KEY\n);".to_string(),
+            sut.generate_case_alias(&empty, &mapper),
+        ]);
+
+        assert_eq!(
+            result,
+            r#"CREATE TABLE test_empty (
+  _id BIGINT AUTO_INCREMENT PRIMARY KEY
+);
+
+CREATE TABLE test (
+  _id BIGINT AUTO_INCREMENT PRIMARY KEY
+);
+"#
+        );
+    }
+}
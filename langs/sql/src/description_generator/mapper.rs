@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use npc::fns::to_snake;
+use structure::parts::type_name::TypeName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlDialect {
+    #[default]
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+impl FromStr for SqlDialect {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "postgres" | "postgresql" | "pg" | "Postgres" => Ok(Self::Postgres),
+            "mysql" | "MySQL" | "Mysql" => Ok(Self::Mysql),
+            "sqlite" | "SQLite" | "Sqlite" => Ok(Self::Sqlite),
+            _ => Err(format!("{} is not define sql dialect", str)),
+        }
+    }
+}
+
+/// how array property is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlArrayStyle {
+    /// each element is a row of join table
+    #[default]
+    JoinTable,
+    /// whole array is a json column
+    Json,
+}
+impl FromStr for SqlArrayStyle {
+    type Err = String;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "join" | "join_table" | "JoinTable" => Ok(Self::JoinTable),
+            "json" | "Json" => Ok(Self::Json),
+            _ => Err(format!("{} is not define sql array style", str)),
+        }
+    }
+}
+
+/// column type of each dialect.
+/// custom type is foreign key to surrogate key of the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SqlMapper {
+    dialect: SqlDialect,
+    array_style: SqlArrayStyle,
+}
+impl SqlMapper {
+    pub const PRIMARY_KEY: &'static str = "_id";
+    const RESERVED_WORDS: [&'static str; 24] = [
+        "all", "and", "as", "by", "case", "check", "column", "create", "default", "desc", "from",
+        "group", "index", "key", "limit", "not", "null", "or", "order", "select", "table", "to",
+        "user", "where",
+    ];
+    pub fn new(dialect: SqlDialect, array_style: SqlArrayStyle) -> Self {
+        Self {
+            dialect,
+            array_style,
+        }
+    }
+    pub fn dialect(&self) -> SqlDialect {
+        self.dialect
+    }
+    pub fn array_style(&self) -> SqlArrayStyle {
+        self.array_style
+    }
+    pub fn case_primary_key(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres => "BIGSERIAL PRIMARY KEY".to_string(),
+            SqlDialect::Mysql => "BIGINT AUTO_INCREMENT PRIMARY KEY".to_string(),
+            SqlDialect::Sqlite => "INTEGER PRIMARY KEY AUTOINCREMENT".to_string(),
+        }
+    }
+    pub fn case_foreign_key(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres | SqlDialect::Mysql => "BIGINT".to_string(),
+            SqlDialect::Sqlite => "INTEGER".to_string(),
+        }
+    }
+    pub fn case_json(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres => "JSONB".to_string(),
+            SqlDialect::Mysql => "JSON".to_string(),
+            SqlDialect::Sqlite => "TEXT".to_string(),
+        }
+    }
+    /// quote identifier only when it is reserved word
+    pub fn identifier(&self, name: &str) -> String {
+        if !Self::RESERVED_WORDS.contains(&name) {
+            return name.to_string();
+        }
+        match self.dialect {
+            SqlDialect::Mysql => format!("`{}`", name),
+            SqlDialect::Postgres | SqlDialect::Sqlite => format!("\"{}\"", name),
+        }
+    }
+    pub fn table_name(&self, type_name: &TypeName) -> String {
+        self.identifier(&to_snake(&type_name.valid_lang_str()))
+    }
+}
+
+impl TypeMapper for SqlMapper {
+    fn case_string(&self) -> TypeString {
+        "TEXT".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        self.case_json()
+    }
+    fn case_custom_type(&self, _: &TypeName) -> String {
+        self.case_foreign_key()
+    }
+    fn case_any(&self) -> TypeString {
+        self.case_json()
+    }
+    fn case_boolean(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres | SqlDialect::Mysql => "BOOLEAN".to_string(),
+            SqlDialect::Sqlite => "INTEGER".to_string(),
+        }
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, _: T) -> TypeString {
+        self.case_json()
+    }
+    /// nullability is decided by property part
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        type_statement.into()
+    }
+    fn case_datetime(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres => "TIMESTAMP".to_string(),
+            SqlDialect::Mysql => "DATETIME".to_string(),
+            SqlDialect::Sqlite => "TEXT".to_string(),
+        }
+    }
+    fn case_decimal(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres | SqlDialect::Sqlite => "NUMERIC".to_string(),
+            SqlDialect::Mysql => "DECIMAL".to_string(),
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        match self.dialect {
+            SqlDialect::Postgres => "DOUBLE PRECISION".to_string(),
+            SqlDialect::Mysql => "DOUBLE".to_string(),
+            SqlDialect::Sqlite => "REAL".to_string(),
+        }
+    }
+    fn case_isize(&self) -> TypeString {
+        self.case_foreign_key()
+    }
+    fn case_usize(&self) -> TypeString {
+        self.case_foreign_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use structure::parts::property_type::property_type_factories::{
+        make_any, make_array_type, make_datetime_type, make_decimal_type, make_float_type,
+        make_optional_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 定義されていない方言はエラーになる() {
+        assert_eq!(SqlDialect::from_str("mysql"), Ok(SqlDialect::Mysql));
+        assert!(SqlDialect::from_str("foo").is_err());
+    }
+    #[test]
+    fn 定義されていない配列の保存方法はエラーになる() {
+        assert_eq!(SqlArrayStyle::from_str("json"), Ok(SqlArrayStyle::Json));
+        assert!(SqlArrayStyle::from_str("foo").is_err());
+    }
+    #[test]
+    fn 方言ごとの列の型になる() {
+        let postgres = SqlMapper::new(SqlDialect::Postgres, SqlArrayStyle::Json);
+        let mysql = SqlMapper::new(SqlDialect::Mysql, SqlArrayStyle::Json);
+        let sqlite = SqlMapper::new(SqlDialect::Sqlite, SqlArrayStyle::Json);
+
+        assert_eq!(
+            postgres.case_property_type(&make_float_type()),
+            "DOUBLE PRECISION"
+        );
+        assert_eq!(
+            postgres.case_property_type(&make_array_type(make_string_type())),
+            "JSONB"
+        );
+        assert_eq!(mysql.case_property_type(&make_any()), "JSON");
+        assert_eq!(mysql.case_property_type(&make_datetime_type()), "DATETIME");
+        assert_eq!(mysql.case_property_type(&make_decimal_type()), "DECIMAL");
+        assert_eq!(
+            sqlite.case_property_type(&make_optional_type(make_string_type())),
+            "TEXT"
+        );
+    }
+    #[test]
+    fn 予約語は方言ごとに引用される() {
+        let postgres = SqlMapper::new(SqlDialect::Postgres, SqlArrayStyle::JoinTable);
+        let mysql = SqlMapper::new(SqlDialect::Mysql, SqlArrayStyle::JoinTable);
+
+        assert_eq!(postgres.table_name(&"User".into()), "\"user\"");
+        assert_eq!(mysql.identifier("order"), "`order`");
+        assert_eq!(mysql.identifier("user_name"), "user_name");
+    }
+}
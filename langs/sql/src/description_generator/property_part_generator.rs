@@ -0,0 +1,246 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{
+            Convertor, CustomizablePropertyDescriptionGenerator, DescriptionConvertor,
+        },
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use npc::fns::to_snake;
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::mapper::{SqlArrayStyle, SqlMapper};
+
+pub struct SqlPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, SqlMapper>,
+}
+impl SqlPropertyPartGenerator {
+    const INDENT: &'static str = "  ";
+    const NEXT_LINE: &'static str = ",\n";
+    pub fn new() -> Self {
+        fn sql_property_concat(key: String, type_: String) -> String {
+            format!("{} {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(sql_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(SqlColumnNameConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(SqlNotNullConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for SqlPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<SqlMapper> for SqlPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &SqlMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct SqlPropertyPartGeneratorBuilder {
+    generator: SqlPropertyPartGenerator,
+}
+impl SqlPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: SqlPropertyPartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> SqlPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors();
+        generator
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("-- {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for SqlPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// returns inner type and whether it is nullable
+pub(crate) fn unwrap_optional(property_type: &PropertyType) -> (&PropertyType, bool) {
+    match property_type {
+        PropertyType::Optional(inner) => (unwrap_optional(inner).0, true),
+        _ => (property_type, false),
+    }
+}
+/// custom type column is foreign key, so it is named `{key}_id`
+pub(crate) fn column_name(
+    property_key: &PropertyKey,
+    property_type: &PropertyType,
+    mapper: &SqlMapper,
+) -> String {
+    let column = to_snake(&property_key.invalid_lang_str());
+    match unwrap_optional(property_type).0 {
+        PropertyType::CustomType(_) => {
+            mapper.identifier(&format!("{}{}", column, SqlMapper::PRIMARY_KEY))
+        }
+        _ => mapper.identifier(&column),
+    }
+}
+/// element type of array which is stored in join table instead of column.
+/// nested array is always stored in json column
+pub(crate) fn join_table_element<'a>(
+    property_type: &'a PropertyType,
+    mapper: &SqlMapper,
+) -> Option<&'a PropertyType> {
+    if mapper.array_style() != SqlArrayStyle::JoinTable {
+        return None;
+    }
+    match unwrap_optional(property_type).0 {
+        PropertyType::Array(element) => match unwrap_optional(element).0 {
+            PropertyType::Array(_) => None,
+            _ => Some(element),
+        },
+        _ => None,
+    }
+}
+
+struct SqlColumnNameConvertor {}
+impl Convertor<SqlMapper> for SqlColumnNameConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &SqlMapper,
+    ) {
+        *acc = column_name(property_key, property_type, mapper);
+    }
+}
+
+/// add `NOT NULL` to required column, and remove column which is stored in join table
+struct SqlNotNullConvertor {}
+impl DescriptionConvertor<SqlMapper> for SqlNotNullConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        _: &TypeName,
+        _: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &SqlMapper,
+    ) -> Option<String> {
+        let acc = acc?;
+        if join_table_element(property_type, mapper).is_some() {
+            return None;
+        }
+        if unwrap_optional(property_type).1 {
+            Some(acc)
+        } else {
+            Some(format!("{} NOT NULL", acc))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::{SqlArrayStyle, SqlDialect, SqlMapper},
+        property_part_generator::SqlPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn 必須の列にはnot_nullが付きカスタム型は外部キーの列になる() {
+        let type_name: TypeName = "Test".into();
+        let mapper = SqlMapper::new(SqlDialect::Postgres, SqlArrayStyle::JoinTable);
+        let generator = SqlPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let name = generator.generate(
+            &type_name,
+            &"userName".into(),
+            &make_optional_type(make_string_type()),
+            &mapper,
+        );
+        let child = generator.generate(
+            &type_name,
+            &"child".into(),
+            &make_custom_type("TestChild"),
+            &mapper,
+        );
+        let order = generator.generate(&type_name, &"order".into(), &make_usize_type(), &mapper);
+
+        assert_eq!(name, "  -- this is comment\n  user_name TEXT,\n");
+        assert_eq!(child, "  -- this is comment\n  child_id BIGINT NOT NULL,\n");
+        assert_eq!(
+            order,
+            "  -- this is comment\n  \"order\" BIGINT NOT NULL,\n"
+        );
+    }
+    #[test]
+    fn 配列は設定によりjoin_tableに移るかjson列になる() {
+        let type_name: TypeName = "Test".into();
+        let tags = make_array_type(make_string_type());
+        let matrix = make_array_type(make_array_type(make_usize_type()));
+        let join_table = SqlMapper::new(SqlDialect::Mysql, SqlArrayStyle::JoinTable);
+        let json = SqlMapper::new(SqlDialect::Mysql, SqlArrayStyle::Json);
+        let generator = SqlPropertyPartGeneratorBuilder::new().build();
+
+        assert_eq!(
+            generator.generate(&type_name, &"tags".into(), &tags, &join_table),
+            ""
+        );
+        assert_eq!(
+            generator.generate(&type_name, &"matrix".into(), &matrix, &join_table),
+            "  matrix JSON NOT NULL,\n"
+        );
+        assert_eq!(
+            generator.generate(&type_name, &"tags".into(), &tags, &json),
+            "  tags JSON NOT NULL,\n"
+        );
+    }
+}
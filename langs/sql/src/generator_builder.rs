@@ -0,0 +1,141 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{SqlDeclarePartGenerator, SqlDeclarePartGeneratorBuilder},
+    mapper::{SqlArrayStyle, SqlDialect, SqlMapper},
+    property_part_generator::{SqlPropertyPartGenerator, SqlPropertyPartGeneratorBuilder},
+};
+
+pub struct SqlTypeDescriptionGeneratorBuilder {
+    pub declare_part: SqlDeclarePartGeneratorBuilder,
+    pub property_part: SqlPropertyPartGeneratorBuilder,
+    mapper: SqlMapper,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl SqlTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl SqlTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+);
+impl SqlTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: SqlDeclarePartGeneratorBuilder::new(),
+            property_part: SqlPropertyPartGeneratorBuilder::new(),
+            mapper: SqlMapper::default(),
+        }
+    }
+    pub fn dialect(mut self, dialect: SqlDialect) -> Self {
+        self.declare_part = self.declare_part.dialect(dialect);
+        self.mapper = SqlMapper::new(dialect, self.mapper.array_style());
+        self
+    }
+    pub fn array_style(mut self, array_style: SqlArrayStyle) -> Self {
+        self.declare_part = self.declare_part.array_style(array_style);
+        self.mapper = SqlMapper::new(self.mapper.dialect(), array_style);
+        self
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<SqlDeclarePartGenerator, SqlPropertyPartGenerator, SqlMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, self.mapper)
+    }
+}
+impl Default for SqlTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+                ("user", make_custom_type("RootUser")),
+                (
+                    "tags",
+                    make_optional_type(make_array_type(make_string_type())),
+                ),
+            ],
+        );
+        let root_data = TypeStructure::make_composite("RootData", vec![("value", make_any())]);
+        let root_user =
+            TypeStructure::make_composite("RootUser", vec![("name", make_string_type())]);
+        let generator = SqlTypeDescriptionGeneratorBuilder::new()
+            .dialect(SqlDialect::Postgres)
+            .array_style(SqlArrayStyle::Json)
+            .build();
+
+        let result = generator.generate_concat_define(vec![root, root_data, root_user]);
+
+        assert_eq!(
+            result,
+            r#"CREATE TABLE root_user (
+  _id BIGSERIAL PRIMARY KEY,
+  name TEXT NOT NULL
+);
+
+CREATE TABLE root_data (
+  _id BIGSERIAL PRIMARY KEY,
+  value JSONB NOT NULL
+);
+
+CREATE TABLE root (
+  _id BIGSERIAL PRIMARY KEY,
+  data JSONB NOT NULL,
+  id BIGINT NOT NULL,
+  tags JSONB,
+  user_id BIGINT NOT NULL,
+  FOREIGN KEY (user_id) REFERENCES root_user (_id)
+);
+"#
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::SwiftDeclarePartGenerator, mapper::SwiftMapper,
+    property_part_generator::SwiftPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type SwiftTypeDescriptionGenerator =
+    TypeDescriptionGenerator<SwiftDeclarePartGenerator, SwiftPropertyPartGenerator, SwiftMapper>;
@@ -0,0 +1,268 @@
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure, composite_type_structure::CompositeTypeStructure,
+    parts::type_name::TypeName,
+};
+
+use super::{
+    mapper::SwiftMapper,
+    property_part_generator::{escape_swift_keyword, swift_property_name},
+};
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct SwiftDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<SwiftMapper, ConcatFn, ConcatFn>,
+}
+impl SwiftDeclarePartGenerator {
+    const HEADER: &'static str = "import Foundation";
+    /// AnyCodable is package (https://github.com/Flight-School/AnyCodable), not Foundation
+    const ANY_CODABLE_IMPORT: &'static str = "import AnyCodable";
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("typealias", Self::concat_swift_alias),
+                CustomizableCompositeTypeDeclareGenerator::new("struct", Self::concat_swift_struct),
+            ),
+        }
+    }
+    fn concat_swift_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{} {} = {}", identify, type_name.valid_lang_str(), type_)
+    }
+    fn concat_swift_struct(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {}: Codable {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<SwiftMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for SwiftDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for SwiftDeclarePartGenerator {
+    type Mapper = SwiftMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// AnyCodable is imported, only if any value is used
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .map(|body| {
+                if body.contains("AnyCodable") {
+                    format!(
+                        "{}\n{}\n\n{}\n",
+                        Self::HEADER,
+                        Self::ANY_CODABLE_IMPORT,
+                        body
+                    )
+                } else {
+                    format!("{}\n\n{}\n", Self::HEADER, body)
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
+pub struct SwiftDeclarePartGeneratorBuilder {
+    generator: SwiftDeclarePartGenerator,
+}
+impl SwiftDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: SwiftDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> SwiftDeclarePartGenerator {
+        let mut generator = self.generator;
+        generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(SwiftCodingKeysConvertor {}));
+        generator
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for SwiftDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// add `CodingKeys` when some property name is not same as original key.
+/// CodingKeys must list all properties, so not renamed properties are also listed
+struct SwiftCodingKeysConvertor {}
+impl CompositeTypeDeclareConvertor for SwiftCodingKeysConvertor {
+    fn convert(
+        &self,
+        acc: Option<String>,
+        composite_type: &CompositeTypeStructure,
+    ) -> Option<String> {
+        let acc = acc?;
+        let type_name = composite_type.type_name();
+        let keys = composite_type
+            .iter()
+            .map(|(key, property_type)| {
+                (
+                    key.as_str(),
+                    swift_property_name(type_name, key, property_type),
+                )
+            })
+            // properties removed by property part (for example blacklist) are not listed
+            .filter(|(_, name)| acc.contains(&format!(" {}: ", escape_swift_keyword(name.clone()))))
+            .collect::<Vec<_>>();
+        if keys.iter().all(|(original, name)| original == name) {
+            return Some(acc);
+        }
+        let cases = keys
+            .into_iter()
+            .fold(String::new(), |acc, (original, name)| {
+                let name = if original == name {
+                    escape_swift_keyword(name)
+                } else {
+                    format!("{} = \"{}\"", escape_swift_keyword(name), original)
+                };
+                format!("{}        case {}\n", acc, name)
+            });
+        let Some(declare) = acc.strip_suffix('}') else {
+            return Some(acc);
+        };
+        Some(format!(
+            "{}\n    enum CodingKeys: String, CodingKey {{\n{}    }}\n}}",
+            declare, cases
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn キー名が変わらない場合coding_keysは出力しない() {
+        let mut properties = BTreeMap::new();
+        properties.insert("id".into(), make_usize_type());
+        let composite_type = CompositeTypeStructure::new("Test", properties);
+        let sut = SwiftDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "    let id: Int\n".to_string());
+
+        assert_eq!(
+            result,
+            "// this is comment\nstruct Test: Codable {\n    let id: Int\n}"
+        );
+    }
+    #[test]
+    fn キー名が変わる場合coding_keysを出力する() {
+        let mut properties = BTreeMap::new();
+        properties.insert("default".into(), make_usize_type());
+        properties.insert("user_name".into(), make_string_type());
+        properties.insert("removed".into(), make_string_type());
+        let composite_type = CompositeTypeStructure::new("Test", properties);
+        let sut = SwiftDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(
+            &composite_type,
+            "    let `default`: Int\n    let userName: String\n".to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"struct Test: Codable {
+    let `default`: Int
+    let userName: String
+
+    enum CodingKeys: String, CodingKey {
+        case `default`
+        case userName = "user_name"
+    }
+}"#
+        );
+    }
+    #[test]
+    fn alias型はtypealiasとして出力される() {
+        let mapper = SwiftMapper;
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = SwiftDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "typealias TestArray = [Test]");
+    }
+}
@@ -0,0 +1,64 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// `Any` is not Codable, so any value is mapped to `AnyCodable` (https://github.com/Flight-School/AnyCodable)
+pub struct SwiftMapper;
+
+impl TypeMapper for SwiftMapper {
+    fn case_string(&self) -> TypeString {
+        "String".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "AnyCodable?".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "AnyCodable".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "Bool".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("[{}]", type_statement.into())
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement.ends_with('?') {
+            type_statement
+        } else {
+            format!("{}?", type_statement)
+        }
+    }
+    fn case_float(&self) -> TypeString {
+        "Double".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "Int".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "Int".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type,
+    };
+
+    use super::SwiftMapper;
+
+    #[test]
+    fn 配列とoptionalはswiftの糖衣構文で表現される() {
+        let mapper = SwiftMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_optional_type(
+                make_custom_type("Test")
+            )))),
+            "[Test?]?"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor, Principal,
+            RenameConvertor,
+        },
+        property_part_generator::{Convertor, CustomizablePropertyDescriptionGenerator},
+    },
+    type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::mapper::SwiftMapper;
+
+pub struct SwiftPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, SwiftMapper>,
+}
+impl SwiftPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = "\n";
+    pub fn new() -> Self {
+        fn swift_property_concat(key: String, type_: String) -> String {
+            format!("let {}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(swift_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, all_optional: bool) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(swift_rename_convertor()));
+        self.generator
+            .add_property_key_convertor(Box::new(SwiftEscapeKeywordConvertor {}));
+        if all_optional {
+            self.generator
+                .add_property_type_convertor(Box::new(SwiftAllOptionalConvertor {}));
+        }
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for SwiftPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<SwiftMapper> for SwiftPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &SwiftMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct SwiftPropertyPartGeneratorBuilder {
+    generator: SwiftPropertyPartGenerator,
+    all_optional: bool,
+}
+impl SwiftPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: SwiftPropertyPartGenerator::new(),
+            all_optional: false,
+        }
+    }
+    pub fn build(self) -> SwiftPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.all_optional);
+        generator
+    }
+    pub fn all_optional(mut self) -> Self {
+        self.all_optional = true;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for SwiftPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn swift_rename_convertor() -> RenameConvertor {
+    let mut convertor = RenameConvertor::new(Principal::Camel);
+    convertor.set_all();
+    convertor
+}
+/// property name used in swift struct, which is converted to camel case by same convertor as property part.
+/// returned name is not escaped, so it can be compared with original key
+pub(crate) fn swift_property_name(
+    type_name: &TypeName,
+    property_key: &PropertyKey,
+    property_type: &PropertyType,
+) -> String {
+    let mut name = property_key.invalid_lang_str();
+    Convertor::<SwiftMapper>::convert(
+        &swift_rename_convertor(),
+        &mut name,
+        type_name,
+        property_key,
+        property_type,
+        &SwiftMapper,
+    );
+    name
+}
+/// keywords can be used as name by escaping with backticks
+pub(crate) fn escape_swift_keyword(name: String) -> String {
+    if SwiftReservedWords::is_keyword(&name) {
+        format!("`{}`", name)
+    } else {
+        name
+    }
+}
+
+struct SwiftEscapeKeywordConvertor {}
+impl Convertor<SwiftMapper> for SwiftEscapeKeywordConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        _: &PropertyKey,
+        _: &PropertyType,
+        _: &SwiftMapper,
+    ) {
+        *acc = escape_swift_keyword(acc.clone());
+    }
+}
+
+struct SwiftAllOptionalConvertor {}
+impl Convertor<SwiftMapper> for SwiftAllOptionalConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        _: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &SwiftMapper,
+    ) {
+        if !matches!(property_type, PropertyType::Optional(_)) {
+            *acc = mapper.case_optional_type(acc.clone());
+        }
+    }
+}
+
+struct SwiftReservedWords;
+impl SwiftReservedWords {
+    const KEYWORDS: [&'static str; 48] = [
+        "associatedtype",
+        "class",
+        "deinit",
+        "enum",
+        "extension",
+        "fileprivate",
+        "func",
+        "import",
+        "init",
+        "inout",
+        "internal",
+        "let",
+        "open",
+        "operator",
+        "private",
+        "protocol",
+        "public",
+        "rethrows",
+        "static",
+        "struct",
+        "subscript",
+        "typealias",
+        "var",
+        "break",
+        "case",
+        "continue",
+        "default",
+        "defer",
+        "do",
+        "else",
+        "fallthrough",
+        "for",
+        "guard",
+        "if",
+        "in",
+        "repeat",
+        "return",
+        "switch",
+        "where",
+        "while",
+        "as",
+        "catch",
+        "false",
+        "is",
+        "nil",
+        "super",
+        "self",
+        "true",
+    ];
+    fn is_keyword(word: &str) -> bool {
+        Self::KEYWORDS.contains(&word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::SwiftMapper, property_part_generator::SwiftPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn プロパティはcamel_caseのletで宣言される() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "user_name".into();
+        let property_type = make_optional_type(make_string_type());
+        let mapper = SwiftMapper;
+        let generator = SwiftPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(
+            result,
+            "    // this is comment\n    let userName: String?\n"
+        );
+    }
+    #[test]
+    fn 予約語はバッククォートで囲まれる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "default".into();
+        let property_type = make_usize_type();
+        let mapper = SwiftMapper;
+        let generator = SwiftPropertyPartGeneratorBuilder::new()
+            .all_optional()
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    let `default`: Int?\n");
+    }
+}
@@ -0,0 +1,137 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{SwiftDeclarePartGenerator, SwiftDeclarePartGeneratorBuilder},
+    mapper::SwiftMapper,
+    property_part_generator::{SwiftPropertyPartGenerator, SwiftPropertyPartGeneratorBuilder},
+};
+
+pub struct SwiftTypeDescriptionGeneratorBuilder {
+    pub declare_part: SwiftDeclarePartGeneratorBuilder,
+    pub property_part: SwiftPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl SwiftTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl SwiftTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{all_optional,}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+);
+impl SwiftTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: SwiftDeclarePartGeneratorBuilder::new(),
+            property_part: SwiftPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<SwiftDeclarePartGenerator, SwiftPropertyPartGenerator, SwiftMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, SwiftMapper)
+    }
+}
+impl Default for SwiftTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![("user-name", make_optional_type(make_string_type()))],
+        );
+        let generator = SwiftTypeDescriptionGeneratorBuilder::new().build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"import Foundation
+
+struct Root: Codable {
+    let data: [RootData]
+    let id: Int
+}
+
+struct RootData: Codable {
+    let userName: String?
+
+    enum CodingKeys: String, CodingKey {
+        case userName = "user-name"
+    }
+}
+"#
+        );
+    }
+    #[test]
+    fn anyが使われるとany_codableがimportされる() {
+        let root = TypeStructure::make_composite("Root", vec![("meta", make_any())]);
+        let generator = SwiftTypeDescriptionGeneratorBuilder::new().build();
+
+        let result = generator.generate_concat_define(vec![root]);
+
+        assert_eq!(
+            result,
+            r#"import Foundation
+import AnyCodable
+
+struct Root: Codable {
+    let meta: AnyCodable
+}
+"#
+        );
+    }
+}
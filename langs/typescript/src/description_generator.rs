@@ -0,0 +1,15 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::TypeScriptDeclarePartGenerator, mapper::TypeScriptMapper,
+    property_part_generator::TypeScriptPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type TypeScriptTypeDescriptionGenerator = TypeDescriptionGenerator<
+    TypeScriptDeclarePartGenerator,
+    TypeScriptPropertyPartGenerator,
+    TypeScriptMapper,
+>;
@@ -0,0 +1,248 @@
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CompositeTypeDeclareConvertor, CustomizableAliasTypeDeclareGenerator,
+            CustomizableCompositeTypeDeclareGenerator, CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::DeclarePartGenerator,
+};
+use structure::parts::type_name::TypeName;
+
+use super::mapper::TypeScriptMapper;
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct TypeScriptDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<TypeScriptMapper, ConcatFn, ConcatFn>,
+}
+impl TypeScriptDeclarePartGenerator {
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("type", Self::concat_ts_alias),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "interface",
+                    Self::concat_ts_composite,
+                ),
+            ),
+        }
+    }
+    fn concat_ts_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!("{} {} = {};", identify, type_name.valid_lang_str(), type_)
+    }
+    fn concat_ts_composite(
+        identify: &str,
+        type_name: &TypeName,
+        property_descriptions: String,
+    ) -> String {
+        format!(
+            "{} {} {{\n{}}}",
+            identify,
+            type_name.valid_lang_str(),
+            property_descriptions
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<TypeScriptMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for TypeScriptDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for TypeScriptDeclarePartGenerator {
+    type Mapper = TypeScriptMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &structure::composite_type_structure::CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.inner
+            .generate_case_composite(composite_type, properties_statement)
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &structure::alias_type_structure::AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+}
+
+pub struct TypeScriptDeclarePartGeneratorBuilder {
+    generator: TypeScriptDeclarePartGenerator,
+}
+impl TypeScriptDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: TypeScriptDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> TypeScriptDeclarePartGenerator {
+        self.generator
+    }
+    pub fn export_all(self) -> Self {
+        self.export_all_alias().export_all_composite()
+    }
+    pub fn export_all_alias(mut self) -> Self {
+        let mut convertor = AddHeaderConvertor::new("export ");
+        convertor.all();
+        self.generator
+            .change_alias_generator()
+            .add_type_identify_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn export_all_composite(mut self) -> Self {
+        let mut convertor = AddHeaderConvertor::new("export ");
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_type_identify_convertor(convertor.to_declare_part());
+        self
+    }
+    /// declare composite type as `type Test = {...};` instead of `interface Test {...}`
+    pub fn use_type_alias(mut self) -> Self {
+        struct ToTypeAliasConvertor {}
+        impl CompositeTypeDeclareConvertor for ToTypeAliasConvertor {
+            fn convert(
+                &self,
+                acc: Option<String>,
+                composite_type: &structure::composite_type_structure::CompositeTypeStructure,
+            ) -> Option<String> {
+                let acc = acc?;
+                let type_name = composite_type.type_name().valid_lang_str();
+                Some(format!(
+                    "{};",
+                    acc.replacen(
+                        &format!("interface {} {{", type_name),
+                        &format!("type {} = {{", type_name),
+                        1
+                    )
+                ))
+            }
+        }
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(Box::new(ToTypeAliasConvertor {}));
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for TypeScriptDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::{
+        alias_type_structure::AliasTypeStructure,
+        composite_type_structure::CompositeTypeStructure,
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_string_type,
+        },
+    };
+
+    use super::*;
+    #[test]
+    fn 複合型はinterfaceとして出力される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = TypeScriptDeclarePartGenerator::new();
+
+        let result = sut.generate_case_composite(&composite_type, "    id: number;\n".to_string());
+
+        assert_eq!(
+            result,
+            r#"interface Test {
+    id: number;
+}"#
+        );
+    }
+    #[test]
+    fn use_type_aliasを設定すると複合型はtype_aliasとして出力される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = TypeScriptDeclarePartGeneratorBuilder::new()
+            .use_type_alias()
+            .export_all()
+            .all_comment("this is comment")
+            .build();
+
+        let result = sut.generate_case_composite(&composite_type, "    id: number;\n".to_string());
+
+        assert_eq!(
+            result,
+            r#"// this is comment
+export type Test = {
+    id: number;
+};"#
+        );
+    }
+    #[test]
+    fn alias型はtypeとして出力される() {
+        let mapper = TypeScriptMapper;
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+        let sut = TypeScriptDeclarePartGeneratorBuilder::new()
+            .export_all()
+            .build();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "export type TestArray = Test[];");
+    }
+    #[test]
+    fn 不正な文字列は型名に指定できない() {
+        let mapper = TypeScriptMapper;
+        let alias = AliasTypeStructure::new(TypeName::new("Test:invalidName"), make_string_type());
+        let sut = TypeScriptDeclarePartGenerator::new();
+
+        let result = sut.generate_case_alias(&alias, &mapper);
+
+        assert_eq!(result, "type TestInvalidName = string;");
+    }
+}
@@ -0,0 +1,66 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+pub struct TypeScriptMapper;
+
+impl TypeMapper for TypeScriptMapper {
+    fn case_string(&self) -> TypeString {
+        "string".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "null".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "any".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "boolean".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        // union type needs parentheses, for example (string | null)[]
+        if type_statement.contains(' ') {
+            format!("({})[]", type_statement)
+        } else {
+            format!("{}[]", type_statement)
+        }
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("{} | {}", type_statement.into(), self.case_null())
+    }
+    fn case_float(&self) -> TypeString {
+        "number".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "number".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "number".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_mapper::TypeMapper;
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_optional_type, make_string_type,
+    };
+
+    use super::TypeScriptMapper;
+
+    #[test]
+    fn optionalな要素の配列は括弧で囲まれる() {
+        let mapper = TypeScriptMapper;
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_string_type())),
+            "string[]"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_string_type()))),
+            "(string | null)[]"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{Convertor, CustomizablePropertyDescriptionGenerator},
+    },
+    type_description_generator::PropertyPartGenerator,
+    type_mapper::TypeMapper,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::mapper::TypeScriptMapper;
+
+pub struct TypeScriptPropertyPartGenerator {
+    generator:
+        CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, TypeScriptMapper>,
+}
+impl TypeScriptPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = ";\n";
+    pub fn new() -> Self {
+        fn ts_property_concat(key: String, type_: String) -> String {
+            format!("{}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(ts_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self, all_optional: bool) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(TypeScriptPropertyKeyConvertor { all_optional }));
+        self.generator
+            .add_property_type_convertor(Box::new(TypeScriptOptionalTypeConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for TypeScriptPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<TypeScriptMapper> for TypeScriptPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &TypeScriptMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct TypeScriptPropertyPartGeneratorBuilder {
+    generator: TypeScriptPropertyPartGenerator,
+    all_optional: bool,
+}
+impl TypeScriptPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: TypeScriptPropertyPartGenerator::new(),
+            all_optional: false,
+        }
+    }
+    pub fn build(self) -> TypeScriptPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors(self.all_optional);
+        generator
+    }
+    pub fn all_optional(mut self) -> Self {
+        self.all_optional = true;
+        self
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for TypeScriptPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// TypeScript can use original json key as property name.
+/// if key is not valid identifier, key is quoted like `"key:value"`.
+/// optional property is expressed by `?`
+struct TypeScriptPropertyKeyConvertor {
+    all_optional: bool,
+}
+impl TypeScriptPropertyKeyConvertor {
+    fn is_valid_identifier(key: &str) -> bool {
+        let mut chars = key.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        (first.is_ascii_alphabetic() || first == '_' || first == '$')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    }
+}
+impl Convertor<TypeScriptMapper> for TypeScriptPropertyKeyConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        _mapper: &TypeScriptMapper,
+    ) {
+        let key = property_key.as_str();
+        *acc = if Self::is_valid_identifier(key) {
+            key.to_string()
+        } else {
+            format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+        };
+        if self.all_optional || matches!(property_type, PropertyType::Optional(_)) {
+            acc.push('?');
+        }
+    }
+}
+
+/// optional property is expressed by `?` at key, so type is not union with null
+struct TypeScriptOptionalTypeConvertor {}
+impl Convertor<TypeScriptMapper> for TypeScriptOptionalTypeConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _type_name: &TypeName,
+        _property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &TypeScriptMapper,
+    ) {
+        if let PropertyType::Optional(inner) = property_type {
+            *acc = mapper.case_property_type(inner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_key::PropertyKey,
+        property_type::property_type_factories::{
+            make_array_type, make_optional_type, make_string_type, make_usize_type,
+        },
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::TypeScriptMapper, property_part_generator::TypeScriptPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn optionalなプロパティはkeyに疑問符がつく() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id".into();
+        let property_type = make_optional_type(make_usize_type());
+        let mapper = TypeScriptMapper;
+        let generator = TypeScriptPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    id?: number;\n");
+    }
+    #[test]
+    fn 全てのプロパティをoptionalにできる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "names".into();
+        let property_type = make_array_type(make_string_type());
+        let mapper = TypeScriptMapper;
+        let generator = TypeScriptPropertyPartGeneratorBuilder::new()
+            .all_optional()
+            .all_comment("this is comment")
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    // this is comment\n    names?: string[];\n");
+    }
+    #[test]
+    fn 識別子に使えないkeyはクォートされる() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id:value".into();
+        let property_type = make_usize_type();
+        let mapper = TypeScriptMapper;
+        let generator = TypeScriptPropertyPartGeneratorBuilder::new().build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "    \"id:value\": number;\n");
+    }
+    #[test]
+    fn blacklistに指定したkeyは出力されない() {
+        let type_name: TypeName = "Test".into();
+        let property_key: PropertyKey = "id".into();
+        let property_type = make_usize_type();
+        let mapper = TypeScriptMapper;
+        let generator = TypeScriptPropertyPartGeneratorBuilder::new()
+            .set_blacklist_with_keys(vec!["id"])
+            .build();
+
+        let result = generator.generate(&type_name, &property_key, &property_type, &mapper);
+
+        assert_eq!(result, "");
+    }
+}
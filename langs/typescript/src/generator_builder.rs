@@ -0,0 +1,135 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{
+        TypeScriptDeclarePartGenerator, TypeScriptDeclarePartGeneratorBuilder,
+    },
+    mapper::TypeScriptMapper,
+    property_part_generator::{
+        TypeScriptPropertyPartGenerator, TypeScriptPropertyPartGeneratorBuilder,
+    },
+};
+
+pub struct TypeScriptTypeDescriptionGeneratorBuilder {
+    pub declare_part: TypeScriptDeclarePartGeneratorBuilder,
+    pub property_part: TypeScriptPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl TypeScriptTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl TypeScriptTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_optional,}
+    ,{all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {export_all,}
+    ,{export_all_alias,}
+    ,{export_all_composite,}
+    ,{use_type_alias,}
+    ,{all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+);
+impl TypeScriptTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: TypeScriptDeclarePartGeneratorBuilder::new(),
+            property_part: TypeScriptPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<
+        TypeScriptDeclarePartGenerator,
+        TypeScriptPropertyPartGenerator,
+        TypeScriptMapper,
+    > {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, TypeScriptMapper)
+    }
+}
+impl Default for TypeScriptTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test_case_set_all() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+            ],
+        );
+        let root_data = TypeStructure::make_composite(
+            "RootData",
+            vec![
+                ("user-name", make_string_type()),
+                ("accountId", make_optional_type(make_string_type())),
+            ],
+        );
+        let generator = TypeScriptTypeDescriptionGeneratorBuilder::new()
+            .declare_part_export_all()
+            .declare_part_all_comment("this is type")
+            .property_part_all_comment("this is property")
+            .build();
+
+        let tobe = vec![
+            r#"// this is type
+export interface Root {
+    // this is property
+    data: RootData[];
+    // this is property
+    id: number;
+}"#,
+            r#"// this is type
+export interface RootData {
+    // this is property
+    accountId?: string;
+    // this is property
+    "user-name": string;
+}"#,
+        ];
+        let expect = generator.generate(vec![root, root_data]);
+        assert_eq!(expect, tobe)
+    }
+}
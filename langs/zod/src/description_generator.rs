@@ -0,0 +1,12 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use self::{
+    declare_part_generator::ZodDeclarePartGenerator, mapper::ZodMapper,
+    property_part_generator::ZodPropertyPartGenerator,
+};
+
+pub mod declare_part_generator;
+pub mod mapper;
+pub mod property_part_generator;
+pub type ZodTypeDescriptionGenerator =
+    TypeDescriptionGenerator<ZodDeclarePartGenerator, ZodPropertyPartGenerator, ZodMapper>;
@@ -0,0 +1,343 @@
+use description_generator::{
+    customizable::{
+        declare_part_convetors::{
+            AddHeaderConvertor, BlackListConvertor, ToDeclarePartConvertor, WhiteListConvertor,
+        },
+        declare_part_generator::{
+            CustomizableAliasTypeDeclareGenerator, CustomizableCompositeTypeDeclareGenerator,
+            CustomizableDeclarePartGenerator,
+        },
+    },
+    type_description_generator::{DeclarePartGenerator, TypeDescription},
+    type_mapper::TypeMapper,
+};
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{property_type::PropertyType, type_name::TypeName},
+    type_structure::TypeStructure,
+};
+
+use super::{
+    mapper::{ZodMapper, ZodTypeScriptMapper},
+    property_part_generator::ZodPropertyKeyConvertor,
+};
+
+type ConcatFn = fn(&str, &TypeName, String) -> String;
+pub struct ZodDeclarePartGenerator {
+    inner: CustomizableDeclarePartGenerator<ZodMapper, ConcatFn, ConcatFn>,
+}
+impl ZodDeclarePartGenerator {
+    const IMPORT: &'static str = "import { z } from \"zod\";";
+    pub fn new() -> Self {
+        Self {
+            inner: CustomizableDeclarePartGenerator::new(
+                CustomizableAliasTypeDeclareGenerator::new("export const", Self::concat_zod_alias),
+                CustomizableCompositeTypeDeclareGenerator::new(
+                    "export const",
+                    Self::concat_zod_object,
+                ),
+            ),
+        }
+    }
+    /// schema and its inferred type are exported together
+    fn infer_type(type_name: &TypeName) -> String {
+        format!(
+            "export type {} = z.infer<typeof {}>;",
+            type_name.valid_lang_str(),
+            ZodMapper::schema_name(type_name)
+        )
+    }
+    fn concat_zod_alias(identify: &str, type_name: &TypeName, type_: String) -> String {
+        format!(
+            "{} {} = {};\n{}",
+            identify,
+            ZodMapper::schema_name(type_name),
+            type_,
+            Self::infer_type(type_name)
+        )
+    }
+    fn concat_zod_object(identify: &str, type_name: &TypeName, properties: String) -> String {
+        format!(
+            "{} {} = z.object({{\n{}}});\n{}",
+            identify,
+            ZodMapper::schema_name(type_name),
+            properties,
+            Self::infer_type(type_name)
+        )
+    }
+    /// custom type which property refers directly
+    fn referred_type(property_type: &PropertyType) -> Option<&TypeName> {
+        match property_type {
+            PropertyType::CustomType(custom_type) => Some(custom_type),
+            PropertyType::Array(property_type) | PropertyType::Optional(property_type) => {
+                Self::referred_type(property_type)
+            }
+            _ => None,
+        }
+    }
+    fn composite_referred_types(composite_type: &CompositeTypeStructure) -> Vec<&TypeName> {
+        composite_type
+            .iter()
+            .filter_map(|(_, property_type)| Self::referred_type(property_type))
+            .collect()
+    }
+    /// type refers itself directly or through other types (for example A -> B -> A)
+    fn is_recursive(composite_type: &CompositeTypeStructure, structures: &[TypeStructure]) -> bool {
+        let type_name = composite_type.type_name();
+        let mut visited: Vec<&TypeName> = Vec::new();
+        let mut stack = Self::composite_referred_types(composite_type);
+        while let Some(current) = stack.pop() {
+            if current == type_name {
+                return true;
+            }
+            if visited.contains(&current) {
+                continue;
+            }
+            visited.push(current);
+            match structures.iter().find(|s| s.type_name() == current) {
+                Some(TypeStructure::Composite(composite_type)) => {
+                    stack.extend(Self::composite_referred_types(composite_type))
+                }
+                Some(TypeStructure::Alias(alias_type)) => {
+                    stack.extend(Self::referred_type(alias_type.property_type()))
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+    /// optional property is optional key, as same as inferred type.
+    /// properties removed by property part (for example blacklist) are not declared
+    fn declare_type(composite_type: &CompositeTypeStructure, properties_statement: &str) -> String {
+        let mapper = ZodTypeScriptMapper;
+        let properties = composite_type
+            .iter()
+            .map(|(key, property_type)| {
+                (ZodPropertyKeyConvertor::to_key(key.as_str()), property_type)
+            })
+            .filter(|(key, _)| properties_statement.contains(&format!("    {}: ", key)))
+            .map(|(key, property_type)| {
+                let (key, property_type) = match property_type {
+                    PropertyType::Optional(property_type) => {
+                        (format!("{}?", key), property_type.as_ref())
+                    }
+                    _ => (key, property_type),
+                };
+                format!(
+                    "    {}: {};\n",
+                    key,
+                    mapper.case_property_type(property_type)
+                )
+            })
+            .collect::<String>();
+        format!(
+            "export type {} = {{\n{}}};",
+            composite_type.type_name().valid_lang_str(),
+            properties
+        )
+    }
+    fn change_alias_generator(
+        &mut self,
+    ) -> &mut CustomizableAliasTypeDeclareGenerator<ZodMapper, ConcatFn> {
+        self.inner.change_alias_generator()
+    }
+    fn change_composite_generator(
+        &mut self,
+    ) -> &mut CustomizableCompositeTypeDeclareGenerator<ConcatFn> {
+        self.inner.change_composite_generator()
+    }
+}
+impl Default for ZodDeclarePartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeclarePartGenerator for ZodDeclarePartGenerator {
+    type Mapper = ZodMapper;
+    fn generate_case_composite(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+    ) -> String {
+        self.generate_case_composite_among(
+            composite_type,
+            properties_statement,
+            &[TypeStructure::Composite(composite_type.clone())],
+        )
+    }
+    fn generate_case_composite_among(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+        structures: &[TypeStructure],
+    ) -> String {
+        let declare_type = Self::declare_type(composite_type, &properties_statement);
+        let description = self
+            .inner
+            .generate_case_composite(composite_type, properties_statement);
+        if !Self::is_recursive(composite_type, structures) {
+            return description;
+        }
+        // type of recursive schema is implicitly any under strict,
+        // so the type is declared and annotates the schema instead of inferred
+        let type_name = composite_type.type_name();
+        let schema_name = ZodMapper::schema_name(type_name);
+        description
+            .replace(&format!("\n{}", Self::infer_type(type_name)), "")
+            .replacen(
+                &format!("export const {} =", schema_name),
+                &format!(
+                    "{}\nexport const {}: z.ZodType<{}> =",
+                    declare_type,
+                    schema_name,
+                    type_name.valid_lang_str()
+                ),
+                1,
+            )
+    }
+    fn generate_case_alias(
+        &self,
+        alias_type: &AliasTypeStructure,
+        mapper: &Self::Mapper,
+    ) -> String {
+        self.inner.generate_case_alias(alias_type, mapper)
+    }
+    /// add import of zod
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .reduce(|acc, cur| format!("{}\n\n{}", acc, cur))
+            .map(|body| format!("{}\n\n{}\n", Self::IMPORT, body))
+            .unwrap_or_default()
+    }
+}
+
+pub struct ZodDeclarePartGeneratorBuilder {
+    generator: ZodDeclarePartGenerator,
+}
+impl ZodDeclarePartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: ZodDeclarePartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> ZodDeclarePartGenerator {
+        self.generator
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.all();
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = WhiteListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+    pub fn set_blacklist(mut self, list: Vec<impl Into<String>>) -> Self {
+        let mut convertor = BlackListConvertor::new();
+        list.into_iter().for_each(|v| convertor.add(v));
+        self.generator
+            .change_composite_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self.generator
+            .change_alias_generator()
+            .add_description_convertor(convertor.to_declare_part());
+        self
+    }
+}
+impl Default for ZodDeclarePartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn z_objectとinferした型が宣言される() {
+        let composite_type = CompositeTypeStructure::new("Test", BTreeMap::new());
+        let sut = ZodDeclarePartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let result =
+            sut.generate_case_composite(&composite_type, "    id: z.number(),\n".to_string());
+
+        assert_eq!(
+            result,
+            r#"// this is comment
+export const TestSchema = z.object({
+    id: z.number(),
+});
+export type Test = z.infer<typeof TestSchema>;"#
+        );
+    }
+    #[test]
+    fn 自己参照する型は宣言した型でスキーマを注釈する() {
+        let composite_type = CompositeTypeStructure::new(
+            "Node",
+            BTreeMap::from([
+                ("children".into(), make_array_type(make_custom_type("Node"))),
+                ("user-name".into(), make_optional_type(make_string_type())),
+            ]),
+        );
+        let sut = ZodDeclarePartGeneratorBuilder::new().build();
+
+        let result = sut.generate_case_composite(
+            &composite_type,
+            "    children: z.array(z.lazy(() => NodeSchema)),\n    \"user-name\": z.string().optional(),\n"
+                .to_string(),
+        );
+
+        assert_eq!(
+            result,
+            r#"export type Node = {
+    children: Node[];
+    "user-name"?: string;
+};
+export const NodeSchema: z.ZodType<Node> = z.object({
+    children: z.array(z.lazy(() => NodeSchema)),
+    "user-name": z.string().optional(),
+});"#
+        );
+    }
+    #[test]
+    fn aliasはスキーマの代入になりimportが付与される() {
+        let mapper = ZodMapper;
+        let sut = ZodDeclarePartGeneratorBuilder::new().build();
+        let alias = AliasTypeStructure::new("TestArray", make_array_type(make_custom_type("Test")));
+
+        let result = sut.generate_case_concat(vec![sut.generate_case_alias(&alias, &mapper)]);
+
+        assert_eq!(
+            result,
+            r#"import { z } from "zod";
+
+export const TestArraySchema = z.array(z.lazy(() => TestSchema));
+export type TestArray = z.infer<typeof TestArraySchema>;
+"#
+        );
+    }
+}
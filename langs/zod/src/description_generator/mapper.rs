@@ -0,0 +1,120 @@
+use description_generator::type_mapper::{TypeMapper, TypeString};
+use structure::parts::type_name::TypeName;
+
+/// custom type refers schema lazily,
+/// so schemas can be declared in any order and recursive type works
+pub struct ZodMapper;
+impl ZodMapper {
+    pub(crate) fn schema_name(type_name: &TypeName) -> String {
+        format!("{}Schema", type_name.valid_lang_str())
+    }
+}
+
+impl TypeMapper for ZodMapper {
+    fn case_string(&self) -> TypeString {
+        "z.string()".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "z.null()".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        format!("z.lazy(() => {})", Self::schema_name(custom_type))
+    }
+    fn case_any(&self) -> TypeString {
+        "z.any()".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "z.boolean()".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("z.array({})", type_statement.into())
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("{}.optional()", type_statement.into())
+    }
+    fn case_float(&self) -> TypeString {
+        "z.number()".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "z.number()".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "z.number()".to_string()
+    }
+}
+
+/// typescript type which schema parses into.
+/// type of recursive schema can not be inferred, so it is declared by this
+pub(crate) struct ZodTypeScriptMapper;
+impl TypeMapper for ZodTypeScriptMapper {
+    fn case_string(&self) -> TypeString {
+        "string".to_string()
+    }
+    fn case_null(&self) -> TypeString {
+        "null".to_string()
+    }
+    fn case_custom_type(&self, custom_type: &TypeName) -> String {
+        custom_type.valid_lang_str()
+    }
+    fn case_any(&self) -> TypeString {
+        "any".to_string()
+    }
+    fn case_boolean(&self) -> TypeString {
+        "boolean".to_string()
+    }
+    fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        let type_statement = type_statement.into();
+        if type_statement.contains(' ') {
+            format!("({})[]", type_statement)
+        } else {
+            format!("{}[]", type_statement)
+        }
+    }
+    fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString {
+        format!("{} | undefined", type_statement.into())
+    }
+    fn case_float(&self) -> TypeString {
+        "number".to_string()
+    }
+    fn case_isize(&self) -> TypeString {
+        "number".to_string()
+    }
+    fn case_usize(&self) -> TypeString {
+        "number".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn カスタム型は遅延評価されるスキーマになる() {
+        let mapper = ZodMapper;
+
+        assert_eq!(
+            mapper.case_property_type(&make_optional_type(make_array_type(make_custom_type(
+                "Test"
+            )))),
+            "z.array(z.lazy(() => TestSchema)).optional()"
+        );
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_string_type()))),
+            "z.array(z.string().optional())"
+        );
+    }
+    #[test]
+    fn スキーマがparseする型はtypescriptの型になる() {
+        let mapper = ZodTypeScriptMapper;
+
+        assert_eq!(
+            mapper.case_property_type(&make_array_type(make_optional_type(make_custom_type(
+                "Test"
+            )))),
+            "(Test | undefined)[]"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use description_generator::{
+    customizable::{
+        property_part_convertors::{
+            AddHeaderConvertor, AddLastSideConvertor, AddLeftSideConvertor,
+        },
+        property_part_generator::{Convertor, CustomizablePropertyDescriptionGenerator},
+    },
+    type_description_generator::PropertyPartGenerator,
+};
+use structure::parts::{
+    property_key::PropertyKey, property_type::PropertyType, type_name::TypeName,
+};
+
+use super::mapper::ZodMapper;
+
+pub struct ZodPropertyPartGenerator {
+    generator: CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, ZodMapper>,
+}
+impl ZodPropertyPartGenerator {
+    const INDENT: &'static str = "    ";
+    const NEXT_LINE: &'static str = ",\n";
+    pub fn new() -> Self {
+        fn zod_property_concat(key: String, type_: String) -> String {
+            format!("{}: {}", key, type_)
+        }
+        Self {
+            generator: CustomizablePropertyDescriptionGenerator::new(zod_property_concat),
+        }
+    }
+    fn add_default_convertors(&mut self) {
+        let mut add_space_convertor = AddLeftSideConvertor::new(Self::INDENT);
+        add_space_convertor.set_all();
+        let mut add_last_side_convertor = AddLastSideConvertor::new(Self::NEXT_LINE);
+        add_last_side_convertor.set_all();
+        self.generator
+            .add_property_key_convertor(Box::new(ZodPropertyKeyConvertor {}));
+        self.generator
+            .add_statement_convertor(Box::new(add_space_convertor));
+        self.generator
+            .add_statement_convertor(Box::new(add_last_side_convertor));
+    }
+}
+impl Default for ZodPropertyPartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PropertyPartGenerator<ZodMapper> for ZodPropertyPartGenerator {
+    fn generate(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &ZodMapper,
+    ) -> String {
+        self.generator
+            .generate(type_name, property_key, property_type, mapper)
+    }
+}
+
+pub struct ZodPropertyPartGeneratorBuilder {
+    generator: ZodPropertyPartGenerator,
+}
+impl ZodPropertyPartGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            generator: ZodPropertyPartGenerator::new(),
+        }
+    }
+    pub fn build(self) -> ZodPropertyPartGenerator {
+        let mut generator = self.generator;
+        generator.add_default_convertors();
+        generator
+    }
+    pub fn all_comment(mut self, comment: impl Into<String>) -> Self {
+        let mut convertor = AddHeaderConvertor::new(format!("// {}", comment.into()));
+        convertor.set_all();
+        self.generator
+            .generator
+            .add_statement_convertor(Box::new(convertor));
+        self
+    }
+    pub fn set_whitelist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_whitelist_with_keys(list);
+        self
+    }
+    pub fn set_blacklist_with_keys(mut self, list: Vec<impl Into<String>>) -> Self {
+        self.generator.generator.set_blacklist_with_keys(list);
+        self
+    }
+}
+impl Default for ZodPropertyPartGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// schema validates original json key, so key is quoted when it is not valid identifier
+pub(crate) struct ZodPropertyKeyConvertor {}
+impl ZodPropertyKeyConvertor {
+    pub(crate) fn to_key(key: &str) -> String {
+        if Self::is_valid_identifier(key) {
+            key.to_string()
+        } else {
+            format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+    }
+    fn is_valid_identifier(key: &str) -> bool {
+        let mut chars = key.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        (first.is_ascii_alphabetic() || first == '_' || first == '$')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    }
+}
+impl Convertor<ZodMapper> for ZodPropertyKeyConvertor {
+    fn convert(
+        &self,
+        acc: &mut String,
+        _: &TypeName,
+        property_key: &PropertyKey,
+        _: &PropertyType,
+        _: &ZodMapper,
+    ) {
+        *acc = Self::to_key(property_key.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use description_generator::type_description_generator::PropertyPartGenerator;
+    use structure::parts::{
+        property_type::property_type_factories::{make_optional_type, make_string_type},
+        type_name::TypeName,
+    };
+
+    use crate::description_generator::{
+        mapper::ZodMapper, property_part_generator::ZodPropertyPartGeneratorBuilder,
+    };
+
+    #[test]
+    fn 不正な識別子のキーは引用される() {
+        let type_name: TypeName = "Test".into();
+        let mapper = ZodMapper;
+        let generator = ZodPropertyPartGeneratorBuilder::new()
+            .all_comment("this is comment")
+            .build();
+
+        let name = generator.generate(
+            &type_name,
+            &"user-name".into(),
+            &make_optional_type(make_string_type()),
+            &mapper,
+        );
+        let id = generator.generate(&type_name, &"id".into(), &make_string_type(), &mapper);
+
+        assert_eq!(
+            name,
+            "    // this is comment\n    \"user-name\": z.string().optional(),\n"
+        );
+        assert_eq!(id, "    // this is comment\n    id: z.string(),\n");
+    }
+}
@@ -0,0 +1,157 @@
+use description_generator::type_description_generator::TypeDescriptionGenerator;
+
+use crate::description_generator::{
+    declare_part_generator::{ZodDeclarePartGenerator, ZodDeclarePartGeneratorBuilder},
+    mapper::ZodMapper,
+    property_part_generator::{ZodPropertyPartGenerator, ZodPropertyPartGeneratorBuilder},
+};
+
+pub struct ZodTypeDescriptionGeneratorBuilder {
+    pub declare_part: ZodDeclarePartGeneratorBuilder,
+    pub property_part: ZodPropertyPartGeneratorBuilder,
+}
+macro_rules! impl_property_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl ZodTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<property_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.property_part =  self.property_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_declare_part_methods {
+    ($({$method:ident, $(($key:ident, $type_:ty)),*}),*) => {
+        $(
+            impl ZodTypeDescriptionGeneratorBuilder {
+                paste::item! {
+                    pub fn [<declare_part_ $method>](mut self,$($key: $type_),*)-> Self {
+                        self.declare_part =  self.declare_part.$method($($key),*);
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_property_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist_with_keys,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist_with_keys,(list,Vec<impl Into<String>>)}
+);
+impl_declare_part_methods!(
+    {all_comment,(comment,impl Into<String>)}
+    ,{set_whitelist,(list,Vec<impl Into<String>>)}
+    ,{set_blacklist,(list,Vec<impl Into<String>>)}
+);
+impl ZodTypeDescriptionGeneratorBuilder {
+    pub fn new() -> Self {
+        Self {
+            declare_part: ZodDeclarePartGeneratorBuilder::new(),
+            property_part: ZodPropertyPartGeneratorBuilder::new(),
+        }
+    }
+    pub fn build(
+        self,
+    ) -> TypeDescriptionGenerator<ZodDeclarePartGenerator, ZodPropertyPartGenerator, ZodMapper>
+    {
+        let (d, p) = (self.declare_part.build(), self.property_part.build());
+        TypeDescriptionGenerator::new(d, p, ZodMapper)
+    }
+}
+impl Default for ZodTypeDescriptionGeneratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structure::{
+        parts::property_type::property_type_factories::{
+            make_any, make_array_type, make_custom_type, make_optional_type, make_string_type,
+            make_usize_type,
+        },
+        type_structure::TypeStructure,
+    };
+    #[test]
+    fn integration_test() {
+        let root = TypeStructure::make_composite(
+            "Root",
+            vec![
+                ("id", make_usize_type()),
+                ("data", make_array_type(make_custom_type("RootData"))),
+                ("user-name", make_optional_type(make_string_type())),
+            ],
+        );
+        let root_data = TypeStructure::make_composite("RootData", vec![("value", make_any())]);
+        let generator = ZodTypeDescriptionGeneratorBuilder::new().build();
+
+        let result = generator.generate_concat_define(vec![root, root_data]);
+
+        assert_eq!(
+            result,
+            r#"import { z } from "zod";
+
+export const RootSchema = z.object({
+    data: z.array(z.lazy(() => RootDataSchema)),
+    id: z.number(),
+    "user-name": z.string().optional(),
+});
+export type Root = z.infer<typeof RootSchema>;
+
+export const RootDataSchema = z.object({
+    value: z.any(),
+});
+export type RootData = z.infer<typeof RootDataSchema>;
+"#
+        );
+    }
+    #[test]
+    fn 相互参照する型は全て宣言した型でスキーマを注釈する() {
+        let user = TypeStructure::make_composite(
+            "User",
+            vec![
+                ("id", make_usize_type()),
+                ("posts", make_array_type(make_custom_type("Post"))),
+                ("secret", make_string_type()),
+            ],
+        );
+        let post = TypeStructure::make_composite(
+            "Post",
+            vec![("author", make_optional_type(make_custom_type("User")))],
+        );
+        let generator = ZodTypeDescriptionGeneratorBuilder::new()
+            .property_part_set_blacklist_with_keys(vec!["secret"])
+            .build();
+
+        let result = generator.generate_concat_define(vec![user, post]);
+
+        assert_eq!(
+            result,
+            r#"import { z } from "zod";
+
+export type User = {
+    id: number;
+    posts: Post[];
+};
+export const UserSchema: z.ZodType<User> = z.object({
+    id: z.number(),
+    posts: z.array(z.lazy(() => PostSchema)),
+});
+
+export type Post = {
+    author?: User;
+};
+export const PostSchema: z.ZodType<Post> = z.object({
+    author: z.lazy(() => UserSchema).optional(),
+});
+"#
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::{fs::File, io::Read, path::Path};
+
+/// csv or tsv file, header row is keys and type of each column is inferred across all rows
+#[derive(Debug, PartialEq, Clone)]
+pub struct Csv {
+    columns: Vec<Column>,
+}
+impl Csv {
+    pub const COMMA: u8 = b',';
+    pub const TAB: u8 = b'\t';
+    pub fn from_file(path: impl AsRef<Path>, delimiter: u8) -> Self {
+        let file = File::open(path).unwrap();
+        Csv::from_reader(file, delimiter)
+    }
+    /// rows are read one by one, so whole file is never held at once
+    pub fn from_reader(reader: impl Read, delimiter: u8) -> Self {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_reader(reader);
+        let mut columns = reader
+            .headers()
+            .unwrap()
+            .iter()
+            .map(Column::new)
+            .collect::<Vec<_>>();
+        for record in reader.records() {
+            let record = record.unwrap();
+            for (i, column) in columns.iter_mut().enumerate() {
+                // cell of short row is empty
+                column.put(record.get(i).unwrap_or_default());
+            }
+        }
+        Self { columns }
+    }
+    pub(crate) fn columns(self) -> Vec<Column> {
+        self.columns
+    }
+}
+impl From<&str> for Csv {
+    fn from(source: &str) -> Self {
+        Csv::from_reader(source.as_bytes(), Self::COMMA)
+    }
+}
+impl From<String> for Csv {
+    fn from(source: String) -> Self {
+        Csv::from(source.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Column {
+    key: String,
+    // None if all cells are empty
+    cell_type: Option<CellType>,
+    has_empty: bool,
+}
+impl Column {
+    fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            cell_type: None,
+            has_empty: false,
+        }
+    }
+    fn put(&mut self, cell: &str) {
+        if cell.is_empty() {
+            self.has_empty = true;
+            return;
+        }
+        let cell_type = CellType::from_cell(cell);
+        self.cell_type = Some(match self.cell_type {
+            Some(current) => current.merge(cell_type),
+            None => cell_type,
+        });
+    }
+    pub(crate) fn key(&self) -> &str {
+        &self.key
+    }
+    pub(crate) fn cell_type(&self) -> Option<CellType> {
+        self.cell_type
+    }
+    pub(crate) fn has_empty(&self) -> bool {
+        self.has_empty
+    }
+}
+
+/// cell is parsed same as csv::Deserializer does
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum CellType {
+    Boolean,
+    Usize,
+    Isize,
+    Float,
+    String,
+}
+impl CellType {
+    fn from_cell(cell: &str) -> Self {
+        if cell.parse::<bool>().is_ok() {
+            return Self::Boolean;
+        }
+        if cell.parse::<u64>().is_ok() {
+            return Self::Usize;
+        }
+        if cell.parse::<i64>().is_ok() {
+            return Self::Isize;
+        }
+        if cell.parse::<f64>().is_ok() {
+            return Self::Float;
+        }
+        Self::String
+    }
+    /// numbers are widened, and different kinds of cell become string
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (this, other) if this == other => this,
+            (Self::Usize, Self::Isize) | (Self::Isize, Self::Usize) => Self::Isize,
+            (Self::Usize | Self::Isize | Self::Float, Self::Usize | Self::Isize | Self::Float) => {
+                Self::Float
+            }
+            _ => Self::String,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn 列の型は全ての行から推測される() {
+        let sut = Csv::from("id,score,name,active,memo\n1,-1,kai,true,\n2,0.5,3,false,\n");
+
+        assert_eq!(
+            sut.columns()
+                .iter()
+                .map(|column| (column.key(), column.cell_type(), column.has_empty()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("id", Some(CellType::Usize), false),
+                ("score", Some(CellType::Float), false),
+                ("name", Some(CellType::String), false),
+                ("active", Some(CellType::Boolean), false),
+                ("memo", None, true),
+            ]
+        );
+    }
+}
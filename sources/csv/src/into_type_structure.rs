@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    parts::{
+        property_key::PropertyKey,
+        property_type::{
+            property_type_factories::{
+                make_any, make_bool_type, make_float_type, make_isize_type, make_optional_type,
+                make_string_type, make_usize_type,
+            },
+            PropertyType,
+        },
+        type_name::TypeName,
+    },
+    type_structure::TypeStructure,
+};
+
+use crate::csv::{CellType, Column, Csv};
+
+// into type structures impl
+impl Csv {
+    /// one row type per file
+    pub fn into_type_structures(self, root_name: impl Into<TypeName>) -> Vec<TypeStructure> {
+        let root_name = root_name.into();
+        let properties = self
+            .columns()
+            .iter()
+            .map(|column| (PropertyKey::from(column.key()), Self::column_type(column)))
+            .collect::<BTreeMap<_, _>>();
+        if properties.is_empty() {
+            return vec![TypeStructure::Alias(AliasTypeStructure::new(
+                root_name,
+                PropertyType::Any,
+            ))];
+        }
+        vec![TypeStructure::Composite(CompositeTypeStructure::new(
+            root_name, properties,
+        ))]
+    }
+    fn column_type(column: &Column) -> PropertyType {
+        let property_type = match column.cell_type() {
+            Some(CellType::Boolean) => make_bool_type(),
+            Some(CellType::Usize) => make_usize_type(),
+            Some(CellType::Isize) => make_isize_type(),
+            Some(CellType::Float) => make_float_type(),
+            Some(CellType::String) => make_string_type(),
+            None => make_any(),
+        };
+        if column.has_empty() {
+            return make_optional_type(property_type);
+        }
+        property_type
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use super::*;
+    #[test]
+    fn 空のセルがある列は省略可能になる() {
+        let sut = Csv::from_reader(
+            "user_id\tnickname\tage\n1\tkai\t\n2\t\t-3\n".as_bytes(),
+            Csv::TAB,
+        );
+
+        assert_eq!(
+            sut.into_type_structures("Users"),
+            vec![TypeStructure::make_composite(
+                "Users",
+                vec![
+                    ("age", make_optional_type(make_isize_type())),
+                    ("nickname", make_optional_type(make_string_type())),
+                    ("user_id", make_usize_type()),
+                ]
+            )]
+        );
+    }
+}
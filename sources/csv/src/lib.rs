@@ -0,0 +1,2 @@
+pub mod csv;
+pub mod into_type_structure;
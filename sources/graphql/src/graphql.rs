@@ -0,0 +1,307 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use graphql_parser::schema::{self, Document, TypeDefinition, TypeExtension};
+use serde_json::Value;
+
+/// GraphQL schema written in SDL, or result of introspection query
+#[derive(Debug, PartialEq, Clone)]
+pub struct Graphql {
+    definitions: Vec<Definition>,
+}
+impl Graphql {
+    pub const BUILTIN_SCALARS: [&'static str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Graphql::from(result.as_str())
+    }
+    pub(crate) fn definitions(self) -> Vec<Definition> {
+        self.definitions
+    }
+    fn from_sdl(source: &str) -> Self {
+        let document: Document<String> = schema::parse_schema(source).unwrap();
+        let mut result = Self {
+            definitions: vec![],
+        };
+        for definition in document.definitions {
+            match definition {
+                schema::Definition::TypeDefinition(definition) => result.put(definition),
+                schema::Definition::TypeExtension(extension) => result.extend(extension),
+                // schema and directive definitions are not types
+                _ => {}
+            }
+        }
+        result
+    }
+    fn put(&mut self, definition: TypeDefinition<String>) {
+        let definition = match definition {
+            TypeDefinition::Scalar(scalar) => Definition::Scalar { name: scalar.name },
+            TypeDefinition::Object(object) => Definition::Object {
+                name: object.name,
+                fields: Field::from_fields(object.fields),
+            },
+            TypeDefinition::Interface(interface) => Definition::Object {
+                name: interface.name,
+                fields: Field::from_fields(interface.fields),
+            },
+            TypeDefinition::InputObject(input) => Definition::Object {
+                name: input.name,
+                fields: Field::from_input_values(input.fields),
+            },
+            TypeDefinition::Union(union) => Definition::Union {
+                name: union.name,
+                members: union.types,
+            },
+            TypeDefinition::Enum(enum_type) => Definition::Enum {
+                name: enum_type.name,
+                values: enum_type.values.into_iter().map(|v| v.name).collect(),
+            },
+        };
+        self.definitions.push(definition);
+    }
+    /// fields, members and values of extension are added to extended definition
+    fn extend(&mut self, extension: TypeExtension<String>) {
+        let (extended, added_fields, added_members) = match extension {
+            TypeExtension::Object(object) => {
+                (object.name, Field::from_fields(object.fields), vec![])
+            }
+            TypeExtension::Interface(interface) => {
+                (interface.name, Field::from_fields(interface.fields), vec![])
+            }
+            TypeExtension::InputObject(input) => {
+                (input.name, Field::from_input_values(input.fields), vec![])
+            }
+            TypeExtension::Union(union) => (union.name, vec![], union.types),
+            TypeExtension::Enum(enum_type) => (
+                enum_type.name,
+                vec![],
+                enum_type.values.into_iter().map(|v| v.name).collect(),
+            ),
+            TypeExtension::Scalar(_) => return,
+        };
+        for definition in self.definitions.iter_mut() {
+            match definition {
+                Definition::Object { name, fields } if name == &extended => {
+                    fields.extend(added_fields);
+                    return;
+                }
+                Definition::Union { name, members } if name == &extended => {
+                    members.extend(added_members);
+                    return;
+                }
+                Definition::Enum { name, values } if name == &extended => {
+                    values.extend(added_members);
+                    return;
+                }
+                _ => {}
+            }
+        }
+    }
+    /// `data` of response or its content are accepted
+    fn from_introspection(root: &Value) -> Self {
+        let schema = root
+            .pointer("/data/__schema")
+            .or_else(|| root.get("__schema"))
+            .unwrap();
+        let definitions = schema["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Definition::from_introspection)
+            .collect();
+        Self { definitions }
+    }
+}
+impl From<&str> for Graphql {
+    fn from(source: &str) -> Self {
+        match serde_json::from_str::<Value>(source) {
+            Ok(root) => Self::from_introspection(&root),
+            Err(_) => Self::from_sdl(source),
+        }
+    }
+}
+impl From<String> for Graphql {
+    fn from(source: String) -> Self {
+        Graphql::from(source.as_str())
+    }
+}
+
+/// named types of schema, interface and input object are same as object
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Definition {
+    Object { name: String, fields: Vec<Field> },
+    Union { name: String, members: Vec<String> },
+    Enum { name: String, values: Vec<String> },
+    Scalar { name: String },
+}
+impl Definition {
+    /// builtin scalars and introspection types (`__Type` etc) are not declared
+    fn from_introspection(full_type: &Value) -> Option<Self> {
+        let name = full_type["name"].as_str()?;
+        if name.starts_with("__") {
+            return None;
+        }
+        let name = name.to_string();
+        let fields = |key: &str| {
+            full_type[key]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Field::from_introspection)
+                .collect()
+        };
+        match full_type["kind"].as_str()? {
+            "OBJECT" | "INTERFACE" => Some(Self::Object {
+                name,
+                fields: fields("fields"),
+            }),
+            "INPUT_OBJECT" => Some(Self::Object {
+                name,
+                fields: fields("inputFields"),
+            }),
+            "UNION" => Some(Self::Union {
+                name,
+                members: full_type["possibleTypes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|member| member["name"].as_str())
+                    .map(str::to_string)
+                    .collect(),
+            }),
+            "ENUM" => Some(Self::Enum {
+                name,
+                values: full_type["enumValues"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|value| value["name"].as_str())
+                    .map(str::to_string)
+                    .collect(),
+            }),
+            "SCALAR" if !Graphql::BUILTIN_SCALARS.contains(&name.as_str()) => {
+                Some(Self::Scalar { name })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// arguments of field are ignored
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Field {
+    pub(crate) name: String,
+    pub(crate) field_type: FieldType,
+}
+impl Field {
+    fn from_fields(fields: Vec<schema::Field<String>>) -> Vec<Self> {
+        fields
+            .into_iter()
+            .map(|field| Self {
+                name: field.name,
+                field_type: FieldType::from(field.field_type),
+            })
+            .collect()
+    }
+    fn from_input_values(values: Vec<schema::InputValue<String>>) -> Vec<Self> {
+        values
+            .into_iter()
+            .map(|value| Self {
+                name: value.name,
+                field_type: FieldType::from(value.value_type),
+            })
+            .collect()
+    }
+    fn from_introspection(field: &Value) -> Option<Self> {
+        Some(Self {
+            name: field["name"].as_str()?.to_string(),
+            field_type: FieldType::from_introspection(&field["type"])?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum FieldType {
+    Named(String),
+    List(Box<FieldType>),
+    NonNull(Box<FieldType>),
+}
+impl FieldType {
+    fn from_introspection(type_ref: &Value) -> Option<Self> {
+        match type_ref["kind"].as_str()? {
+            "NON_NULL" => Some(Self::NonNull(Box::new(Self::from_introspection(
+                &type_ref["ofType"],
+            )?))),
+            "LIST" => Some(Self::List(Box::new(Self::from_introspection(
+                &type_ref["ofType"],
+            )?))),
+            _ => Some(Self::Named(type_ref["name"].as_str()?.to_string())),
+        }
+    }
+}
+impl From<schema::Type<'_, String>> for FieldType {
+    fn from(field_type: schema::Type<String>) -> Self {
+        match field_type {
+            schema::Type::NamedType(name) => Self::Named(name),
+            schema::Type::ListType(inner) => Self::List(Box::new(Self::from(*inner))),
+            schema::Type::NonNullType(inner) => Self::NonNull(Box::new(Self::from(*inner))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn sdlとintrospectionは同じ定義になる() {
+        let sdl = Graphql::from(
+            r#"
+            type User { id: ID! tags: [String] }
+            extend type User { age: Int }
+            "#,
+        );
+        let introspection = Graphql::from(
+            r#"{"data": {"__schema": {"types": [
+                {"kind": "OBJECT", "name": "User", "fields": [
+                    {"name": "id", "args": [], "type": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "SCALAR", "name": "ID", "ofType": null}}},
+                    {"name": "tags", "args": [], "type": {"kind": "LIST", "name": null, "ofType": {"kind": "SCALAR", "name": "String", "ofType": null}}},
+                    {"name": "age", "args": [], "type": {"kind": "SCALAR", "name": "Int", "ofType": null}}
+                ]},
+                {"kind": "SCALAR", "name": "String"},
+                {"kind": "OBJECT", "name": "__Type", "fields": []}
+            ]}}}"#,
+        );
+
+        assert_eq!(sdl, introspection);
+        assert_eq!(
+            sdl.definitions(),
+            vec![Definition::Object {
+                name: "User".to_string(),
+                fields: vec![
+                    Field {
+                        name: "id".to_string(),
+                        field_type: FieldType::NonNull(Box::new(FieldType::Named(
+                            "ID".to_string()
+                        ))),
+                    },
+                    Field {
+                        name: "tags".to_string(),
+                        field_type: FieldType::List(Box::new(FieldType::Named(
+                            "String".to_string()
+                        ))),
+                    },
+                    Field {
+                        name: "age".to_string(),
+                        field_type: FieldType::Named("Int".to_string()),
+                    },
+                ],
+            }]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use structure::{
+    alias_type_structure::AliasTypeStructure,
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{
+        property_key::PropertyKey,
+        property_type::{
+            property_type_factories::{
+                make_any, make_array_type, make_bool_type, make_custom_type, make_float_type,
+                make_isize_type, make_optional_type, make_string_type,
+            },
+            PropertyType,
+        },
+    },
+    type_structure::TypeStructure,
+};
+
+use crate::graphql::{Definition, Field, FieldType, Graphql};
+
+// into type structures impl
+impl Graphql {
+    /// types are named by schema, so root name is not needed
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        self.definitions()
+            .iter()
+            .map(Self::definition_to_type_structure)
+            .collect()
+    }
+    fn definition_to_type_structure(definition: &Definition) -> TypeStructure {
+        match definition {
+            Definition::Object { name, fields } => TypeStructure::Composite(
+                CompositeTypeStructure::new(name.as_str(), Self::fields_to_properties(fields)),
+            ),
+            // __typename is sent only if it is queried, so member is decided by its fields
+            Definition::Union { name, members } => TypeStructure::Enum(EnumTypeStructure::new(
+                name.as_str(),
+                EnumRepresentation::Untagged(
+                    members
+                        .iter()
+                        .map(|member| make_custom_type(member.as_str()))
+                        .collect(),
+                ),
+            )),
+            // value of enum is sent as its name
+            Definition::Enum { name, values } => TypeStructure::Enum(EnumTypeStructure::new(
+                name.as_str(),
+                EnumRepresentation::Literal(values.clone()),
+            )),
+            // serialization of custom scalar is up to server
+            Definition::Scalar { name } => {
+                TypeStructure::Alias(AliasTypeStructure::new(name.as_str(), make_any()))
+            }
+        }
+    }
+    fn fields_to_properties(fields: &[Field]) -> BTreeMap<PropertyKey, PropertyType> {
+        fields
+            .iter()
+            .map(|field| {
+                (
+                    PropertyKey::from(field.name.as_str()),
+                    Self::property_type(&field.field_type),
+                )
+            })
+            .collect()
+    }
+    /// nullable type is optional unless it is wrapped by non null
+    fn property_type(field_type: &FieldType) -> PropertyType {
+        match field_type {
+            FieldType::NonNull(inner) => Self::non_null_type(inner),
+            field_type => make_optional_type(Self::non_null_type(field_type)),
+        }
+    }
+    fn non_null_type(field_type: &FieldType) -> PropertyType {
+        match field_type {
+            FieldType::NonNull(inner) => Self::non_null_type(inner),
+            FieldType::List(inner) => make_array_type(Self::property_type(inner)),
+            FieldType::Named(name) => match name.as_str() {
+                "Int" => make_isize_type(),
+                "Float" => make_float_type(),
+                "String" | "ID" => make_string_type(),
+                "Boolean" => make_bool_type(),
+                name => make_custom_type(name),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use super::*;
+    #[test]
+    fn non_nullは必須になりリストは配列になる() {
+        let sut = Graphql::from(
+            r#"
+            scalar DateTime
+            enum Role { ADMIN GUEST }
+            extend enum Role { BOT }
+            type User { id: ID! name: String roles: [Role!]! createdAt: DateTime! }
+            type Bot { id: ID! owner: User }
+            union Actor = User | Bot
+            "#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![
+                TypeStructure::make_alias("DateTime", make_any()),
+                TypeStructure::make_literal_enum("Role", vec!["ADMIN", "GUEST", "BOT"]),
+                TypeStructure::make_composite(
+                    "User",
+                    vec![
+                        ("id", make_string_type()),
+                        ("name", make_optional_type(make_string_type())),
+                        ("roles", make_array_type(make_custom_type("Role"))),
+                        ("createdAt", make_custom_type("DateTime")),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "Bot",
+                    vec![
+                        ("id", make_string_type()),
+                        ("owner", make_optional_type(make_custom_type("User"))),
+                    ]
+                ),
+                TypeStructure::make_untagged_enum(
+                    "Actor",
+                    vec![make_custom_type("User"), make_custom_type("Bot")]
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod graphql;
+pub mod into_type_structure;
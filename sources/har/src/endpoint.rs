@@ -0,0 +1,133 @@
+use json::json::Json;
+use serde_json::Value;
+
+/// recorded request and response bodies, grouped by method and path template
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Endpoints {
+    endpoints: Vec<Endpoint>,
+}
+impl Endpoints {
+    pub(crate) fn endpoints(self) -> Vec<Endpoint> {
+        self.endpoints
+    }
+    /// body which is not json (for example html or empty body) is ignored
+    pub(crate) fn put(
+        &mut self,
+        method: &str,
+        path: &str,
+        request_body: Option<&str>,
+        response_body: Option<&str>,
+    ) {
+        let method = method.to_uppercase();
+        let path = Self::path_template(path);
+        let index = match self
+            .endpoints
+            .iter()
+            .position(|endpoint| endpoint.method == method && endpoint.path == path)
+        {
+            Some(index) => index,
+            None => {
+                self.endpoints.push(Endpoint {
+                    method,
+                    path,
+                    requests: vec![],
+                    responses: vec![],
+                });
+                self.endpoints.len() - 1
+            }
+        };
+        let endpoint = &mut self.endpoints[index];
+        endpoint
+            .requests
+            .extend(request_body.and_then(Self::parse_body));
+        endpoint
+            .responses
+            .extend(response_body.and_then(Self::parse_body));
+    }
+    fn parse_body(body: &str) -> Option<Json> {
+        serde_json::from_str::<Value>(body).ok().map(Json::from)
+    }
+    /// path of url without host and query. segment of id (number or uuid) is `{id}`,
+    /// and path variable (`:name`, `{name}` or `{{name}}`) is `{name}`.
+    /// host may be variable of postman (`{{baseUrl}}`)
+    pub(crate) fn path_template(url: &str) -> String {
+        let url = url.split(['?', '#']).next().unwrap_or_default();
+        let path = match (url.split_once("://"), url.strip_prefix("{{")) {
+            (Some((_, rest)), _) => rest.find('/').map_or("", |index| &rest[index..]),
+            (None, Some(rest)) => rest.split_once("}}").map_or("", |(_, path)| path),
+            (None, None) => url,
+        };
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(Self::segment_template)
+            .fold(String::new(), |acc, segment| format!("{}/{}", acc, segment))
+    }
+    fn segment_template(segment: &str) -> String {
+        let variable = segment
+            .strip_prefix(':')
+            .or_else(|| {
+                segment
+                    .strip_prefix("{{")
+                    .and_then(|s| s.strip_suffix("}}"))
+            })
+            .or_else(|| segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')));
+        if let Some(variable) = variable {
+            return format!("{{{}}}", variable);
+        }
+        if Self::is_id(segment) {
+            return "{id}".to_string();
+        }
+        segment.to_string()
+    }
+    fn is_id(segment: &str) -> bool {
+        let is_number = segment.chars().all(|c| c.is_ascii_digit());
+        let is_uuid =
+            segment.len() == 36 && segment.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
+        is_number || is_uuid
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Endpoint {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) requests: Vec<Json>,
+    pub(crate) responses: Vec<Json>,
+}
+impl Endpoint {
+    /// method and path, for example `GET /users/{id}` is `get_users_id`
+    pub(crate) fn name(&self) -> String {
+        self.path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                segment
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect::<String>()
+            })
+            .fold(self.method.to_lowercase(), |acc, segment| {
+                format!("{}_{}", acc, segment.trim_matches('_'))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn idと変数はパスのテンプレートになる() {
+        assert_eq!(
+            Endpoints::path_template("https://api.example.com/users/42/posts?page=2"),
+            "/users/{id}/posts"
+        );
+        assert_eq!(
+            Endpoints::path_template("{{baseUrl}}/users/:userId"),
+            "/users/{userId}"
+        );
+        assert_eq!(
+            Endpoints::path_template("/orders/3f2c1d4e-5b6a-4c7d-8e9f-0a1b2c3d4e5f"),
+            "/orders/{id}"
+        );
+    }
+}
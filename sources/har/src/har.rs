@@ -0,0 +1,57 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use serde_json::Value;
+
+use crate::endpoint::Endpoints;
+
+/// HAR (HTTP Archive) which is exported from browser
+#[derive(Debug, PartialEq, Clone)]
+pub struct Har {
+    endpoints: Endpoints,
+}
+impl Har {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Har::from(result.as_str())
+    }
+    pub(crate) fn endpoints(self) -> Endpoints {
+        self.endpoints
+    }
+    /// error responses have other shape, so only body of successful response is used.
+    /// base64 encoded content is binary, so it is ignored
+    fn response_body(response: &Value) -> Option<&str> {
+        let status = response["status"].as_u64().unwrap_or_default();
+        if !(200..300).contains(&status) || response["content"]["encoding"] == "base64" {
+            return None;
+        }
+        response["content"]["text"].as_str()
+    }
+}
+impl From<&str> for Har {
+    fn from(source: &str) -> Self {
+        let har: Value = serde_json::from_str(source).unwrap();
+        let mut endpoints = Endpoints::default();
+        for entry in har["log"]["entries"].as_array().into_iter().flatten() {
+            let request = &entry["request"];
+            endpoints.put(
+                request["method"].as_str().unwrap_or_default(),
+                request["url"].as_str().unwrap_or_default(),
+                request["postData"]["text"].as_str(),
+                Self::response_body(&entry["response"]),
+            );
+        }
+        Self { endpoints }
+    }
+}
+impl From<String> for Har {
+    fn from(source: String) -> Self {
+        Har::from(source.as_str())
+    }
+}
@@ -0,0 +1,139 @@
+use json::json::Json;
+use structure::type_structure::TypeStructure;
+
+use crate::{endpoint::Endpoints, har::Har, postman::Postman};
+
+// into type structures impl
+impl Endpoints {
+    /// types are named by endpoint, for example `GetUsersIdResponse`.
+    /// all bodies of an endpoint are put together into one type
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        let mut result = Vec::new();
+        for endpoint in self.endpoints() {
+            let name = endpoint.name();
+            for (bodies, suffix) in [
+                (endpoint.requests, "request"),
+                (endpoint.responses, "response"),
+            ] {
+                if bodies.is_empty() {
+                    continue;
+                }
+                result.append(
+                    &mut Self::put_together_bodies(bodies)
+                        .into_type_structures(format!("{}_{}", name, suffix)),
+                );
+            }
+        }
+        result
+    }
+    /// elements of array bodies are put together as one array,
+    /// so empty array body does not hide element type of the others
+    fn put_together_bodies(bodies: Vec<Json>) -> Json {
+        if !bodies.iter().all(|body| matches!(body, Json::Array(_))) {
+            return Json::put_together_array_json(bodies);
+        }
+        Json::Array(
+            bodies
+                .into_iter()
+                .flat_map(|body| match body {
+                    Json::Array(elements) => elements,
+                    _ => Vec::new(),
+                })
+                .collect(),
+        )
+    }
+}
+impl Har {
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        self.endpoints().into_type_structures()
+    }
+}
+impl Postman {
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        self.endpoints().into_type_structures()
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 同じエンドポイントのレスポンスは一つの型にまとめられる() {
+        let sut = Har::from(
+            r#"{"log": {"entries": [
+                {"request": {"method": "GET", "url": "https://api.example.com/users/1"},
+                 "response": {"status": 200, "content": {"mimeType": "application/json", "text": "{\"id\": 1, \"tags\": [\"dev\"]}"}}},
+                {"request": {"method": "GET", "url": "https://api.example.com/users/2?expand=true"},
+                 "response": {"status": 200, "content": {"mimeType": "application/json", "text": "{\"id\": 2, \"tags\": [\"admin\"]}"}}},
+                {"request": {"method": "GET", "url": "https://api.example.com/users/3"},
+                 "response": {"status": 404, "content": {"mimeType": "application/json", "text": "{\"error\": \"not found\"}"}}},
+                {"request": {"method": "POST", "url": "https://api.example.com/users", "postData": {"mimeType": "application/json", "text": "{\"name\": \"kai\"}"}},
+                 "response": {"status": 201, "content": {"mimeType": "text/html", "text": "<p>created</p>"}}}
+            ]}}"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![
+                TypeStructure::make_composite(
+                    "GetUsersIdResponse",
+                    vec![
+                        ("id", make_usize_type()),
+                        ("tags", make_array_type(make_string_type())),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "PostUsersRequest",
+                    vec![("name", make_string_type())]
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn 空の配列のレスポンスは他のレスポンスの要素の型に合わせられる() {
+        let sut = Har::from(
+            r#"{"log": {"entries": [
+                {"request": {"method": "GET", "url": "https://api.example.com/api/v1/users"},
+                 "response": {"status": 200, "content": {"mimeType": "application/json", "text": "[]"}}},
+                {"request": {"method": "GET", "url": "https://api.example.com/api/v1/users"},
+                 "response": {"status": 200, "content": {"mimeType": "application/json", "text": "[{\"id\": 1}]"}}}
+            ]}}"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![
+                TypeStructure::make_alias(
+                    "GetApiV1UsersResponseArray",
+                    make_array_type(make_custom_type("GetApiV1UsersResponse"))
+                ),
+                TypeStructure::make_composite(
+                    "GetApiV1UsersResponse",
+                    vec![("id", make_usize_type())]
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn postmanのフォルダの中のリクエストも読まれる() {
+        let sut = Postman::from(
+            r#"{"info": {"name": "api"}, "item": [{"name": "users", "item": [
+                {"name": "get user",
+                 "request": {"method": "GET", "url": {"raw": "{{baseUrl}}/users/:id", "path": ["users", ":id"]}},
+                 "response": [{"name": "ok", "code": 200, "body": "{\"id\": 1}"}]}
+            ]}]}"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![TypeStructure::make_composite(
+                "GetUsersIdResponse",
+                vec![("id", make_usize_type())]
+            )]
+        );
+    }
+}
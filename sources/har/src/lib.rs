@@ -0,0 +1,4 @@
+pub mod endpoint;
+pub mod har;
+pub mod into_type_structure;
+pub mod postman;
@@ -0,0 +1,67 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use serde_json::Value;
+
+use crate::endpoint::Endpoints;
+
+/// Postman collection (v2), saved responses of each request are bodies of response
+#[derive(Debug, PartialEq, Clone)]
+pub struct Postman {
+    endpoints: Endpoints,
+}
+impl Postman {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Postman::from(result.as_str())
+    }
+    pub(crate) fn endpoints(self) -> Endpoints {
+        self.endpoints
+    }
+    /// item is folder if it has items
+    fn put_items(endpoints: &mut Endpoints, items: &Value) {
+        for item in items.as_array().into_iter().flatten() {
+            if item.get("item").is_some() {
+                Self::put_items(endpoints, &item["item"]);
+                continue;
+            }
+            let request = &item["request"];
+            let method = request["method"].as_str().unwrap_or("GET");
+            let url = Self::url(&request["url"]);
+            endpoints.put(method, &url, request["body"]["raw"].as_str(), None);
+            // error responses have other shape, so only successful response is used
+            for response in item["response"].as_array().into_iter().flatten() {
+                let code = response["code"].as_u64().unwrap_or(200);
+                if (200..300).contains(&code) {
+                    endpoints.put(method, &url, None, response["body"].as_str());
+                }
+            }
+        }
+    }
+    /// url is string or object which has raw url
+    fn url(url: &Value) -> String {
+        match url {
+            Value::String(raw) => raw.clone(),
+            url => url["raw"].as_str().unwrap_or_default().to_string(),
+        }
+    }
+}
+impl From<&str> for Postman {
+    fn from(source: &str) -> Self {
+        let collection: Value = serde_json::from_str(source).unwrap();
+        let mut endpoints = Endpoints::default();
+        Self::put_items(&mut endpoints, &collection["item"]);
+        Self { endpoints }
+    }
+}
+impl From<String> for Postman {
+    fn from(source: String) -> Self {
+        Postman::from(source.as_str())
+    }
+}
@@ -0,0 +1,776 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use structure::{
+    composite_type_structure::CompositeTypeStructure,
+    parts::{
+        property_key::PropertyKey,
+        property_type::{
+            property_type_factories::{
+                make_any, make_array_type, make_bool_type, make_custom_type, make_datetime_type,
+                make_float_type, make_isize_type, make_optional_type, make_string_type,
+                make_usize_type,
+            },
+            PropertyType,
+        },
+        type_name::TypeName,
+    },
+    type_structure::TypeStructure,
+};
+
+use crate::json_schema::JsonSchema;
+
+/// key to (schema, required) of object schema
+type Properties<'a> = BTreeMap<&'a str, (&'a Value, bool)>;
+
+// into type structures impl
+impl JsonSchema {
+    pub fn into_type_structures(self, root_name: impl Into<TypeName>) -> Vec<TypeStructure> {
+        let root_name = root_name.into();
+        let mut result = Vec::new();
+        if !self.is_only_definitions() {
+            result.append(&mut self.declare(&root_name, &root_name, self.root()));
+        }
+        for (type_name, schema) in self.definitions() {
+            result.append(&mut self.declare(&root_name, &type_name, schema));
+        }
+        result
+    }
+    /// declare schema which is a part of this document, so it can refer other parts of document
+    pub fn schema_to_type_structures(
+        &self,
+        type_name: impl Into<TypeName>,
+        schema: &Value,
+    ) -> Vec<TypeStructure> {
+        let type_name = type_name.into();
+        self.declare(&type_name, &type_name, schema)
+    }
+    fn declare(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+    ) -> Vec<TypeStructure> {
+        if let Some(properties) = self.object_properties(schema) {
+            return self.declare_composite(root_name, type_name, properties);
+        }
+        if !Self::is_nullable(schema) {
+            if let Some(types) = self.declare_enum(root_name, type_name, schema) {
+                return types;
+            }
+        }
+        // Test = [TestItem]
+        let item_name = TypeName::new(format!("{}Item", type_name.as_str()));
+        let (property_type, mut children) = self.property_type(root_name, &item_name, schema);
+        children.insert(0, TypeStructure::make_alias(type_name, property_type));
+        children
+    }
+    fn declare_composite(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        properties: Properties,
+    ) -> Vec<TypeStructure> {
+        // case obj is empty
+        if properties.is_empty() {
+            return vec![TypeStructure::make_alias(type_name, make_any())];
+        }
+        let mut children = Vec::new();
+        let mut result = BTreeMap::new();
+        for (key, (schema, required)) in properties {
+            let property_key = PropertyKey::from(key);
+            let child_name = property_key.to_type_name(type_name);
+            let (property_type, mut grandchildren) =
+                self.property_type(root_name, &child_name, schema);
+            let property_type = match property_type {
+                PropertyType::Optional(_) => property_type,
+                _ if !required => make_optional_type(property_type),
+                _ => property_type,
+            };
+            result.insert(property_key, property_type);
+            children.append(&mut grandchildren);
+        }
+        children.insert(
+            0,
+            TypeStructure::Composite(CompositeTypeStructure::new(type_name.clone(), result)),
+        );
+        children
+    }
+    /// type of schema, and types which are declared for it.
+    /// type_name is used when schema declares new object type
+    fn property_type(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+    ) -> (PropertyType, Vec<TypeStructure>) {
+        let (property_type, children) = self.non_null_property_type(root_name, type_name, schema);
+        match property_type {
+            PropertyType::Optional(_) => (property_type, children),
+            _ if Self::is_nullable(schema) => (make_optional_type(property_type), children),
+            _ => (property_type, children),
+        }
+    }
+    fn non_null_property_type(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+    ) -> (PropertyType, Vec<TypeStructure>) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return (
+                make_custom_type(Self::reference_type_name(reference, root_name)),
+                Vec::new(),
+            );
+        }
+        if let Some(properties) = self.object_properties(schema) {
+            return (
+                make_custom_type(type_name),
+                self.declare_composite(root_name, type_name, properties),
+            );
+        }
+        if let Some(types) = self.declare_enum(root_name, type_name, schema) {
+            return (make_custom_type(type_name), types);
+        }
+        if let Some(variants) = Self::union_variants(schema) {
+            return self.union_property_type(root_name, type_name, variants);
+        }
+        if let Some([schema]) = schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            return self.non_null_property_type(root_name, type_name, schema);
+        }
+        if let Some(values) = Self::enum_values(schema) {
+            return (Self::enum_property_type(&values), Vec::new());
+        }
+        match Self::non_null_types(schema).as_slice() {
+            ["string"] if schema.get("format").and_then(Value::as_str) == Some("date-time") => {
+                (make_datetime_type(), Vec::new())
+            }
+            ["string"] => (make_string_type(), Vec::new()),
+            ["integer"] => (Self::integer_property_type(schema), Vec::new()),
+            ["number"] => (make_float_type(), Vec::new()),
+            ["integer", "number"] | ["number", "integer"] => (make_float_type(), Vec::new()),
+            ["boolean"] => (make_bool_type(), Vec::new()),
+            ["array"] => self.array_property_type(root_name, type_name, schema),
+            [] if schema.get("items").is_some() => {
+                self.array_property_type(root_name, type_name, schema)
+            }
+            _ => (make_any(), Vec::new()),
+        }
+    }
+    fn array_property_type(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+    ) -> (PropertyType, Vec<TypeStructure>) {
+        match schema.get("items") {
+            Some(items) => {
+                let (property_type, children) = self.property_type(root_name, type_name, items);
+                (make_array_type(property_type), children)
+            }
+            None => (make_array_type(make_any()), Vec::new()),
+        }
+    }
+    /// string enum is literal enum, and oneOf of different types is tagged enum
+    /// if it has discriminator, otherwise untagged enum
+    fn declare_enum(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+    ) -> Option<Vec<TypeStructure>> {
+        if let Some(values) = Self::literal_values(schema) {
+            return Some(vec![TypeStructure::make_literal_enum(type_name, values)]);
+        }
+        let variants = schema
+            .get("oneOf")
+            .and_then(Value::as_array)?
+            .iter()
+            .filter(|variant| !Self::is_null(variant))
+            .collect::<Vec<_>>();
+        if variants.len() < 2 {
+            return None;
+        }
+        if let Some(types) = self.declare_tagged_enum(root_name, type_name, schema, &variants) {
+            return Some(types);
+        }
+        let mut children = Vec::new();
+        let mut types = Vec::new();
+        for (i, variant) in variants.iter().enumerate() {
+            let variant_name = TypeName::new(format!("{}Variant{}", type_name.as_str(), i + 1));
+            let (property_type, mut grandchildren) =
+                self.property_type(root_name, &variant_name, variant);
+            types.push(property_type);
+            children.append(&mut grandchildren);
+        }
+        if types.iter().all(|property_type| property_type == &types[0]) {
+            return None;
+        }
+        children.insert(0, TypeStructure::make_untagged_enum(type_name, types));
+        Some(children)
+    }
+    /// variant of tagged enum is object without tag, because tag is the value of variant
+    fn declare_tagged_enum(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        schema: &Value,
+        variants: &[&Value],
+    ) -> Option<Vec<TypeStructure>> {
+        let discriminator = schema.get("discriminator")?;
+        let tag = discriminator.get("propertyName").and_then(Value::as_str)?;
+        let mapping = discriminator.get("mapping").and_then(Value::as_object);
+        let mut children = Vec::new();
+        let mut tagged = Vec::new();
+        for variant in variants {
+            let mut properties = self
+                .resolve(variant)
+                .and_then(|variant| self.object_properties(variant))?;
+            let tag_schema = properties.remove(tag).map(|(schema, _)| schema);
+            let value = Self::tag_value(variant, tag_schema, mapping)?;
+            let variant_name = TypeName::new(format!(
+                "{}{}",
+                type_name.as_str(),
+                TypeName::from(value.as_str()).as_str()
+            ));
+            children.append(&mut self.declare_composite(root_name, &variant_name, properties));
+            tagged.push((value, make_custom_type(variant_name)));
+        }
+        let tagged = tagged
+            .iter()
+            .map(|(value, property_type)| (value.as_str(), property_type.clone()))
+            .collect();
+        children.insert(0, TypeStructure::make_tagged_enum(type_name, tag, tagged));
+        Some(children)
+    }
+    /// value of tag is key of mapping, const of tag property or name of referred schema
+    fn tag_value(
+        variant: &Value,
+        tag_schema: Option<&Value>,
+        mapping: Option<&serde_json::Map<String, Value>>,
+    ) -> Option<String> {
+        let reference = variant.get("$ref").and_then(Value::as_str);
+        if let (Some(reference), Some(mapping)) = (reference, mapping) {
+            let value = mapping.iter().find(|(_, mapped)| {
+                mapped.as_str().is_some_and(|mapped| {
+                    mapped == reference || reference.ends_with(&format!("/{}", mapped))
+                })
+            });
+            if let Some((value, _)) = value {
+                return Some(value.clone());
+            }
+        }
+        let value = tag_schema
+            .and_then(Self::enum_values)
+            .and_then(|values| values.first().and_then(|value| value.as_str()));
+        if let Some(value) = value {
+            return Some(value.to_string());
+        }
+        reference
+            .and_then(|reference| reference.rsplit('/').next())
+            .map(str::to_string)
+    }
+    /// anyOf of the same type is the type, otherwise Any
+    fn union_property_type(
+        &self,
+        root_name: &TypeName,
+        type_name: &TypeName,
+        variants: Vec<&Value>,
+    ) -> (PropertyType, Vec<TypeStructure>) {
+        let variants = variants
+            .into_iter()
+            .filter(|variant| !Self::is_null(variant))
+            .collect::<Vec<_>>();
+        match variants.as_slice() {
+            [] => return (make_any(), Vec::new()),
+            [variant] => return self.property_type(root_name, type_name, variant),
+            _ => {}
+        }
+        let mut types = variants
+            .iter()
+            .map(|variant| self.property_type(root_name, type_name, variant));
+        let (first_type, children) = types.next().unwrap_or((make_any(), Vec::new()));
+        if types.all(|(property_type, _)| property_type == first_type) {
+            return (first_type, children);
+        }
+        (make_any(), Vec::new())
+    }
+    /// properties of object schema.
+    /// properties of allOf are merged, and objects of anyOf are put together
+    fn object_properties<'a>(&'a self, schema: &'a Value) -> Option<Properties<'a>> {
+        self.object_properties_visiting(schema, &[])
+    }
+    /// visiting is references being resolved.
+    /// part which refers one of them again is cycle, so it is not merged and stays reference
+    fn object_properties_visiting<'a>(
+        &'a self,
+        schema: &'a Value,
+        visiting: &[&'a str],
+    ) -> Option<Properties<'a>> {
+        if schema.get("$ref").is_some() {
+            return None;
+        }
+        if schema.get("oneOf").is_some() {
+            return None;
+        }
+        if let Some(variants) = Self::union_variants(schema) {
+            let variants = variants
+                .into_iter()
+                .filter(|variant| !Self::is_null(variant))
+                .collect::<Vec<_>>();
+            return if variants.len() > 1 {
+                self.put_together_properties(&variants, visiting)
+            } else {
+                None
+            };
+        }
+        let mut result = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|properties| Self::own_properties(schema, properties));
+        for part in schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(properties) = self
+                .resolve_visiting(part, visiting)
+                .and_then(|(part, visiting)| self.object_properties_visiting(part, &visiting))
+            else {
+                continue;
+            };
+            let result = result.get_or_insert_with(BTreeMap::new);
+            for (key, (schema, required)) in properties {
+                let entry = result.entry(key).or_insert((schema, required));
+                entry.1 |= required;
+            }
+        }
+        result
+    }
+    fn own_properties<'a>(
+        schema: &'a Value,
+        properties: &'a serde_json::Map<String, Value>,
+    ) -> Properties<'a> {
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| {
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        properties
+            .iter()
+            .map(|(key, schema)| (key.as_str(), (schema, required.contains(&key.as_str()))))
+            .collect()
+    }
+    /// schema referred by part, and references being resolved with it.
+    /// None if reference can not be resolved or is cycle
+    fn resolve_visiting<'a>(
+        &'a self,
+        part: &'a Value,
+        visiting: &[&'a str],
+    ) -> Option<(&'a Value, Vec<&'a str>)> {
+        let mut visiting = visiting.to_vec();
+        if let Some(reference) = part.get("$ref").and_then(Value::as_str) {
+            if visiting.contains(&reference) {
+                return None;
+            }
+            visiting.push(reference);
+        }
+        Some((self.resolve(part)?, visiting))
+    }
+    /// property is required only if it is required in all variants
+    fn put_together_properties<'a>(
+        &'a self,
+        variants: &[&'a Value],
+        visiting: &[&'a str],
+    ) -> Option<Properties<'a>> {
+        let variants = variants
+            .iter()
+            .map(|variant| {
+                self.resolve_visiting(variant, visiting)
+                    .and_then(|(variant, visiting)| {
+                        self.object_properties_visiting(variant, &visiting)
+                    })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let mut result = Properties::new();
+        for properties in &variants {
+            for (key, (schema, _)) in properties {
+                let required = variants.iter().all(|properties| {
+                    properties
+                        .get(key)
+                        .map(|(_, required)| *required)
+                        .unwrap_or(false)
+                });
+                result.entry(key).or_insert((schema, required));
+            }
+        }
+        Some(result)
+    }
+    fn union_variants(schema: &Value) -> Option<Vec<&Value>> {
+        ["oneOf", "anyOf"]
+            .iter()
+            .find_map(|key| schema.get(key).and_then(Value::as_array))
+            .map(|variants| variants.iter().collect())
+    }
+    fn enum_values(schema: &Value) -> Option<Vec<&Value>> {
+        match (schema.get("enum"), schema.get("const")) {
+            (Some(Value::Array(values)), _) => Some(values.iter().collect()),
+            (_, Some(value)) => Some(vec![value]),
+            _ => None,
+        }
+    }
+    /// enum of only strings, null is expressed by optional
+    fn literal_values(schema: &Value) -> Option<Vec<&str>> {
+        let values = schema
+            .get("enum")
+            .and_then(Value::as_array)?
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(Value::as_str)
+            .collect::<Option<Vec<_>>>()?;
+        (!values.is_empty()).then_some(values)
+    }
+    /// values of enum are typed by json type of them
+    fn enum_property_type(values: &[&Value]) -> PropertyType {
+        let values = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return make_any();
+        }
+        if values.iter().all(|value| value.is_string()) {
+            return make_string_type();
+        }
+        if values.iter().all(|value| value.is_boolean()) {
+            return make_bool_type();
+        }
+        if values.iter().all(|value| value.is_u64()) {
+            return make_usize_type();
+        }
+        if values.iter().all(|value| value.is_i64() || value.is_u64()) {
+            return make_isize_type();
+        }
+        if values.iter().all(|value| value.is_number()) {
+            return make_float_type();
+        }
+        make_any()
+    }
+    /// signed or unsigned is decided by format, or by minimum if format is not stated
+    fn integer_property_type(schema: &Value) -> PropertyType {
+        match schema.get("format").and_then(Value::as_str) {
+            Some(format) if format.starts_with("uint") => make_usize_type(),
+            Some(format) if format.starts_with("int") => make_isize_type(),
+            _ => {
+                let minimum = ["minimum", "exclusiveMinimum"]
+                    .iter()
+                    .find_map(|key| schema.get(key).and_then(Value::as_f64));
+                match minimum {
+                    Some(minimum) if minimum >= 0.0 => make_usize_type(),
+                    _ => make_isize_type(),
+                }
+            }
+        }
+    }
+    fn non_null_types(schema: &Value) -> Vec<&str> {
+        match schema.get("type") {
+            Some(Value::String(type_)) => vec![type_.as_str()],
+            Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        }
+        .into_iter()
+        .filter(|type_| *type_ != "null")
+        .collect()
+    }
+    fn is_null(schema: &Value) -> bool {
+        schema.get("type").and_then(Value::as_str) == Some("null")
+    }
+    /// `type: [T, "null"]`, `nullable: true` (OpenAPI 3.0), null variant or null in enum
+    fn is_nullable(schema: &Value) -> bool {
+        let type_has_null = match schema.get("type") {
+            Some(Value::Array(types)) => types.iter().any(|type_| type_ == "null"),
+            _ => false,
+        };
+        let variants_have_null = Self::union_variants(schema)
+            .map(|variants| variants.into_iter().any(Self::is_null))
+            .unwrap_or(false);
+        let enum_has_null = schema
+            .get("enum")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().any(Value::is_null))
+            .unwrap_or(false);
+        type_has_null
+            || variants_have_null
+            || enum_has_null
+            || schema.get("nullable") == Some(&Value::Bool(true))
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use super::*;
+    #[test]
+    fn requiredでないプロパティは省略可能になる() {
+        let schema = JsonSchema::from(
+            r#"{
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": {"type": "integer", "format": "int64"},
+                    "name": {"type": "string"},
+                    "age": {"type": ["integer", "null"], "minimum": 0},
+                    "score": {"type": "number", "format": "double"},
+                    "tags": {"type": "array", "items": {"type": "string"}},
+                    "child": {
+                        "type": "object",
+                        "required": ["active"],
+                        "properties": {"active": {"type": "boolean"}}
+                    }
+                }
+            }"#,
+        );
+
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![
+                TypeStructure::make_composite(
+                    "Test",
+                    vec![
+                        ("age", make_optional_type(make_usize_type())),
+                        ("child", make_optional_type(make_custom_type("TestChild"))),
+                        ("id", make_isize_type()),
+                        ("name", make_optional_type(make_string_type())),
+                        ("score", make_optional_type(make_float_type())),
+                        (
+                            "tags",
+                            make_optional_type(make_array_type(make_string_type()))
+                        ),
+                    ]
+                ),
+                TypeStructure::make_composite("TestChild", vec![("active", make_bool_type())]),
+            ]
+        );
+    }
+    #[test]
+    fn refはdefsの型を参照する() {
+        let schema = JsonSchema::from(
+            r##"{
+                "type": "object",
+                "required": ["address", "status"],
+                "properties": {
+                    "address": {"$ref": "#/$defs/address"},
+                    "status": {"$ref": "#/definitions/status"},
+                    "parent": {"$ref": "#"}
+                },
+                "$defs": {
+                    "address": {
+                        "type": "object",
+                        "required": ["city"],
+                        "properties": {"city": {"type": "string"}}
+                    }
+                },
+                "definitions": {
+                    "status": {"enum": ["active", "inactive", null]}
+                }
+            }"##,
+        );
+
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![
+                TypeStructure::make_composite(
+                    "Test",
+                    vec![
+                        ("address", make_custom_type("Address")),
+                        ("parent", make_optional_type(make_custom_type("Test"))),
+                        ("status", make_custom_type("Status")),
+                    ]
+                ),
+                TypeStructure::make_composite("Address", vec![("city", make_string_type())]),
+                TypeStructure::make_alias(
+                    "Status",
+                    make_optional_type(make_custom_type("StatusItem"))
+                ),
+                TypeStructure::make_literal_enum("StatusItem", vec!["active", "inactive"]),
+            ]
+        );
+    }
+    #[test]
+    fn all_ofの自己参照は型の参照になる() {
+        let schema = JsonSchema::from(
+            r##"{
+                "$defs": {
+                    "node": {
+                        "allOf": [
+                            {"$ref": "#/$defs/node"},
+                            {
+                                "type": "object",
+                                "required": ["id"],
+                                "properties": {"id": {"type": "integer"}}
+                            }
+                        ]
+                    }
+                }
+            }"##,
+        );
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![TypeStructure::make_composite(
+                "Node",
+                vec![("id", make_isize_type())]
+            )]
+        );
+
+        let schema = JsonSchema::from(
+            r##"{
+                "$defs": {
+                    "parent": {"allOf": [{"$ref": "#/$defs/child"}]},
+                    "child": {"allOf": [{"$ref": "#/$defs/parent"}]}
+                }
+            }"##,
+        );
+        let result = schema.into_type_structures("Test");
+        assert!(result.contains(&TypeStructure::make_alias(
+            "Parent",
+            make_custom_type("Child")
+        )));
+        assert!(result.contains(&TypeStructure::make_alias(
+            "Child",
+            make_custom_type("Parent")
+        )));
+    }
+    #[test]
+    fn one_ofは異なる型のunionになる() {
+        let schema = JsonSchema::from(
+            r##"{
+                "$defs": {
+                    "pet": {
+                        "oneOf": [
+                            {"$ref": "#/$defs/cat"},
+                            {
+                                "type": "object",
+                                "required": ["name", "bark"],
+                                "properties": {
+                                    "name": {"type": "string"},
+                                    "bark": {"type": "boolean"}
+                                }
+                            }
+                        ]
+                    },
+                    "cat": {
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {"name": {"type": "string"}}
+                    },
+                    "id": {"anyOf": [{"type": "string"}, {"type": "null"}]},
+                    "value": {"oneOf": [{"type": "string"}, {"type": "integer"}]}
+                }
+            }"##,
+        );
+
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![
+                TypeStructure::make_composite("Cat", vec![("name", make_string_type())]),
+                TypeStructure::make_alias("Id", make_optional_type(make_string_type())),
+                TypeStructure::make_untagged_enum(
+                    "Pet",
+                    vec![make_custom_type("Cat"), make_custom_type("PetVariant2")]
+                ),
+                TypeStructure::make_composite(
+                    "PetVariant2",
+                    vec![("bark", make_bool_type()), ("name", make_string_type())]
+                ),
+                TypeStructure::make_untagged_enum(
+                    "Value",
+                    vec![make_string_type(), make_isize_type()]
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn discriminatorのあるone_ofはタグ付きのunionになる() {
+        let schema = JsonSchema::from(
+            r##"{
+                "$defs": {
+                    "circle": {
+                        "type": "object",
+                        "required": ["kind", "radius"],
+                        "properties": {
+                            "kind": {"type": "string"},
+                            "radius": {"type": "number"}
+                        }
+                    },
+                    "shape": {
+                        "oneOf": [
+                            {"$ref": "#/$defs/circle"},
+                            {
+                                "type": "object",
+                                "required": ["kind", "side"],
+                                "properties": {
+                                    "kind": {"const": "square"},
+                                    "side": {"type": "number"}
+                                }
+                            }
+                        ],
+                        "discriminator": {
+                            "propertyName": "kind",
+                            "mapping": {"round": "#/$defs/circle"}
+                        }
+                    }
+                }
+            }"##,
+        );
+
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![
+                TypeStructure::make_composite(
+                    "Circle",
+                    vec![("kind", make_string_type()), ("radius", make_float_type())]
+                ),
+                TypeStructure::make_tagged_enum(
+                    "Shape",
+                    "kind",
+                    vec![
+                        ("round", make_custom_type("ShapeRound")),
+                        ("square", make_custom_type("ShapeSquare")),
+                    ]
+                ),
+                TypeStructure::make_composite("ShapeRound", vec![("radius", make_float_type())]),
+                TypeStructure::make_composite("ShapeSquare", vec![("side", make_float_type())]),
+            ]
+        );
+    }
+    #[test]
+    fn ルートの配列は要素の型の配列になる() {
+        let schema = JsonSchema::from(
+            r#"{
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id"],
+                    "properties": {"id": {"type": "integer", "format": "uint32"}}
+                }
+            }"#,
+        );
+
+        assert_eq!(
+            schema.into_type_structures("Test"),
+            vec![
+                TypeStructure::make_alias("Test", make_array_type(make_custom_type("TestItem"))),
+                TypeStructure::make_composite("TestItem", vec![("id", make_usize_type())]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use serde_json::Value;
+use structure::parts::type_name::TypeName;
+
+/// JSON Schema document which states types of properties precisely
+#[derive(Debug, PartialEq, Clone)]
+pub struct JsonSchema {
+    root: Value,
+}
+impl JsonSchema {
+    const DEFINITIONS_KEYS: [&'static str; 2] = ["$defs", "definitions"];
+    const META_KEYS: [&'static str; 6] = [
+        "$schema",
+        "$id",
+        "$comment",
+        "title",
+        "description",
+        "examples",
+    ];
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        JsonSchema::from(result.as_str())
+    }
+    pub(crate) fn root(&self) -> &Value {
+        &self.root
+    }
+    /// `$defs` and `definitions` of root, each of them is declared as named type
+    pub(crate) fn definitions(&self) -> impl Iterator<Item = (TypeName, &Value)> {
+        Self::DEFINITIONS_KEYS
+            .iter()
+            .filter_map(|key| self.root.get(key).and_then(Value::as_object))
+            .flat_map(|definitions| definitions.iter())
+            .map(|(name, schema)| (TypeName::from(name.as_str()), schema))
+    }
+    /// root which has only definitions is not a type
+    pub(crate) fn is_only_definitions(&self) -> bool {
+        match &self.root {
+            Value::Object(obj) => obj.keys().all(|key| {
+                Self::DEFINITIONS_KEYS.contains(&key.as_str())
+                    || Self::META_KEYS.contains(&key.as_str())
+            }),
+            _ => false,
+        }
+    }
+    /// only local reference (`#/...`) can be resolved
+    pub(crate) fn resolve<'a>(&'a self, schema: &'a Value) -> Option<&'a Value> {
+        match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => self.root.pointer(reference.strip_prefix('#')?),
+            None => Some(schema),
+        }
+    }
+    /// referred type is named by last segment of reference, `#` refers root type
+    pub(crate) fn reference_type_name(reference: &str, root_name: &TypeName) -> TypeName {
+        match reference.rsplit(['/', '#']).next() {
+            Some(name) if !name.is_empty() => TypeName::from(name),
+            _ => root_name.clone(),
+        }
+    }
+}
+impl From<Value> for JsonSchema {
+    fn from(root: Value) -> Self {
+        Self { root }
+    }
+}
+impl From<&str> for JsonSchema {
+    fn from(source: &str) -> Self {
+        let root: Value = serde_json::from_str(source).unwrap();
+        JsonSchema::from(root)
+    }
+}
+impl From<String> for JsonSchema {
+    fn from(source: String) -> Self {
+        JsonSchema::from(source.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn 参照の最後の要素が型名になる() {
+        let root_name = TypeName::from("Test");
+
+        assert_eq!(
+            JsonSchema::reference_type_name("#/$defs/user_address", &root_name),
+            TypeName::from("UserAddress")
+        );
+        assert_eq!(
+            JsonSchema::reference_type_name("#", &root_name),
+            TypeName::from("Test")
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod into_type_structure;
+pub mod json_schema;
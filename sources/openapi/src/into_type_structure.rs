@@ -0,0 +1,278 @@
+use json_schema_source::json_schema::JsonSchema;
+use serde_json::Value;
+use structure::{parts::type_name::TypeName, type_structure::TypeStructure};
+
+use crate::openapi::OpenApi;
+
+struct Operation<'a> {
+    name: TypeName,
+    tag: String,
+    operation: &'a Value,
+}
+
+// into type structures impl
+impl OpenApi {
+    const METHODS: [&'static str; 8] = [
+        "get", "put", "post", "delete", "options", "head", "patch", "trace",
+    ];
+    const COMPONENTS: &'static str = "components";
+    const DEFAULT_TAG: &'static str = "default";
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        self.into_tagged_type_structures()
+            .into_iter()
+            .flat_map(|(_, type_structures)| type_structures)
+            .collect()
+    }
+    /// schemas of components are grouped as `components`,
+    /// and inline bodies of operation are grouped by first tag of operation
+    pub fn into_tagged_type_structures(self) -> Vec<(String, Vec<TypeStructure>)> {
+        let document = JsonSchema::from(self.root().clone());
+        let mut result: Vec<(String, Vec<TypeStructure>)> = Vec::new();
+        let components = self
+            .root()
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .flat_map(|(name, schema)| {
+                document.schema_to_type_structures(TypeName::from(name.as_str()), schema)
+            })
+            .collect::<Vec<_>>();
+        if !components.is_empty() {
+            result.push((Self::COMPONENTS.to_string(), components));
+        }
+        for operation in self.operations() {
+            let mut bodies = self.operation_bodies(&document, &operation);
+            if bodies.is_empty() {
+                continue;
+            }
+            match result.iter_mut().find(|(tag, _)| tag == &operation.tag) {
+                Some((_, type_structures)) => type_structures.append(&mut bodies),
+                None => result.push((operation.tag, bodies)),
+            }
+        }
+        result
+    }
+    fn operations(&self) -> Vec<Operation<'_>> {
+        self.root()
+            .get("paths")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .flat_map(|(path, item)| {
+                Self::METHODS.iter().filter_map(move |method| {
+                    let operation = item.get(method)?;
+                    Some(Operation {
+                        name: Self::operation_name(method, path, operation),
+                        tag: operation
+                            .pointer("/tags/0")
+                            .and_then(Value::as_str)
+                            .unwrap_or(Self::DEFAULT_TAG)
+                            .to_string(),
+                        operation,
+                    })
+                })
+            })
+            .collect()
+    }
+    /// operationId, or method and path if operationId is not stated
+    fn operation_name(method: &str, path: &str, operation: &Value) -> TypeName {
+        match operation.get("operationId").and_then(Value::as_str) {
+            Some(id) => TypeName::from(id),
+            None => TypeName::from(
+                path.split(|c: char| !c.is_ascii_alphanumeric())
+                    .filter(|segment| !segment.is_empty())
+                    .fold(method.to_string(), |acc, segment| {
+                        format!("{}_{}", acc, segment)
+                    }),
+            ),
+        }
+    }
+    /// only inline body declares new type, referred body is already declared in components
+    fn operation_bodies(&self, document: &JsonSchema, operation: &Operation) -> Vec<TypeStructure> {
+        let name = operation.name.as_str();
+        let mut result = Vec::new();
+        if let Some(schema) = operation
+            .operation
+            .get("requestBody")
+            .and_then(|body| self.body_schema(body))
+        {
+            result.append(
+                &mut document
+                    .schema_to_type_structures(TypeName::new(format!("{}Request", name)), schema),
+            );
+        }
+        let mut has_success = false;
+        for (status, response) in operation
+            .operation
+            .get("responses")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            let Some(schema) = self.body_schema(response) else {
+                continue;
+            };
+            // first success response is main response of operation
+            let type_name = if status.starts_with('2') && !has_success {
+                has_success = true;
+                TypeName::new(format!("{}Response", name))
+            } else {
+                TypeName::new(format!("{}Response{}", name, Self::capitalize(status)))
+            };
+            result.append(&mut document.schema_to_type_structures(type_name, schema));
+        }
+        result
+    }
+    /// inline schema of json content
+    fn body_schema<'a>(&'a self, body: &'a Value) -> Option<&'a Value> {
+        let content = self.resolve(body)?.get("content")?.as_object()?;
+        let (_, media) = content
+            .iter()
+            .find(|(media_type, _)| media_type.as_str() == "application/json")
+            .or_else(|| {
+                content
+                    .iter()
+                    .find(|(media_type, _)| media_type.contains("json"))
+            })
+            .or_else(|| content.iter().next())?;
+        let schema = media.get("schema")?;
+        if schema.get("$ref").is_some() {
+            return None;
+        }
+        Some(schema)
+    }
+    fn capitalize(str: &str) -> String {
+        let mut chars = str.chars();
+        match chars.next() {
+            Some(first) => format!("{}{}", first.to_ascii_uppercase(), chars.as_str()),
+            None => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    const DOCUMENT: &str = r##"
+openapi: 3.0.3
+info:
+  title: users
+  version: 1.0.0
+paths:
+  /users:
+    get:
+      operationId: listUsers
+      tags: [user]
+      responses:
+        200:
+          description: users
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/User"
+    post:
+      operationId: createUser
+      tags: [user]
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [name]
+              properties:
+                name:
+                  type: string
+      responses:
+        "201":
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/User"
+        "400":
+          $ref: "#/components/responses/Error"
+  /health:
+    get:
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  status:
+                    type: string
+components:
+  schemas:
+    User:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: integer
+          minimum: 0
+        nickname:
+          type: string
+          nullable: true
+  responses:
+    Error:
+      description: error
+      content:
+        application/json:
+          schema:
+            type: object
+            properties:
+              message:
+                type: string
+"##;
+    #[test]
+    fn componentsとoperationのbodyがタグごとに型になる() {
+        let sut = OpenApi::from(DOCUMENT);
+
+        assert_eq!(
+            sut.into_tagged_type_structures(),
+            vec![
+                (
+                    "components".to_string(),
+                    vec![TypeStructure::make_composite(
+                        "User",
+                        vec![
+                            ("id", make_usize_type()),
+                            ("nickname", make_optional_type(make_string_type())),
+                        ]
+                    )]
+                ),
+                (
+                    "default".to_string(),
+                    vec![TypeStructure::make_composite(
+                        "GetHealthResponse",
+                        vec![("status", make_optional_type(make_string_type()))]
+                    )]
+                ),
+                (
+                    "user".to_string(),
+                    vec![
+                        TypeStructure::make_alias(
+                            "ListUsersResponse",
+                            make_array_type(make_custom_type("User"))
+                        ),
+                        TypeStructure::make_composite(
+                            "CreateUserRequest",
+                            vec![("name", make_string_type())]
+                        ),
+                        TypeStructure::make_composite(
+                            "CreateUserResponse400",
+                            vec![("message", make_optional_type(make_string_type()))]
+                        ),
+                    ]
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use serde_json::Value;
+use yaml_source::yaml::Yaml;
+
+/// OpenAPI 3 document written in yaml or json
+#[derive(Debug, PartialEq, Clone)]
+pub struct OpenApi {
+    root: Value,
+}
+impl OpenApi {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        OpenApi::from(result.as_str())
+    }
+    pub(crate) fn root(&self) -> &Value {
+        &self.root
+    }
+    /// only local reference (`#/...`) can be resolved
+    pub(crate) fn resolve<'a>(&'a self, value: &'a Value) -> Option<&'a Value> {
+        match value.get("$ref").and_then(Value::as_str) {
+            Some(reference) => self.root.pointer(reference.strip_prefix('#')?),
+            None => Some(value),
+        }
+    }
+}
+impl From<Value> for OpenApi {
+    fn from(root: Value) -> Self {
+        Self { root }
+    }
+}
+/// json is also valid yaml
+impl From<&str> for OpenApi {
+    fn from(source: &str) -> Self {
+        let yaml: serde_yaml::Value = serde_yaml::from_str(source).unwrap();
+        OpenApi::from(Yaml::to_json_value(yaml))
+    }
+}
+impl From<String> for OpenApi {
+    fn from(source: String) -> Self {
+        OpenApi::from(source.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn yamlの数値のキーは文字列になる() {
+        let sut = OpenApi::from("responses:\n  200:\n    description: ok\n");
+
+        assert_eq!(
+            sut.root(),
+            &serde_json::json!({"responses": {"200": {"description": "ok"}}})
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use protobuf::descriptor::{
+    field_descriptor_proto::{Label, Type},
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+};
+use structure::{
+    composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
+    parts::{
+        property_key::PropertyKey,
+        property_type::{
+            property_type_factories::{
+                make_any, make_array_type, make_bool_type, make_custom_type, make_datetime_type,
+                make_float_type, make_isize_type, make_optional_type, make_string_type,
+                make_usize_type,
+            },
+            PropertyType,
+        },
+    },
+    type_structure::TypeStructure,
+};
+
+use crate::proto::Proto;
+
+// into type structures impl
+impl Proto {
+    /// types are named by schema, nested message is prefixed by name of parent
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        let declared = Declared::new(self.file_descriptors());
+        let mut result = Vec::new();
+        for file in self.input_file_descriptors() {
+            for message in &file.message_type {
+                declared.message_to_type_structures(message, "", &mut result);
+            }
+            for enum_type in &file.enum_type {
+                result.push(Declared::enum_to_type_structure(enum_type, ""));
+            }
+        }
+        result
+    }
+}
+
+/// names of all messages and enums, keyed by full name (`.package.Message`)
+struct Declared {
+    type_names: HashMap<String, String>,
+    map_entries: HashSet<String>,
+}
+impl Declared {
+    fn new(files: &[FileDescriptorProto]) -> Self {
+        let mut declared = Self {
+            type_names: HashMap::new(),
+            map_entries: HashSet::new(),
+        };
+        for file in files {
+            let scope = match file.package() {
+                "" => String::new(),
+                package => format!(".{}", package),
+            };
+            for message in &file.message_type {
+                declared.put_message(message, &scope, "");
+            }
+            for enum_type in &file.enum_type {
+                declared.type_names.insert(
+                    format!("{}.{}", scope, enum_type.name()),
+                    enum_type.name().to_string(),
+                );
+            }
+        }
+        declared
+    }
+    fn put_message(&mut self, message: &DescriptorProto, scope: &str, prefix: &str) {
+        let full_name = format!("{}.{}", scope, message.name());
+        let type_name = format!("{}{}", prefix, message.name());
+        if message.options.map_entry() {
+            self.map_entries.insert(full_name.clone());
+        }
+        for nested in &message.nested_type {
+            self.put_message(nested, &full_name, &type_name);
+        }
+        for enum_type in &message.enum_type {
+            self.type_names.insert(
+                format!("{}.{}", full_name, enum_type.name()),
+                format!("{}{}", type_name, enum_type.name()),
+            );
+        }
+        self.type_names.insert(full_name, type_name);
+    }
+    fn message_to_type_structures(
+        &self,
+        message: &DescriptorProto,
+        prefix: &str,
+        result: &mut Vec<TypeStructure>,
+    ) {
+        let type_name = format!("{}{}", prefix, message.name());
+        // key is json name of field, same as proto3 json mapping.
+        // member of oneof is put on message directly, so it is optional field
+        let properties = message
+            .field
+            .iter()
+            .map(|field| (PropertyKey::from(field.json_name()), self.field_type(field)))
+            .collect::<BTreeMap<_, _>>();
+        result.push(TypeStructure::Composite(CompositeTypeStructure::new(
+            type_name.as_str(),
+            properties,
+        )));
+        for nested in message
+            .nested_type
+            .iter()
+            .filter(|nested| !nested.options.map_entry())
+        {
+            self.message_to_type_structures(nested, &type_name, result);
+        }
+        for enum_type in &message.enum_type {
+            result.push(Self::enum_to_type_structure(enum_type, &type_name));
+        }
+    }
+    /// value of enum is sent as its name in json
+    fn enum_to_type_structure(enum_type: &EnumDescriptorProto, prefix: &str) -> TypeStructure {
+        TypeStructure::Enum(EnumTypeStructure::new(
+            format!("{}{}", prefix, enum_type.name()),
+            EnumRepresentation::Literal(
+                enum_type
+                    .value
+                    .iter()
+                    .map(|value| value.name().to_string())
+                    .collect(),
+            ),
+        ))
+    }
+    /// message and field of oneof (including proto3 optional) may be absent
+    fn field_type(&self, field: &FieldDescriptorProto) -> PropertyType {
+        // map is json object, and type structure has no map, so it is any object
+        if self.map_entries.contains(field.type_name()) {
+            return make_any();
+        }
+        let property_type = match field.type_() {
+            Type::TYPE_DOUBLE | Type::TYPE_FLOAT => make_float_type(),
+            Type::TYPE_INT32 | Type::TYPE_SINT32 | Type::TYPE_SFIXED32 => make_isize_type(),
+            Type::TYPE_UINT32 | Type::TYPE_FIXED32 => make_usize_type(),
+            // 64 bit integer is string in json, because it may exceed precision of number
+            Type::TYPE_INT64
+            | Type::TYPE_SINT64
+            | Type::TYPE_SFIXED64
+            | Type::TYPE_UINT64
+            | Type::TYPE_FIXED64 => make_string_type(),
+            Type::TYPE_BOOL => make_bool_type(),
+            // bytes is base64 string in json
+            Type::TYPE_STRING | Type::TYPE_BYTES => make_string_type(),
+            Type::TYPE_ENUM | Type::TYPE_MESSAGE | Type::TYPE_GROUP => {
+                self.named_type(field.type_name())
+            }
+        };
+        if field.label() == Label::LABEL_REPEATED {
+            return make_array_type(property_type);
+        }
+        if field.has_oneof_index() || field.type_() == Type::TYPE_MESSAGE {
+            return make_optional_type(property_type);
+        }
+        property_type
+    }
+    /// well known types are typed as their json mapping, for example Duration is string
+    fn named_type(&self, full_name: &str) -> PropertyType {
+        match full_name {
+            ".google.protobuf.Timestamp" => make_datetime_type(),
+            ".google.protobuf.Duration"
+            | ".google.protobuf.FieldMask"
+            | ".google.protobuf.StringValue"
+            | ".google.protobuf.BytesValue"
+            | ".google.protobuf.Int64Value"
+            | ".google.protobuf.UInt64Value" => make_string_type(),
+            ".google.protobuf.Int32Value" => make_isize_type(),
+            ".google.protobuf.UInt32Value" => make_usize_type(),
+            ".google.protobuf.FloatValue" | ".google.protobuf.DoubleValue" => make_float_type(),
+            ".google.protobuf.BoolValue" => make_bool_type(),
+            ".google.protobuf.Struct" | ".google.protobuf.Value" | ".google.protobuf.Any" => {
+                make_any()
+            }
+            full_name => match self.type_names.get(full_name) {
+                Some(type_name) => make_custom_type(type_name.as_str()),
+                None => make_any(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use super::*;
+    #[test]
+    fn ネストしたメッセージは親の名前を接頭辞にした型になる() {
+        let sut = Proto::from(
+            r#"
+            syntax = "proto3";
+            package shop.v1;
+            import "google/protobuf/timestamp.proto";
+
+            message Order {
+              enum Status { STATUS_UNSPECIFIED = 0; PAID = 1; }
+              message Item { string sku = 1; uint32 count = 2; }
+              int64 order_id = 1;
+              repeated Item items = 2;
+              map<string, string> labels = 3;
+              optional string memo = 4;
+              Status status = 5;
+              google.protobuf.Timestamp created_at = 6;
+              oneof payment { string card = 7; string bank = 8; }
+            }
+            "#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![
+                TypeStructure::make_composite(
+                    "Order",
+                    vec![
+                        ("bank", make_optional_type(make_string_type())),
+                        ("card", make_optional_type(make_string_type())),
+                        ("createdAt", make_optional_type(make_datetime_type())),
+                        ("items", make_array_type(make_custom_type("OrderItem"))),
+                        ("labels", make_any()),
+                        ("memo", make_optional_type(make_string_type())),
+                        ("orderId", make_string_type()),
+                        ("status", make_custom_type("OrderStatus")),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "OrderItem",
+                    vec![("count", make_usize_type()), ("sku", make_string_type())]
+                ),
+                TypeStructure::make_literal_enum("OrderStatus", vec!["STATUS_UNSPECIFIED", "PAID"]),
+            ]
+        );
+    }
+}
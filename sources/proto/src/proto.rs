@@ -0,0 +1,101 @@
+use std::{fs, path::Path};
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf_parse::Parser;
+
+/// proto files which are parsed with their imports
+#[derive(Debug, PartialEq, Clone)]
+pub struct Proto {
+    file_descriptors: Vec<FileDescriptorProto>,
+    // relative paths from import root of input files
+    inputs: Vec<String>,
+}
+impl Proto {
+    /// imports are resolved relative to import root, path must be in it
+    pub fn from_file(path: impl AsRef<Path>, import_root: impl AsRef<Path>) -> Self {
+        let parsed = Parser::new()
+            .pure()
+            .include(import_root)
+            .input(path)
+            .parse_and_typecheck()
+            .unwrap();
+        Self {
+            file_descriptors: parsed.file_descriptors,
+            inputs: parsed
+                .relative_paths
+                .iter()
+                .map(|path| path.to_str().to_string())
+                .collect(),
+        }
+    }
+    /// import root is parent directory of file
+    pub fn from_file_in_parent(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let import_root = match path.parent() {
+            Some(parent) if parent != Path::new("") => parent,
+            _ => Path::new("."),
+        };
+        Proto::from_file(path, import_root)
+    }
+    /// all parsed files, including imported ones
+    pub(crate) fn file_descriptors(&self) -> &[FileDescriptorProto] {
+        &self.file_descriptors
+    }
+    /// files which are stated as input, types are declared only from them
+    pub(crate) fn input_file_descriptors(&self) -> impl Iterator<Item = &FileDescriptorProto> {
+        self.file_descriptors
+            .iter()
+            .filter(|file| self.inputs.iter().any(|input| input == file.name()))
+    }
+}
+/// parser reads only files, so content is written to temporary directory.
+/// only well known types (`google/protobuf/*.proto`) can be imported
+impl From<&str> for Proto {
+    fn from(source: &str) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("source.proto");
+        fs::write(&path, source).unwrap();
+        Proto::from_file(path, dir.path())
+    }
+}
+impl From<String> for Proto {
+    fn from(source: String) -> Self {
+        Proto::from(source.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn importされたファイルはsourceのrootから解決される() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("common")).unwrap();
+        fs::write(
+            dir.path().join("common/money.proto"),
+            "syntax = \"proto3\"; package common; message Money { int64 units = 1; }",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("order.proto"),
+            "syntax = \"proto3\"; import \"common/money.proto\"; message Order { common.Money price = 1; }",
+        )
+        .unwrap();
+
+        let sut = Proto::from_file(dir.path().join("order.proto"), dir.path());
+
+        assert_eq!(
+            sut.input_file_descriptors()
+                .map(|file| file.name())
+                .collect::<Vec<_>>(),
+            vec!["order.proto"]
+        );
+        let mut all = sut
+            .file_descriptors()
+            .iter()
+            .map(|file| file.name())
+            .collect::<Vec<_>>();
+        all.sort();
+        assert_eq!(all, vec!["common/money.proto", "order.proto"]);
+    }
+}
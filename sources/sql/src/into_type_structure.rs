@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use sqlparser::ast::{ArrayElemTypeDef, DataType, TimezoneInfo};
+use structure::{
+    composite_type_structure::CompositeTypeStructure,
+    parts::{
+        property_key::PropertyKey,
+        property_type::{
+            property_type_factories::{
+                make_any, make_array_type, make_binary_type, make_bool_type, make_date_type,
+                make_datetime_type, make_decimal_type, make_float_type, make_optional_type,
+                make_sized_isize_type, make_sized_usize_type, make_string_type, make_time_type,
+                make_uuid_type, make_zoned_datetime_type,
+            },
+            PropertyType,
+        },
+    },
+    type_structure::TypeStructure,
+};
+
+use crate::sql::{Column, Sql};
+
+// into type structures impl
+impl Sql {
+    /// one row type per table, named by table
+    pub fn into_type_structures(self) -> Vec<TypeStructure> {
+        self.tables()
+            .into_iter()
+            .map(|table| {
+                let properties = table
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        (
+                            PropertyKey::from(column.name.as_str()),
+                            Self::column_type(column),
+                        )
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                TypeStructure::Composite(CompositeTypeStructure::new(table.name, properties))
+            })
+            .collect()
+    }
+    fn column_type(column: &Column) -> PropertyType {
+        let property_type = Self::data_type(&column.data_type);
+        if column.nullable {
+            return make_optional_type(property_type);
+        }
+        property_type
+    }
+    fn data_type(data_type: &DataType) -> PropertyType {
+        match data_type {
+            DataType::Bool | DataType::Boolean => make_bool_type(),
+            // width is kept, because row decoder (for example sqlx) requires exact width
+            DataType::TinyInt(_) => make_sized_isize_type(8),
+            DataType::SmallInt(_) | DataType::Int2(_) | DataType::Int16 => {
+                make_sized_isize_type(16)
+            }
+            DataType::MediumInt(_)
+            | DataType::Int(_)
+            | DataType::Integer(_)
+            | DataType::Int4(_)
+            | DataType::Int32 => make_sized_isize_type(32),
+            DataType::BigInt(_) | DataType::Int8(_) | DataType::Int64 => make_sized_isize_type(64),
+            DataType::Int128 => make_sized_isize_type(128),
+            DataType::Int256 => make_sized_isize_type(256),
+            DataType::UnsignedTinyInt(_) | DataType::UInt8 => make_sized_usize_type(8),
+            DataType::UnsignedSmallInt(_) | DataType::UnsignedInt2(_) | DataType::UInt16 => {
+                make_sized_usize_type(16)
+            }
+            DataType::UnsignedMediumInt(_)
+            | DataType::UnsignedInt(_)
+            | DataType::UnsignedInteger(_)
+            | DataType::UnsignedInt4(_)
+            | DataType::UInt32 => make_sized_usize_type(32),
+            DataType::UnsignedBigInt(_) | DataType::UnsignedInt8(_) | DataType::UInt64 => {
+                make_sized_usize_type(64)
+            }
+            DataType::UInt128 => make_sized_usize_type(128),
+            DataType::UInt256 => make_sized_usize_type(256),
+            DataType::Float(_)
+            | DataType::Float4
+            | DataType::Float8
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Real
+            | DataType::Double
+            | DataType::DoublePrecision => make_float_type(),
+            DataType::Numeric(_)
+            | DataType::Decimal(_)
+            | DataType::Dec(_)
+            | DataType::BigNumeric(_)
+            | DataType::BigDecimal(_) => make_decimal_type(),
+            DataType::Timestamp(_, TimezoneInfo::WithTimeZone | TimezoneInfo::Tz) => {
+                make_zoned_datetime_type()
+            }
+            DataType::Timestamp(_, _) | DataType::Datetime(_) | DataType::Datetime64(_, _) => {
+                make_datetime_type()
+            }
+            DataType::Date | DataType::Date32 => make_date_type(),
+            // time with time zone has no type of time of day, so it is string
+            DataType::Time(_, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone) => {
+                make_time_type()
+            }
+            DataType::Uuid => make_uuid_type(),
+            DataType::Bytea
+            | DataType::Binary(_)
+            | DataType::Varbinary(_)
+            | DataType::Blob(_)
+            | DataType::TinyBlob
+            | DataType::MediumBlob
+            | DataType::LongBlob
+            | DataType::Bytes(_) => make_binary_type(),
+            DataType::JSON | DataType::JSONB => make_any(),
+            DataType::Array(
+                ArrayElemTypeDef::AngleBracket(element)
+                | ArrayElemTypeDef::SquareBracket(element, _)
+                | ArrayElemTypeDef::Parenthesis(element),
+            ) => make_array_type(Self::data_type(element)),
+            DataType::Nullable(inner) | DataType::LowCardinality(inner) => Self::data_type(inner),
+            DataType::Custom(name, _) => match name.to_string().to_lowercase().as_str() {
+                "smallserial" | "serial2" => make_sized_isize_type(16),
+                "serial" | "serial4" => make_sized_isize_type(32),
+                "bigserial" | "serial8" => make_sized_isize_type(64),
+                "timestamptz" => make_zoned_datetime_type(),
+                "money" => make_decimal_type(),
+                _ => make_string_type(),
+            },
+            // text and enum are string
+            _ => make_string_type(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use super::*;
+    #[test]
+    fn null許容の列は省略可能になり型はsqlの型から決まる() {
+        let sut = Sql::from(
+            r#"
+            CREATE TABLE order_items (
+              id BIGSERIAL PRIMARY KEY,
+              price NUMERIC(10, 2) NOT NULL,
+              quantity INT UNSIGNED NOT NULL,
+              tags TEXT[] NOT NULL,
+              metadata JSONB,
+              shipped_at TIMESTAMP WITH TIME ZONE,
+              tracking_id UUID NOT NULL,
+              ship_on DATE NOT NULL,
+              ship_at TIME,
+              label BYTEA
+            );
+            "#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures(),
+            vec![TypeStructure::make_composite(
+                "OrderItems",
+                vec![
+                    ("id", make_sized_isize_type(64)),
+                    ("label", make_optional_type(make_binary_type())),
+                    ("metadata", make_optional_type(make_any())),
+                    ("price", make_decimal_type()),
+                    ("quantity", make_sized_usize_type(32)),
+                    ("ship_at", make_optional_type(make_time_type())),
+                    ("ship_on", make_date_type()),
+                    ("shipped_at", make_optional_type(make_zoned_datetime_type())),
+                    ("tags", make_array_type(make_string_type())),
+                    ("tracking_id", make_uuid_type()),
+                ]
+            )]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use sqlparser::{
+    ast::{
+        AlterTableOperation, ColumnDef, ColumnOption, CreateTable, DataType, ObjectName, Statement,
+        TableConstraint,
+    },
+    dialect::{Dialect, GenericDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect},
+    parser::Parser,
+    tokenizer::Token,
+};
+
+/// ddl such as migration, tables are made by CREATE TABLE and changed by ALTER TABLE.
+/// other statements are ignored
+#[derive(Debug, PartialEq, Clone)]
+pub struct Sql {
+    tables: Vec<Table>,
+}
+impl Sql {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Sql::from(result.as_str())
+    }
+    pub(crate) fn tables(self) -> Vec<Table> {
+        self.tables
+    }
+    /// syntax of each database is tried, and result of one which parses most statements is used
+    fn parse(source: &str) -> Vec<Statement> {
+        let dialects: [&dyn Dialect; 4] = [
+            &GenericDialect {},
+            &PostgreSqlDialect {},
+            &MySqlDialect {},
+            &SQLiteDialect {},
+        ];
+        let mut result: Vec<Statement> = vec![];
+        for dialect in dialects {
+            let statements = Self::parse_with(dialect, source);
+            if statements.len() > result.len() {
+                result = statements;
+            }
+        }
+        result
+    }
+    /// migration has statements which are not supported (for example trigger or `DO` block),
+    /// so statement which can not be parsed is skipped until next semicolon
+    fn parse_with(dialect: &dyn Dialect, source: &str) -> Vec<Statement> {
+        let Ok(mut parser) = Parser::new(dialect).try_with_sql(source) else {
+            return vec![];
+        };
+        let mut statements = vec![];
+        loop {
+            while parser.consume_token(&Token::SemiColon) {}
+            if parser.peek_token().token == Token::EOF {
+                return statements;
+            }
+            match parser.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                Err(_) => loop {
+                    match parser.next_token().token {
+                        Token::SemiColon | Token::EOF => break,
+                        _ => {}
+                    }
+                },
+            }
+        }
+    }
+    fn put(&mut self, statement: Statement) {
+        match statement {
+            Statement::CreateTable(create_table) => self.tables.push(Table::from(create_table)),
+            Statement::AlterTable {
+                name, operations, ..
+            } => {
+                let name = Table::name_of(&name);
+                if let Some(table) = self.tables.iter_mut().find(|table| table.name == name) {
+                    operations
+                        .into_iter()
+                        .for_each(|operation| table.alter(operation));
+                }
+            }
+            Statement::Drop { names, .. } => {
+                let names = names.iter().map(Table::name_of).collect::<Vec<_>>();
+                self.tables.retain(|table| !names.contains(&table.name));
+            }
+            _ => {}
+        }
+    }
+}
+impl From<&str> for Sql {
+    fn from(source: &str) -> Self {
+        let mut result = Self { tables: vec![] };
+        for statement in Self::parse(source) {
+            result.put(statement);
+        }
+        result
+    }
+}
+impl From<String> for Sql {
+    fn from(source: String) -> Self {
+        Sql::from(source.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Table {
+    pub(crate) name: String,
+    pub(crate) columns: Vec<Column>,
+}
+impl Table {
+    /// schema of name is ignored
+    fn name_of(name: &ObjectName) -> String {
+        name.0
+            .last()
+            .map(|ident| ident.value.clone())
+            .unwrap_or_default()
+    }
+    fn alter(&mut self, operation: AlterTableOperation) {
+        match operation {
+            AlterTableOperation::AddColumn { column_def, .. } => {
+                self.columns.push(Column::new(column_def, &[]))
+            }
+            AlterTableOperation::DropColumn { column_name, .. } => self
+                .columns
+                .retain(|column| column.name != column_name.value),
+            _ => {}
+        }
+    }
+}
+impl From<CreateTable> for Table {
+    fn from(create_table: CreateTable) -> Self {
+        let primary_keys = create_table
+            .constraints
+            .iter()
+            .flat_map(|constraint| match constraint {
+                TableConstraint::PrimaryKey { columns, .. } => {
+                    columns.iter().map(|column| column.value.clone()).collect()
+                }
+                _ => vec![],
+            })
+            .collect::<Vec<_>>();
+        Self {
+            name: Self::name_of(&create_table.name),
+            columns: create_table
+                .columns
+                .into_iter()
+                .map(|column| Column::new(column, &primary_keys))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Column {
+    pub(crate) name: String,
+    pub(crate) data_type: DataType,
+    pub(crate) nullable: bool,
+}
+impl Column {
+    /// column is nullable unless it is NOT NULL or primary key
+    fn new(column: ColumnDef, primary_keys: &[String]) -> Self {
+        let not_null = primary_keys.contains(&column.name.value)
+            || column.options.iter().any(|option| {
+                matches!(
+                    option.option,
+                    ColumnOption::NotNull
+                        | ColumnOption::Unique {
+                            is_primary: true,
+                            ..
+                        }
+                )
+            });
+        Self {
+            name: column.name.value,
+            data_type: column.data_type,
+            nullable: !not_null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn alter_tableの変更はcreate_tableに反映される() {
+        let sut = Sql::from(
+            r#"
+            CREATE TABLE public.users (id BIGINT, email TEXT NOT NULL, nickname TEXT, PRIMARY KEY (id));
+            CREATE INDEX users_email ON users (email);
+            ALTER TABLE users ADD COLUMN age INT;
+            ALTER TABLE users DROP COLUMN nickname;
+            CREATE TABLE tmp (id INT);
+            DROP TABLE tmp;
+            "#,
+        );
+
+        assert_eq!(
+            sut.tables()
+                .into_iter()
+                .map(|table| (
+                    table.name,
+                    table
+                        .columns
+                        .into_iter()
+                        .map(|column| (column.name, column.nullable))
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![(
+                "users".to_string(),
+                vec![
+                    ("id".to_string(), false),
+                    ("email".to_string(), false),
+                    ("age".to_string(), true),
+                ]
+            )]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use structure::{parts::type_name::TypeName, type_structure::TypeStructure};
+
+use crate::toml::Toml;
+
+// into type structures impl
+impl Toml {
+    pub fn into_type_structures(self, root_name: impl Into<TypeName>) -> Vec<TypeStructure> {
+        self.root().into_type_structures(root_name)
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_datetime_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn テーブルは型になり日時は日時型になる() {
+        let sut = Toml::from(
+            r#"
+[package]
+name = "tg"
+authors = ["kai"]
+
+[[bin]]
+name = "tg"
+built = 2023-01-01T00:00:00Z
+"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures("Manifest"),
+            vec![
+                TypeStructure::make_composite(
+                    "Manifest",
+                    vec![
+                        ("bin", make_array_type(make_custom_type("ManifestBin"))),
+                        ("package", make_custom_type("ManifestPackage")),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "ManifestBin",
+                    vec![
+                        ("built", make_datetime_type()),
+                        ("name", make_string_type())
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "ManifestPackage",
+                    vec![
+                        ("authors", make_array_type(make_string_type())),
+                        ("name", make_string_type()),
+                    ]
+                ),
+            ]
+        );
+    }
+}
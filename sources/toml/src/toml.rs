@@ -0,0 +1,79 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use json::json::{Json, Number};
+
+/// toml document, root of which is always a table
+#[derive(Debug, PartialEq, Clone)]
+pub struct Toml {
+    root: Json,
+}
+impl Toml {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Toml::from(result.as_str())
+    }
+    pub(crate) fn root(self) -> Json {
+        self.root
+    }
+    /// not negative integer is usize like json
+    fn to_json(toml: ::toml::Value) -> Json {
+        match toml {
+            ::toml::Value::String(str) => Json::String(str),
+            ::toml::Value::Integer(num) if num >= 0 => Json::Number(Number::Usize64(num as u64)),
+            ::toml::Value::Integer(num) => Json::Number(Number::Isize64(num)),
+            ::toml::Value::Float(num) => Json::Number(Number::Float64(num)),
+            ::toml::Value::Boolean(bool) => Json::Boolean(bool),
+            ::toml::Value::Datetime(datetime) => Json::DateTime(datetime.to_string()),
+            ::toml::Value::Array(array) => {
+                Json::Array(array.into_iter().map(Self::to_json).collect())
+            }
+            ::toml::Value::Table(table) => Json::Object(
+                table
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::to_json(value)))
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+        }
+    }
+}
+impl From<&str> for Toml {
+    fn from(source: &str) -> Self {
+        let root: ::toml::Value = ::toml::from_str(source).unwrap();
+        Self {
+            root: Self::to_json(root),
+        }
+    }
+}
+impl From<String> for Toml {
+    fn from(source: String) -> Self {
+        Toml::from(source.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn 日時は文字列と区別される() {
+        let sut = Toml::from("released = 1979-05-27T07:32:00Z\nname = \"tg\"\n");
+
+        assert_eq!(
+            sut.root(),
+            Json::Object(BTreeMap::from([
+                ("name".to_string(), Json::String("tg".to_string())),
+                (
+                    "released".to_string(),
+                    Json::DateTime("1979-05-27T07:32:00Z".to_string())
+                ),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use structure::{parts::type_name::TypeName, type_structure::TypeStructure};
+
+use crate::xml::Xml;
+
+// into type structures impl
+impl Xml {
+    pub fn into_type_structures(self, root_name: impl Into<TypeName>) -> Vec<TypeStructure> {
+        self.to_json().into_type_structures(root_name)
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use structure::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 要素は型になり繰り返される要素は配列になる() {
+        let sut = Xml::from(
+            r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <order id="10">
+      <item sku="a">2</item>
+      <item sku="b">3</item>
+    </order>
+  </soap:Body>
+</soap:Envelope>"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures("Response"),
+            vec![
+                TypeStructure::make_composite(
+                    "Response",
+                    vec![("Body", make_custom_type("ResponseBody"))]
+                ),
+                TypeStructure::make_composite(
+                    "ResponseBody",
+                    vec![("order", make_custom_type("ResponseBodyOrder"))]
+                ),
+                TypeStructure::make_composite(
+                    "ResponseBodyOrder",
+                    vec![
+                        ("@id", make_usize_type()),
+                        (
+                            "item",
+                            make_array_type(make_custom_type("ResponseBodyOrderItem"))
+                        ),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "ResponseBodyOrderItem",
+                    vec![("$value", make_usize_type()), ("@sku", make_string_type())]
+                ),
+            ]
+        );
+    }
+}
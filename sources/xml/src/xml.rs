@@ -0,0 +1,138 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use json::json::{Json, Number};
+use roxmltree::{Document, Node};
+
+/// xml document, elements are objects and attributes are properties with prefix
+#[derive(Debug, PartialEq, Clone)]
+pub struct Xml {
+    source: String,
+    attribute_prefix: String,
+}
+impl Xml {
+    /// quick-xml treats `@` prefixed field as attribute
+    pub const DEFAULT_ATTRIBUTE_PREFIX: &'static str = "@";
+    /// serde-xml-rs and quick-xml treat this field as text content
+    pub const TEXT_KEY: &'static str = "$value";
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Xml::from(result)
+    }
+    pub fn attribute_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.attribute_prefix = prefix.into();
+        self
+    }
+    /// root element is root object, namespace of name is ignored
+    pub(crate) fn to_json(&self) -> Json {
+        let document = Document::parse(&self.source).unwrap();
+        self.element_to_json(document.root_element())
+    }
+    fn element_to_json(&self, element: Node) -> Json {
+        let mut object = element
+            .attributes()
+            .map(|attribute| {
+                (
+                    format!("{}{}", self.attribute_prefix, attribute.name()),
+                    Self::text_to_json(attribute.value()),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut children: BTreeMap<String, Vec<Json>> = BTreeMap::new();
+        for child in element.children().filter(Node::is_element) {
+            children
+                .entry(child.tag_name().name().to_string())
+                .or_default()
+                .push(self.element_to_json(child));
+        }
+        // repeated sibling elements are array
+        for (name, mut jsons) in children {
+            let json = if jsons.len() == 1 {
+                jsons.remove(0)
+            } else {
+                Json::Array(jsons)
+            };
+            object.insert(name, json);
+        }
+        let text = element
+            .children()
+            .filter(Node::is_text)
+            .filter_map(|node| node.text())
+            .collect::<String>();
+        let text = text.trim();
+        if object.is_empty() {
+            return Self::text_to_json(text);
+        }
+        if !text.is_empty() {
+            object.insert(Self::TEXT_KEY.to_string(), Self::text_to_json(text));
+        }
+        Json::Object(object)
+    }
+    fn text_to_json(text: &str) -> Json {
+        if let Ok(bool) = text.parse::<bool>() {
+            return Json::Boolean(bool);
+        }
+        if let Ok(num) = text.parse::<u64>() {
+            return Json::Number(Number::from(num));
+        }
+        if let Ok(num) = text.parse::<i64>() {
+            return Json::Number(Number::from(num));
+        }
+        if let Ok(num) = text.parse::<f64>() {
+            return Json::Number(Number::from(num));
+        }
+        Json::String(text.to_string())
+    }
+}
+impl From<&str> for Xml {
+    fn from(source: &str) -> Self {
+        Xml::from(source.to_string())
+    }
+}
+impl From<String> for Xml {
+    fn from(source: String) -> Self {
+        Self {
+            source,
+            attribute_prefix: Self::DEFAULT_ATTRIBUTE_PREFIX.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn 属性は接頭辞付きのキーになり本文はvalueになる() {
+        let sut =
+            Xml::from(r#"<user id="1"><name lang="ja">kai</name><tag>a</tag><tag>b</tag></user>"#)
+                .attribute_prefix("_");
+
+        assert_eq!(
+            sut.to_json(),
+            Json::Object(BTreeMap::from([
+                ("_id".to_string(), Json::Number(Number::Usize64(1))),
+                (
+                    "name".to_string(),
+                    Json::Object(BTreeMap::from([
+                        ("$value".to_string(), Json::String("kai".to_string())),
+                        ("_lang".to_string(), Json::String("ja".to_string())),
+                    ]))
+                ),
+                (
+                    "tag".to_string(),
+                    Json::Array(vec![
+                        Json::String("a".to_string()),
+                        Json::String("b".to_string())
+                    ])
+                ),
+            ]))
+        );
+    }
+}
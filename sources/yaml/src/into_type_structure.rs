@@ -0,0 +1,76 @@
+use structure::{parts::type_name::TypeName, type_structure::TypeStructure};
+
+use crate::yaml::Yaml;
+
+// into type structures impl
+impl Yaml {
+    /// each document is a root, numbered when the file has several documents
+    pub fn into_type_structures(self, root_name: impl Into<TypeName>) -> Vec<TypeStructure> {
+        let root_name = root_name.into();
+        let documents = self.documents();
+        if documents.len() == 1 {
+            return documents
+                .into_iter()
+                .flat_map(|document| document.into_type_structures(root_name.clone()))
+                .collect();
+        }
+        documents
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, document)| {
+                document.into_type_structures(format!("{}{}", root_name.as_str(), i + 1))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_into_type_structures {
+    use structure::parts::property_type::property_type_factories::{
+        make_custom_type, make_string_type, make_usize_type,
+    };
+
+    use super::*;
+    #[test]
+    fn 複数のドキュメントは番号付きの型になる() {
+        let sut = Yaml::from(
+            r#"
+kind: Deployment
+spec:
+  replicas: 3
+---
+kind: Service
+"#,
+        );
+
+        assert_eq!(
+            sut.into_type_structures("Manifest"),
+            vec![
+                TypeStructure::make_composite(
+                    "Manifest1",
+                    vec![
+                        ("kind", make_string_type()),
+                        ("spec", make_custom_type("Manifest1Spec")),
+                    ]
+                ),
+                TypeStructure::make_composite(
+                    "Manifest1Spec",
+                    vec![("replicas", make_usize_type())]
+                ),
+                TypeStructure::make_composite("Manifest2", vec![("kind", make_string_type())]),
+            ]
+        );
+    }
+    #[test]
+    fn ドキュメントが一つならそのままの型名になる() {
+        let sut = Yaml::from("name: app\n");
+
+        assert_eq!(
+            sut.into_type_structures("Config"),
+            vec![TypeStructure::make_composite(
+                "Config",
+                vec![("name", make_string_type())]
+            )]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use json::json::Json;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+
+/// yaml file, which may contain several documents separated by `---`
+#[derive(Debug, PartialEq, Clone)]
+pub struct Yaml {
+    documents: Vec<Json>,
+}
+impl Yaml {
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).unwrap();
+        let mut result = String::new();
+        let mut reader = BufReader::new(file);
+        reader.read_to_string(&mut result).unwrap();
+        Yaml::from(result.as_str())
+    }
+    pub(crate) fn documents(self) -> Vec<Json> {
+        self.documents
+    }
+    /// yaml allows not string key (for example status code `200:`), so key is stringified
+    pub fn to_json_value(yaml: serde_yaml::Value) -> Value {
+        match yaml {
+            serde_yaml::Value::Null => Value::Null,
+            serde_yaml::Value::Bool(bool) => Value::Bool(bool),
+            serde_yaml::Value::Number(num) => {
+                if let Some(num) = num.as_u64() {
+                    return Value::Number(Number::from(num));
+                }
+                if let Some(num) = num.as_i64() {
+                    return Value::Number(Number::from(num));
+                }
+                num.as_f64()
+                    .and_then(Number::from_f64)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)
+            }
+            serde_yaml::Value::String(str) => Value::String(str),
+            serde_yaml::Value::Sequence(seq) => {
+                Value::Array(seq.into_iter().map(Self::to_json_value).collect())
+            }
+            serde_yaml::Value::Mapping(mapping) => Value::Object(
+                mapping
+                    .into_iter()
+                    .map(|(key, value)| (Self::key(key), Self::to_json_value(value)))
+                    .collect::<Map<_, _>>(),
+            ),
+            serde_yaml::Value::Tagged(tagged) => Self::to_json_value(tagged.value),
+        }
+    }
+    fn key(key: serde_yaml::Value) -> String {
+        match Self::to_json_value(key) {
+            Value::String(str) => str,
+            value => value.to_string(),
+        }
+    }
+}
+/// empty document (for example trailing `---`) is ignored
+impl From<&str> for Yaml {
+    fn from(source: &str) -> Self {
+        let documents = serde_yaml::Deserializer::from_str(source)
+            .map(|document| serde_yaml::Value::deserialize(document).unwrap())
+            .filter(|document| !document.is_null())
+            .map(|document| Json::from(Self::to_json_value(document)))
+            .collect();
+        Self { documents }
+    }
+}
+impl From<String> for Yaml {
+    fn from(source: String) -> Self {
+        Yaml::from(source.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use json::json::Number;
+
+    use super::*;
+    #[test]
+    fn 区切られたドキュメントはそれぞれjsonになる() {
+        let sut = Yaml::from("kind: Service\n---\nreplicas: 3\n---\n");
+
+        assert_eq!(
+            sut.documents(),
+            vec![
+                Json::Object(BTreeMap::from([(
+                    "kind".to_string(),
+                    Json::String("Service".to_string())
+                )])),
+                Json::Object(BTreeMap::from([(
+                    "replicas".to_string(),
+                    Json::Number(Number::Usize64(3))
+                )])),
+            ]
+        );
+    }
+}
@@ -221,7 +221,7 @@ where
     pub fn add_description_convertor(&mut self, convertor: Box<dyn CompositeTypeDeclareConvertor>) {
         self.description_convertors.push(convertor);
     }
-    pub(super) fn generate_type_define(
+    pub fn generate_type_define(
         &self,
         composite_type: &CompositeTypeStructure,
         properties_description: String,
@@ -282,9 +282,9 @@ impl CustomizableCompositeTypeDeclareGenerator<fn(&str, &TypeName, String) -> St
         property_descriptions: String,
     ) -> String {
         format!(
-            "{} {} :\n{}",
+            "{} {}:\n{}",
             identify,
-            type_name.as_str(),
+            type_name.valid_lang_str(),
             property_descriptions
         )
     }
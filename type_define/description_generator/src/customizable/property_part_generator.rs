@@ -58,6 +58,11 @@ where
     pub fn add_property_type_convertor(&mut self, convertor: PropertyTypeConvertor<M>) {
         self.property_type_convertor.push(convertor);
     }
+    /// convertor which chooses type itself instead of mapper.
+    /// it is applied before other type convertors, so that they convert the chosen type
+    pub fn add_base_property_type_convertor(&mut self, convertor: PropertyTypeConvertor<M>) {
+        self.property_type_convertor.insert(0, convertor);
+    }
     pub fn add_property_key_convertor(&mut self, convertor: PropertyKeyConvertor<M>) {
         self.property_key_convertor.push(convertor);
     }
@@ -73,6 +78,28 @@ where
             .for_each(|s| black_list.add_match_property_key(s));
         self.add_statement_convertor(Box::new(black_list));
     }
+    /// key is changed by change_key after key convertors,
+    /// for example when converted key is same as key of other property
+    pub fn generate_with_key(
+        &self,
+        type_name: &TypeName,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &M,
+        change_key: impl FnOnce(String) -> String,
+    ) -> String {
+        let c = &self.concat_key_and_property_type_clouser;
+        let concated_str = c(
+            change_key(self.gen_key_str(type_name, property_key, property_type, mapper)),
+            self.gen_type_str(type_name, property_key, property_type, mapper),
+        );
+        self.statement_convertor
+            .iter()
+            .fold(Some(concated_str), |acc, cur| {
+                cur.convert(acc, type_name, property_key, property_type, mapper)
+            })
+            .unwrap_or_default()
+    }
     fn gen_key_str(
         &self,
         type_name: &TypeName,
@@ -119,17 +146,7 @@ where
         property_type: &PropertyType,
         mapper: &M,
     ) -> String {
-        let c = &self.concat_key_and_property_type_clouser;
-        let concated_str = c(
-            self.gen_key_str(type_name, property_key, property_type, mapper),
-            self.gen_type_str(type_name, property_key, property_type, mapper),
-        );
-        self.statement_convertor
-            .iter()
-            .fold(Some(concated_str), |acc, cur| {
-                cur.convert(acc, type_name, property_key, property_type, mapper)
-            })
-            .unwrap_or_default()
+        self.generate_with_key(type_name, property_key, property_type, mapper, |key| key)
     }
 }
 impl<M> Default for CustomizablePropertyDescriptionGenerator<fn(String, String) -> String, M>
@@ -1,6 +1,7 @@
 use structure::{
     alias_type_structure::AliasTypeStructure,
     composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::EnumTypeStructure,
     parts::{property_key::PropertyKey, property_type::PropertyType, type_name::TypeName},
     type_structure::TypeStructure,
 };
@@ -57,6 +58,10 @@ where
 /// ```no_run ignore
 /// let alias = "type Alias = String;";
 /// ```
+/// made case enum
+/// ```no_run ignore
+/// let enum_ = "enum Status { Active, Inactive }";
+/// ```
 pub trait DeclarePartGenerator {
     const TYPE_PREFIX: &'static str = "struct";
     type Mapper: TypeMapper;
@@ -66,8 +71,33 @@ pub trait DeclarePartGenerator {
         properties_statement: String,
     ) -> String;
 
+    /// composite type generated together with other type structures.
+    /// override this when declaration depends on other types (for example recursive reference)
+    fn generate_case_composite_among(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        properties_statement: String,
+        _structures: &[TypeStructure],
+    ) -> String {
+        self.generate_case_composite(composite_type, properties_statement)
+    }
+
     fn generate_case_alias(&self, alias_type: &AliasTypeStructure, mapper: &Self::Mapper)
         -> String;
+
+    /// enum is declared as alias (string or any), unless lang has sum type
+    fn generate_case_enum(&self, enum_type: &EnumTypeStructure, mapper: &Self::Mapper) -> String {
+        self.generate_case_alias(&enum_type.to_alias(), mapper)
+    }
+
+    /// concat all type descriptions into one file content.
+    /// override this when lang needs file header (imports etc.) or specific order
+    fn generate_case_concat(&self, descriptions: Vec<TypeDescription>) -> TypeDescription {
+        descriptions
+            .into_iter()
+            .reduce(|acc, cur| format!("{}\n{}\n", acc, cur))
+            .unwrap_or_default()
+    }
 }
 
 /// made case composite
@@ -85,6 +115,23 @@ where
         property_type: &PropertyType,
         mapper: &M,
     ) -> String;
+
+    /// property generated together with other properties of composite type.
+    /// override this when property depends on others (for example same field name)
+    fn generate_among(
+        &self,
+        composite_type: &CompositeTypeStructure,
+        property_key: &PropertyKey,
+        property_type: &PropertyType,
+        mapper: &M,
+    ) -> String {
+        self.generate(
+            composite_type.type_name(),
+            property_key,
+            property_type,
+            mapper,
+        )
+    }
 }
 
 impl<Declare, Property, Mapper> TypeDescriptionGenerator<Declare, Property, Mapper>
@@ -105,38 +152,49 @@ where
         }
     }
     pub fn generate_concat_define(&self, structures: Vec<TypeStructure>) -> TypeDescription {
-        self.generate(structures)
-            .into_iter()
-            .reduce(|acc, cur| format!("{}\n{}\n", acc, cur))
-            .unwrap()
+        self.declare_part_generator
+            .generate_case_concat(self.generate(structures))
     }
     pub fn generate(&self, structures: Vec<TypeStructure>) -> Vec<TypeDescription> {
         structures
-            .into_iter()
-            .map(|s| self.generate_one(s))
+            .iter()
+            .map(|s| self.generate_among(s, &structures))
             .collect()
     }
     pub fn generate_one(&self, structure: TypeStructure) -> TypeDescription {
+        self.generate_among(&structure, std::slice::from_ref(&structure))
+    }
+    fn generate_among(
+        &self,
+        structure: &TypeStructure,
+        structures: &[TypeStructure],
+    ) -> TypeDescription {
         match structure {
             TypeStructure::Composite(composite) => {
                 let properties_statement =
                     composite
                         .iter()
                         .fold(String::new(), |acc, (property_key, property_type)| {
-                            let property_statement = self.property_part_generator.generate(
-                                &composite.type_name(),
+                            let property_statement = self.property_part_generator.generate_among(
+                                composite,
                                 property_key,
                                 property_type,
                                 &self.mapper,
                             );
                             format!("{}{}", acc, property_statement)
                         });
-                self.declare_part_generator
-                    .generate_case_composite(&composite, properties_statement)
+                self.declare_part_generator.generate_case_composite_among(
+                    composite,
+                    properties_statement,
+                    structures,
+                )
             }
             TypeStructure::Alias(primitive) => self
                 .declare_part_generator
-                .generate_case_alias(&primitive, &self.mapper),
+                .generate_case_alias(primitive, &self.mapper),
+            TypeStructure::Enum(enum_type) => self
+                .declare_part_generator
+                .generate_case_enum(enum_type, &self.mapper),
         }
     }
 }
@@ -223,6 +281,24 @@ mod test_type_define_statement_generator {
 
     use crate::type_description_generator::TypeDescriptionGenerator;
 
+    #[test]
+    fn sum_typeがない言語ではenumはaliasになる() {
+        let sut = TypeDescriptionGenerator::fake_new();
+        assert_eq!(
+            sut.generate_one(TypeStructure::make_literal_enum(
+                "Status",
+                vec!["active", "inactive"]
+            )),
+            "type Status = String;"
+        );
+        assert_eq!(
+            sut.generate_one(TypeStructure::make_untagged_enum(
+                "Value",
+                vec![make_string_type(), make_custom_type("Child")]
+            )),
+            "type Value = any;"
+        );
+    }
     #[test]
     fn test_case_primitive() {
         let simple_statement = TypeStructure::make_alias("Test", make_string_type());
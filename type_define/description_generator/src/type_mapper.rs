@@ -15,6 +15,42 @@ pub trait TypeMapper {
     fn case_array_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString;
     fn case_optional_type<T: Into<TypeString>>(&self, type_statement: T) -> TypeString;
 
+    /// datetime is string, unless lang has dedicated type
+    fn case_datetime(&self) -> TypeString {
+        self.case_string()
+    }
+    /// decimal is float, unless lang has dedicated type
+    fn case_decimal(&self) -> TypeString {
+        self.case_float()
+    }
+    /// datetime with time zone is datetime, unless lang distinguishes them
+    fn case_zoned_datetime(&self) -> TypeString {
+        self.case_datetime()
+    }
+    /// date and time of day are string, unless lang has dedicated type
+    fn case_date(&self) -> TypeString {
+        self.case_string()
+    }
+    fn case_time(&self) -> TypeString {
+        self.case_string()
+    }
+    /// uuid is string, unless lang has dedicated type
+    fn case_uuid(&self) -> TypeString {
+        self.case_string()
+    }
+    /// binary is string (for example base64), unless lang has dedicated type
+    fn case_binary(&self) -> TypeString {
+        self.case_string()
+    }
+    /// width of integer is ignored, unless lang has integer of each width
+    fn case_sized_integer(&self, _bits: u16, signed: bool) -> TypeString {
+        if signed {
+            self.case_isize()
+        } else {
+            self.case_usize()
+        }
+    }
+
     // not customize developer
     fn case_custom_type(&self, custom_type: &TypeName) -> String {
         custom_type.as_str().to_string()
@@ -23,10 +59,18 @@ pub trait TypeMapper {
         match primitive_type {
             PrimitiveType::Boolean => self.case_boolean(),
             PrimitiveType::String => self.case_string(),
+            PrimitiveType::DateTime => self.case_datetime(),
+            PrimitiveType::ZonedDateTime => self.case_zoned_datetime(),
+            PrimitiveType::Date => self.case_date(),
+            PrimitiveType::Time => self.case_time(),
+            PrimitiveType::Uuid => self.case_uuid(),
+            PrimitiveType::Binary => self.case_binary(),
+            PrimitiveType::Decimal => self.case_decimal(),
             PrimitiveType::Number(num) => match num {
                 Number::Float => self.case_float(),
                 Number::Usize => self.case_usize(),
                 Number::Isize => self.case_isize(),
+                Number::Sized { bits, signed } => self.case_sized_integer(*bits, *signed),
             },
         }
     }
@@ -0,0 +1,185 @@
+use crate::{
+    alias_type_structure::AliasTypeStructure,
+    parts::{
+        property_type::{Number, PrimitiveType, PropertyType},
+        type_name::TypeName,
+    },
+};
+
+/// EnumTypeStructure is sum type, string literal enum or union of types
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumTypeStructure {
+    name: TypeName,
+    representation: EnumRepresentation,
+}
+
+/// how variant is distinguished in serialized value
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumRepresentation {
+    /// `"a" | "b"`
+    Literal(Vec<String>),
+    /// `{"type":"a",...} | {"type":"b",...}`.
+    /// variant is literal of tag and type which has the other fields
+    Tagged {
+        tag: String,
+        variants: Vec<(String, PropertyType)>,
+    },
+    /// `A | B`, variant is distinguished by shape of value
+    Untagged(Vec<PropertyType>),
+}
+
+impl EnumTypeStructure {
+    pub fn new(name: impl Into<TypeName>, representation: EnumRepresentation) -> Self {
+        Self {
+            name: name.into(),
+            representation,
+        }
+    }
+    pub fn type_name(&self) -> &TypeName {
+        &self.name
+    }
+    pub fn representation(&self) -> &EnumRepresentation {
+        &self.representation
+    }
+    /// name of each variant, literal or type of variant is named in pascal case.
+    /// names which collide (e.g. `Vec<A>` and `Option<Vec<A>>`) are numbered in order
+    pub fn variant_names(&self) -> Vec<TypeName> {
+        let names: Vec<TypeName> = match &self.representation {
+            EnumRepresentation::Literal(values) => values
+                .iter()
+                .map(|value| Self::variant_name_of_literal(value))
+                .collect(),
+            EnumRepresentation::Tagged { variants, .. } => variants
+                .iter()
+                .map(|(value, _)| Self::variant_name_of_literal(value))
+                .collect(),
+            EnumRepresentation::Untagged(variants) => {
+                variants.iter().map(Self::variant_name_of_type).collect()
+            }
+        };
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                if names.iter().filter(|other| *other == name).count() == 1 {
+                    return name.clone();
+                }
+                let nth = names[..i].iter().filter(|other| *other == name).count() + 1;
+                TypeName::new(format!("{}{}", name.as_str(), nth))
+            })
+            .collect()
+    }
+    /// lang which has no sum type declares enum as alias.
+    /// literal enum is string and union is any
+    pub fn to_alias(&self) -> AliasTypeStructure {
+        let property_type = match &self.representation {
+            EnumRepresentation::Literal(_) => PropertyType::Primitive(PrimitiveType::String),
+            _ => PropertyType::Any,
+        };
+        AliasTypeStructure::new(self.name.clone(), property_type)
+    }
+    /// literal is not always identifier,
+    /// so empty literal is `Empty` and literal which starts with digit is prefixed by `V`
+    fn variant_name_of_literal(value: &str) -> TypeName {
+        let name = TypeName::from(value);
+        let valid = name.valid_lang_str();
+        match valid.chars().next() {
+            None => TypeName::new("Empty"),
+            Some(c) if c.is_ascii_digit() => TypeName::new(format!("V{}", valid)),
+            Some(_) => name,
+        }
+    }
+    fn variant_name_of_type(property_type: &PropertyType) -> TypeName {
+        let name = match property_type {
+            PropertyType::Any => "Any".to_string(),
+            PropertyType::CustomType(type_name) => type_name.as_str().to_string(),
+            PropertyType::Optional(property_type) => {
+                return Self::variant_name_of_type(property_type)
+            }
+            PropertyType::Array(property_type) => format!(
+                "{}Array",
+                Self::variant_name_of_type(property_type).as_str()
+            ),
+            PropertyType::Primitive(primitive) => match primitive {
+                PrimitiveType::String => "String",
+                PrimitiveType::Boolean => "Boolean",
+                PrimitiveType::DateTime | PrimitiveType::ZonedDateTime => "DateTime",
+                PrimitiveType::Date => "Date",
+                PrimitiveType::Time => "Time",
+                PrimitiveType::Uuid => "Uuid",
+                PrimitiveType::Binary => "Binary",
+                PrimitiveType::Decimal => "Decimal",
+                PrimitiveType::Number(Number::Usize | Number::Sized { signed: false, .. }) => {
+                    "Usize"
+                }
+                PrimitiveType::Number(Number::Isize | Number::Sized { signed: true, .. }) => {
+                    "Isize"
+                }
+                PrimitiveType::Number(Number::Float) => "Float",
+            }
+            .to_string(),
+        };
+        TypeName::new(name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parts::property_type::property_type_factories::{
+        make_array_type, make_custom_type, make_optional_type, make_string_type,
+    };
+
+    use super::*;
+    #[test]
+    fn variantの名前はリテラルか型から作られる() {
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["in_progress".to_string(), "done".to_string()]),
+        );
+        assert_eq!(
+            literal.variant_names(),
+            vec![TypeName::new("InProgress"), TypeName::new("Done")]
+        );
+        let untagged = EnumTypeStructure::new(
+            "Value",
+            EnumRepresentation::Untagged(vec![
+                make_string_type(),
+                make_array_type(make_custom_type("User")),
+            ]),
+        );
+        assert_eq!(
+            untagged.variant_names(),
+            vec![TypeName::new("String"), TypeName::new("UserArray")]
+        );
+    }
+    #[test]
+    fn 識別子にならないリテラルは代わりの名前になる() {
+        let literal = EnumTypeStructure::new(
+            "Status",
+            EnumRepresentation::Literal(vec!["".to_string(), "1".to_string()]),
+        );
+        assert_eq!(
+            literal.variant_names(),
+            vec![TypeName::new("Empty"), TypeName::new("V1")]
+        );
+    }
+    #[test]
+    fn 同じ名前になるvariantは番号で区別される() {
+        let untagged = EnumTypeStructure::new(
+            "Value",
+            EnumRepresentation::Untagged(vec![
+                make_array_type(make_string_type()),
+                make_string_type(),
+                make_optional_type(make_array_type(make_string_type())),
+            ]),
+        );
+        assert_eq!(
+            untagged.variant_names(),
+            vec![
+                TypeName::new("StringArray1"),
+                TypeName::new("String"),
+                TypeName::new("StringArray2")
+            ]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod alias_type_structure;
 pub mod composite_type_structure;
+pub mod enum_type_structure;
 pub mod parts;
 pub mod type_structure;
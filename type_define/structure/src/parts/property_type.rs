@@ -24,12 +24,28 @@ pub enum PrimitiveType {
     String,
     Boolean,
     Number(Number),
+    DateTime,
+    /// datetime with time zone, for example `TIMESTAMP WITH TIME ZONE` column
+    ZonedDateTime,
+    /// date without time of day, for example `DATE` column
+    Date,
+    /// time of day without date, for example `TIME` column
+    Time,
+    Uuid,
+    /// byte sequence, for example `BYTEA` column
+    Binary,
+    Decimal,
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Number {
     Usize,
     Isize,
     Float,
+    /// integer of known width, for example `INT` column is 32 bits
+    Sized {
+        bits: u16,
+        signed: bool,
+    },
 }
 
 pub mod property_type_factories {
@@ -64,6 +80,36 @@ pub mod property_type_factories {
     pub fn make_float_type() -> PropertyType {
         make_primitive_type(make_float())
     }
+    pub fn make_datetime_type() -> PropertyType {
+        make_primitive_type(make_datetime())
+    }
+    pub fn make_decimal_type() -> PropertyType {
+        make_primitive_type(make_decimal())
+    }
+    pub fn make_zoned_datetime_type() -> PropertyType {
+        make_primitive_type(PrimitiveType::ZonedDateTime)
+    }
+    pub fn make_date_type() -> PropertyType {
+        make_primitive_type(PrimitiveType::Date)
+    }
+    pub fn make_time_type() -> PropertyType {
+        make_primitive_type(PrimitiveType::Time)
+    }
+    pub fn make_uuid_type() -> PropertyType {
+        make_primitive_type(PrimitiveType::Uuid)
+    }
+    pub fn make_binary_type() -> PropertyType {
+        make_primitive_type(PrimitiveType::Binary)
+    }
+    pub fn make_sized_isize_type(bits: u16) -> PropertyType {
+        make_primitive_type(PrimitiveType::Number(Number::Sized { bits, signed: true }))
+    }
+    pub fn make_sized_usize_type(bits: u16) -> PropertyType {
+        make_primitive_type(PrimitiveType::Number(Number::Sized {
+            bits,
+            signed: false,
+        }))
+    }
     pub fn make_any() -> PropertyType {
         PropertyType::Any
     }
@@ -82,4 +128,10 @@ pub mod property_type_factories {
     fn make_float() -> PrimitiveType {
         PrimitiveType::Number(Number::Float)
     }
+    fn make_datetime() -> PrimitiveType {
+        PrimitiveType::DateTime
+    }
+    fn make_decimal() -> PrimitiveType {
+        PrimitiveType::Decimal
+    }
 }
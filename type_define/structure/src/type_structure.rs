@@ -1,6 +1,7 @@
 use crate::{
     alias_type_structure::AliasTypeStructure,
     composite_type_structure::CompositeTypeStructure,
+    enum_type_structure::{EnumRepresentation, EnumTypeStructure},
     parts::{property_type::PropertyType, type_name::TypeName},
 };
 
@@ -8,6 +9,7 @@ use crate::{
 pub enum TypeStructure {
     Composite(CompositeTypeStructure),
     Alias(AliasTypeStructure),
+    Enum(EnumTypeStructure),
 }
 
 impl TypeStructure {
@@ -22,6 +24,43 @@ impl TypeStructure {
     pub fn make_alias(name: impl Into<TypeName>, property_type: PropertyType) -> Self {
         Self::Alias(AliasTypeStructure::new(name.into(), property_type))
     }
+    pub fn make_literal_enum(name: impl Into<TypeName>, values: Vec<&str>) -> Self {
+        let values = values.into_iter().map(|v| v.to_string()).collect();
+        Self::Enum(EnumTypeStructure::new(
+            name,
+            EnumRepresentation::Literal(values),
+        ))
+    }
+    pub fn make_tagged_enum(
+        name: impl Into<TypeName>,
+        tag: &str,
+        variants: Vec<(&str, PropertyType)>,
+    ) -> Self {
+        let variants = variants
+            .into_iter()
+            .map(|(v, t)| (v.to_string(), t))
+            .collect();
+        Self::Enum(EnumTypeStructure::new(
+            name,
+            EnumRepresentation::Tagged {
+                tag: tag.to_string(),
+                variants,
+            },
+        ))
+    }
+    pub fn make_untagged_enum(name: impl Into<TypeName>, variants: Vec<PropertyType>) -> Self {
+        Self::Enum(EnumTypeStructure::new(
+            name,
+            EnumRepresentation::Untagged(variants),
+        ))
+    }
+    pub fn type_name(&self) -> &TypeName {
+        match self {
+            Self::Composite(composite) => composite.type_name(),
+            Self::Alias(alias) => alias.type_name(),
+            Self::Enum(enum_type) => enum_type.type_name(),
+        }
+    }
 }
 
 pub trait IntoTypeStructures {